@@ -21,6 +21,46 @@ TRANSLATE_GLIDER_NAMES = {
     "Ventus a/b (16.6m)": "Ventus a/b 16.6m",
 }
 
+# speed bands of flapped gliders, see core/src/flight_physics/flaps.rs
+FLAP_TABLES = {
+    "ASG-29 (15m)": "ASG29_15M",
+    "ASG-29 (18m)": "ASG29_18M",
+    "ASG-29E (15m)": "ASG29_15M",
+    "ASG-29E (18m)": "ASG29_18M",
+    "DG-800B (15m)": "DG800_15M",
+    "DG-800B (18m)": "DG800_18M",
+    "DG-800S (15m)": "DG800_15M",
+    "DG-800S (18m)": "DG800_18M",
+    "Ventus 2b 15m": "VENTUS2_15M",
+    "Ventus 2c 18m": "VENTUS2_18M",
+    "Ventus 2cT 18m": "VENTUS2_18M",
+    "Ventus 2cx 18m": "VENTUS2_18M",
+    "Ventus 2cxT 18m": "VENTUS2_18M",
+}
+
+# speed limits of the flight manual, see core/src/flight_physics/speed_limits.rs
+SPEED_LIMITS = {
+    "ASG-29 (15m)": "ASG29",
+    "ASG-29 (18m)": "ASG29",
+    "ASG-29E (15m)": "ASG29",
+    "ASG-29E (18m)": "ASG29",
+    "DG-800B (15m)": "DG800",
+    "DG-800B (18m)": "DG800",
+    "DG-800S (15m)": "DG800",
+    "DG-800S (18m)": "DG800",
+    "Ventus 2b 15m": "VENTUS2_15M",
+    "Ventus 2c 18m": "VENTUS2_18M",
+    "Ventus 2cT 18m": "VENTUS2_18M",
+    "Ventus 2cx 18m": "VENTUS2_18M",
+    "Ventus 2cxT 18m": "VENTUS2_18M",
+}
+
+# polars of higher order than the three points, see core/src/flight_physics/polar_curve.rs
+# The measured polars of the flapped gliders are still missing, until they are added all
+# gliders use the parabola through the three XCSoar points.
+POLAR_CURVES = {
+}
+
 class Glider():
     def load_from_line(self, cpp_line, comment):
         line = cpp_line.replace(b'  { _T(', b'').replace(b'),', b',').replace(b' },', b'').replace(b'"', b'')
@@ -65,6 +105,18 @@ class Glider():
         r += f"        reference_weight: {self.reference_mass:.1f},\n"
         r += f"        handicap: {self.contest_handicap},\n"
         r += f"        polar_values: [[{self.v1:.1f}, {self.w1:.3f}], [{self.v2:.1f}, {self.w2:.3f}], [{self.v3:.1f}, {self.w3:.3f}]],\n"
+        if self.name in FLAP_TABLES:
+            r += f"        flaps: Some(&flaps::{FLAP_TABLES[self.name]}),\n"
+        else:
+            r += "        flaps: None,\n"
+        if self.name in SPEED_LIMITS:
+            r += f"        limits: Some(&speed_limits::{SPEED_LIMITS[self.name]}),\n"
+        else:
+            r += "        limits: None,\n"
+        if self.name in POLAR_CURVES:
+            r += f"        curve: Some(&polar_curve::{POLAR_CURVES[self.name]}),\n"
+        else:
+            r += "        curve: None,\n"
         r += "    },\n"
         return r
 
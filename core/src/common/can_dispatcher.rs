@@ -40,6 +40,15 @@ pub type QRxFrames<const MAX_RX_FRAMES: usize> = Queue<Frame, MAX_RX_FRAMES>;
 pub type PRxFrames<const MAX_RX_FRAMES: usize> = Producer<'static, Frame, MAX_RX_FRAMES>;
 pub type CRxFrames<const MAX_RX_FRAMES: usize> = Consumer<'static, Frame, MAX_RX_FRAMES>;
 
+/// Snapshot of a bus participant as seen by the dispatcher
+#[derive(Clone, Copy, Default)]
+pub struct CanParticipant {
+    pub vda: u16,
+    pub object_id: u16,
+    pub age: u8,         // seconds since the last heartbeat
+    pub frame_rate: u16, // frames received during the last second
+}
+
 pub trait CanRng {
     fn random(&mut self, min: u32, max: u32) -> u32;
 }
@@ -166,6 +175,25 @@ impl<
         }
     }
 
+    /// Own device address, 0 during startup
+    pub fn vda(&self) -> u16 {
+        self.vda
+    }
+
+    /// Iterate over all participants currently visible on the bus
+    pub fn participants(&self) -> impl Iterator<Item = CanParticipant> + '_ {
+        self.can_devices
+            .iter()
+            .enumerate()
+            .filter(|(_, can_device)| can_device.is_alive())
+            .map(|(vda, can_device)| CanParticipant {
+                vda: vda as u16,
+                object_id: can_device.object_id,
+                age: can_device.age,
+                frame_rate: can_device.frame_rate,
+            })
+    }
+
     /// rx_data() takes CAN bus frames from the hardware driver, analyzes them and passes them on
     /// to the application if necessary.
     pub fn rx_data(&mut self, can_frame: CanFrame) {
@@ -193,6 +221,9 @@ impl<
                         return;
                     }
                 }
+                // Frames according to specification carry the address of the sender
+                self.can_devices[can_frame.vda() as usize].count_frame();
+
                 // Then check whether it is a generic frame
                 if let Some(generic_id) = can_frame.generic_id() {
                     // We don't want to see all the heartbeats
@@ -310,6 +341,9 @@ struct CanDevice {
     time_to_death: u8,
     is_first: bool,
     object_id: u16,
+    age: u8,
+    frame_count: u16,
+    frame_rate: u16,
 }
 
 #[allow(unused)]
@@ -318,11 +352,14 @@ impl CanDevice {
         self.time_to_death == 0
     }
 
-    fn is_alive(&mut self) -> bool {
+    fn is_alive(&self) -> bool {
         self.time_to_death != 0
     }
 
     fn sec_tick(&mut self) {
+        self.frame_rate = self.frame_count;
+        self.frame_count = 0;
+        self.age = self.age.saturating_add(1);
         if self.time_to_death > 0 {
             self.time_to_death -= 1;
             if self.time_to_death == 0 {
@@ -336,9 +373,14 @@ impl CanDevice {
         if frame.is_heartbeat() {
             self.object_id = frame.read_u16(0);
             self.time_to_death = 3;
+            self.age = 0;
         }
     }
 
+    fn count_frame(&mut self) {
+        self.frame_count = self.frame_count.saturating_add(1);
+    }
+
     fn object_id(&self) -> u16 {
         self.object_id
     }
@@ -0,0 +1,259 @@
+use crate::{
+    basic_config::{CONTROLLER_TICK_RATE, ENGINE_INFO_TIMEOUT, ICING_INFO_TIMEOUT},
+    model::TypeOfInfo,
+};
+
+/// Warnings which can be sounded or shown, sorted by priority, the first one is the most urgent
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Alarm {
+    Flarm,
+    Stall,
+    Overspeed,
+    Gear,
+    Engine,
+    LowBattery,
+    WaterBallast,
+    Icing,
+}
+
+/// Sound pattern of an alarm
+#[derive(Clone, Copy, PartialEq)]
+pub enum AlarmPattern {
+    /// The alarm is only shown, the vario keeps sounding
+    Silent,
+    Flarm,
+    Stall,
+    Overspeed,
+    Gear,
+    Engine,
+    Reminder,
+}
+
+/// What happens when the pilot silences an alarm
+#[derive(Clone, Copy, PartialEq)]
+pub enum AckPolicy {
+    /// Cannot be silenced, it ends with its cause
+    Never,
+    /// Silenced until the cause disappears and arises again
+    UntilCleared,
+    /// Silenced for the given seconds, then it is repeated as long as the cause persists
+    Repeat(u16),
+    /// Ends by itself after the given seconds, it can be silenced before
+    Timeout(u16),
+}
+
+struct AlarmSpec {
+    alarm: Alarm,
+    pattern: AlarmPattern,
+    policy: AckPolicy,
+    info: TypeOfInfo,
+}
+
+// Sorted like the alarms, so that an alarm is the index of its spec
+const ALARM_SPECS: &[AlarmSpec] = &[
+    AlarmSpec {
+        // the traffic is shown in the FLARM view
+        alarm: Alarm::Flarm,
+        pattern: AlarmPattern::Flarm,
+        policy: AckPolicy::Never,
+        info: TypeOfInfo::None,
+    },
+    AlarmSpec {
+        alarm: Alarm::Stall,
+        pattern: AlarmPattern::Stall,
+        policy: AckPolicy::Never,
+        info: TypeOfInfo::Stall,
+    },
+    AlarmSpec {
+        alarm: Alarm::Overspeed,
+        pattern: AlarmPattern::Overspeed,
+        policy: AckPolicy::Repeat(10),
+        info: TypeOfInfo::Overspeed,
+    },
+    AlarmSpec {
+        alarm: Alarm::Gear,
+        pattern: AlarmPattern::Gear,
+        policy: AckPolicy::UntilCleared,
+        info: TypeOfInfo::GearAlarm,
+    },
+    AlarmSpec {
+        alarm: Alarm::Engine,
+        pattern: AlarmPattern::Engine,
+        policy: AckPolicy::Timeout(ENGINE_INFO_TIMEOUT as u16),
+        info: TypeOfInfo::Engine,
+    },
+    AlarmSpec {
+        alarm: Alarm::LowBattery,
+        pattern: AlarmPattern::Reminder,
+        policy: AckPolicy::Repeat(300),
+        info: TypeOfInfo::LowBattery,
+    },
+    AlarmSpec {
+        alarm: Alarm::WaterBallast,
+        pattern: AlarmPattern::Reminder,
+        policy: AckPolicy::UntilCleared,
+        info: TypeOfInfo::WaterBallast,
+    },
+    AlarmSpec {
+        alarm: Alarm::Icing,
+        pattern: AlarmPattern::Silent,
+        policy: AckPolicy::Timeout(ICING_INFO_TIMEOUT as u16),
+        info: TypeOfInfo::Icing,
+    },
+];
+
+const ALARM_COUNT: usize = ALARM_SPECS.len();
+
+impl Alarm {
+    pub fn pattern(&self) -> AlarmPattern {
+        ALARM_SPECS[*self as usize].pattern
+    }
+
+    pub fn info(&self) -> TypeOfInfo {
+        ALARM_SPECS[*self as usize].info
+    }
+
+    fn policy(&self) -> AckPolicy {
+        ALARM_SPECS[*self as usize].policy
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct AlarmState {
+    active: bool,
+    silenced: bool,
+    /// Ticks since the alarm arose or since it was silenced
+    ticks: u32,
+}
+
+/// Arbitration of simultaneous alarms
+///
+/// The sources only report whether their cause is present. Of all alarms, which are present and
+/// not silenced, the one with the highest priority is sounded and shown.
+#[derive(Default)]
+pub struct AlarmMixer {
+    states: [AlarmState; ALARM_COUNT],
+}
+
+impl AlarmMixer {
+    pub fn set(&mut self, alarm: Alarm, active: bool) {
+        let state = &mut self.states[alarm as usize];
+        if active != state.active {
+            *state = AlarmState {
+                active,
+                ..Default::default()
+            };
+        }
+    }
+
+    /// Restart an alarm, even if its cause is already present
+    pub fn raise(&mut self, alarm: Alarm) {
+        self.states[alarm as usize] = AlarmState {
+            active: true,
+            ..Default::default()
+        };
+    }
+
+    /// The alarm which is sounded and shown
+    pub fn current(&self) -> Option<Alarm> {
+        ALARM_SPECS.iter().map(|spec| spec.alarm).find(|alarm| {
+            let state = &self.states[*alarm as usize];
+            state.active && !state.silenced
+        })
+    }
+
+    /// Silence the current alarm, returns false if there is nothing to silence
+    pub fn acknowledge(&mut self) -> bool {
+        match self.current() {
+            Some(alarm) if alarm.policy() != AckPolicy::Never => {
+                let state = &mut self.states[alarm as usize];
+                state.silenced = true;
+                state.ticks = 0;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Must be called every 100ms
+    pub fn tick_100ms(&mut self) {
+        for alarm in ALARM_SPECS.iter().map(|spec| spec.alarm) {
+            let state = &mut self.states[alarm as usize];
+            if !state.active {
+                continue;
+            }
+            state.ticks += 1;
+            let secs = state.ticks / CONTROLLER_TICK_RATE;
+            match alarm.policy() {
+                AckPolicy::Repeat(repeat) if state.silenced && secs >= repeat as u32 => {
+                    state.silenced = false;
+                    state.ticks = 0;
+                }
+                AckPolicy::Timeout(timeout) if !state.silenced && secs >= timeout as u32 => {
+                    state.silenced = true;
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticks(mixer: &mut AlarmMixer, secs: u32) {
+        for _ in 0..secs * CONTROLLER_TICK_RATE {
+            mixer.tick_100ms();
+        }
+    }
+
+    #[test]
+    fn test_specs() {
+        for (idx, spec) in ALARM_SPECS.iter().enumerate() {
+            assert_eq!(spec.alarm as usize, idx);
+        }
+        assert_eq!(ALARM_COUNT, Alarm::Icing as usize + 1);
+    }
+
+    #[test]
+    fn test_priority() {
+        let mut mixer = AlarmMixer::default();
+        assert!(mixer.current().is_none());
+
+        mixer.set(Alarm::WaterBallast, true);
+        mixer.set(Alarm::Gear, true);
+        assert!(mixer.current() == Some(Alarm::Gear));
+        mixer.set(Alarm::Flarm, true);
+        assert!(mixer.current() == Some(Alarm::Flarm));
+
+        // FLARM cannot be silenced, the gear alarm until the gear is down
+        assert!(!mixer.acknowledge());
+        mixer.set(Alarm::Flarm, false);
+        assert!(mixer.acknowledge());
+        assert!(mixer.current() == Some(Alarm::WaterBallast));
+        mixer.set(Alarm::Gear, true);
+        assert!(mixer.current() == Some(Alarm::WaterBallast));
+        mixer.set(Alarm::Gear, false);
+        mixer.set(Alarm::Gear, true);
+        assert!(mixer.current() == Some(Alarm::Gear));
+    }
+
+    #[test]
+    fn test_repeat_and_timeout() {
+        let mut mixer = AlarmMixer::default();
+        mixer.set(Alarm::LowBattery, true);
+        assert!(mixer.acknowledge());
+        assert!(mixer.current().is_none());
+        ticks(&mut mixer, 300);
+        assert!(mixer.current() == Some(Alarm::LowBattery));
+        mixer.set(Alarm::LowBattery, false);
+
+        mixer.set(Alarm::Icing, true);
+        ticks(&mut mixer, ICING_INFO_TIMEOUT as u32 - 1);
+        assert!(mixer.current() == Some(Alarm::Icing));
+        ticks(&mut mixer, 1);
+        assert!(mixer.current().is_none());
+    }
+}
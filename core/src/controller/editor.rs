@@ -163,7 +163,7 @@ pub fn key_action(key_event: &mut KeyEvent, cm: &mut CoreModel, cc: &mut CoreCon
 
 pub fn activate_editable(editable: Editable, cm: &mut CoreModel, cc: &mut CoreController) {
     cm.control.editor.target = editable;
-    cm.control.editor.params = editable.params();
+    cm.control.editor.params = editable.params(cm);
     cm.control.editor.content = editable.content(cm, cc);
     cm.control.editor.enter_pushed = false;
     if cm.config.display_active == DisplayActive::Menu {
@@ -207,7 +207,9 @@ impl Editor {
         Editor {
             target: Editable::None,
             mode: EditMode::Off,
-            params: Editable::None.params(),
+            params: Params::String(StringParams {
+                content: TString::new(),
+            }),
             content: Content::String(TString::new()),
             enter_pushed: false,
         }
@@ -217,7 +219,7 @@ impl Editor {
         self.target.name()
     }
 
-    pub fn get_value_line(&self) -> TString<20> {
-        self.target.content_as_str(self.content)
+    pub fn get_value_line(&self, cm: &CoreModel) -> TString<20> {
+        self.target.content_as_str(cm, self.content, &self.params)
     }
 }
@@ -1,13 +1,103 @@
-use crate::{model::CoreModel, utils::DeviceEvent};
+use crate::{
+    controller::{
+        helpers::{BinaryTransfer, TransferAction},
+        object_id,
+    },
+    model::{CoreModel, SensorboxUpdate},
+    utils::{DeviceEvent, FwImage, HwVersion, SwVersion},
+    Frame, GenericFrame, IdleEvent, SdCardCmd,
+};
 
-pub struct SwUpdateController {}
+pub struct SwUpdateController {
+    sensorbox_checked: bool,
+    sensorbox_vda: u16,
+    sensorbox_image: Option<FwImage>,
+    transfer: BinaryTransfer,
+}
 
 impl SwUpdateController {
     pub fn new() -> Self {
-        SwUpdateController {}
+        SwUpdateController {
+            sensorbox_checked: false,
+            sensorbox_vda: 0,
+            sensorbox_image: None,
+            transfer: BinaryTransfer::default(),
+        }
     }
 
     pub fn device_action(&mut self, core_model: &mut CoreModel, _device_event: &DeviceEvent) {
         core_model.control.firmware_update_state = DeviceEvent::UploadInProgress;
     }
+
+    /// A device reported its versions, look for a sensorbox update once after power on
+    pub fn versions_received(
+        &mut self,
+        core_model: &CoreModel,
+        vda: u16,
+        hw_version: HwVersion,
+        sw_version: SwVersion,
+    ) -> Option<IdleEvent> {
+        let is_sensor = core_model
+            .can_bus
+            .devices()
+            .any(|d| d.vda == vda && d.object_id == object_id::SENSOR);
+        if !is_sensor || self.sensorbox_checked {
+            return None;
+        }
+        self.sensorbox_checked = true;
+        self.sensorbox_vda = vda;
+        Some(IdleEvent::SdCardItem(SdCardCmd::SensorboxCheck(
+            hw_version, sw_version,
+        )))
+    }
+
+    /// Events of the sensorbox update, the own update is not affected
+    pub fn sensorbox_action(&mut self, core_model: &mut CoreModel, device_event: &DeviceEvent) {
+        match device_event {
+            DeviceEvent::SensorboxFwAvailable(image) => {
+                // the pilot has to confirm the update first
+                self.sensorbox_image = Some(*image);
+                let offer = SensorboxUpdate::Offered(image.sw_version);
+                core_model.control.sensorbox_update = Some(offer);
+            }
+            DeviceEvent::SensorboxFwBlock(block) => self.transfer.block_read(block),
+            _ => (),
+        }
+    }
+
+    /// Start the offered sensorbox update
+    pub fn sensorbox_start(&mut self, core_model: &mut CoreModel) {
+        if let Some(image) = self.sensorbox_image.take() {
+            self.transfer.start(self.sensorbox_vda, image.len);
+            core_model.control.sensorbox_update = Some(SensorboxUpdate::Running(0));
+        }
+    }
+
+    pub fn frame_received(&mut self, frame: &GenericFrame) {
+        self.transfer.frame_received(frame);
+    }
+
+    /// Step the transfer of the sensorbox image, returns the frame to send if any
+    pub fn tick_1ms(&mut self, core_model: &mut CoreModel) -> (Option<Frame>, Option<IdleEvent>) {
+        match self.transfer.tick_1ms() {
+            TransferAction::None => (None, None),
+            TransferAction::Send(frame) => (Some(frame), None),
+            TransferAction::ReadBlock(offset) => {
+                let progress = self.transfer.progress();
+                core_model.control.sensorbox_update = Some(SensorboxUpdate::Running(progress));
+                (
+                    None,
+                    Some(IdleEvent::SdCardItem(SdCardCmd::SensorboxRead(offset))),
+                )
+            }
+            TransferAction::Finished => {
+                core_model.control.sensorbox_update = None;
+                (None, None)
+            }
+            TransferAction::Failed => {
+                core_model.control.sensorbox_update = Some(SensorboxUpdate::Failed);
+                (None, None)
+            }
+        }
+    }
 }
@@ -0,0 +1,231 @@
+use super::{QNH_MAX_HPA, QNH_MIN_HPA};
+use crate::{
+    flight_physics::qnh_from_elevation,
+    model::AutoQnhMode,
+    system_of_units::{Float, Length, Pressure},
+};
+
+/// Satellites needed at least, otherwise the GNSS altitude is not trustworthy
+const MIN_SATS: u8 = 6;
+
+/// Seconds the GNSS altitude is averaged, it wanders for some time after the first fix
+const GNSS_SETTLING_SECS: u16 = 60;
+
+/// Seconds the static pressure is averaged with a known elevation
+const ELEVATION_SETTLING_SECS: u16 = 10;
+
+/// QNH from the field elevation after power-up
+///
+/// On the ground, the static pressure and the GNSS altitude are averaged while the GNSS settles.
+/// Then the QNH is calculated, at which the altitude equals the field elevation. Instead of the
+/// GNSS altitude, the elevation entered by the pilot can be used. The QNH is proposed once and
+/// never after take-off.
+#[derive(Default)]
+pub struct AutoQnh {
+    mode: Option<AutoQnhMode>, // of the running average
+    pressure_sum: Float,       // hPa
+    altitude_sum: Float,       // m
+    count: u16,                // averaged samples
+    done: bool,                // proposed or took off
+}
+
+impl AutoQnh {
+    /// Adds a sample every second, returns the proposed QNH and the field elevation once
+    pub fn update(
+        &mut self,
+        mode: AutoQnhMode,
+        flying: bool,
+        pressure: Option<Pressure>,
+        gnss_altitude: Option<Length>,
+        gps_sats: u8,
+        elevation: Length,
+    ) -> Option<(Pressure, Length)> {
+        self.done |= flying;
+        if self.done {
+            return None;
+        }
+        if self.mode != Some(mode) {
+            self.mode = Some(mode);
+            self.restart();
+        }
+
+        let pressure = match pressure {
+            Some(pressure) => pressure,
+            None => {
+                // no static pressure from the sensorbox, so the average starts again
+                self.restart();
+                return None;
+            }
+        };
+        let settling_secs = match mode {
+            AutoQnhMode::Off => return None,
+            AutoQnhMode::Gnss => match gnss_altitude {
+                Some(altitude) if gps_sats >= MIN_SATS => {
+                    self.altitude_sum += altitude.to_m();
+                    GNSS_SETTLING_SECS
+                }
+                _ => {
+                    // the fix is lost, so the average starts again
+                    self.restart();
+                    return None;
+                }
+            },
+            AutoQnhMode::Elevation => ELEVATION_SETTLING_SECS,
+        };
+        self.pressure_sum += pressure.to_hpa();
+        self.count += 1;
+        if self.count < settling_secs {
+            return None;
+        }
+
+        self.done = true;
+        let elevation = match mode {
+            AutoQnhMode::Gnss => Length::from_m(self.altitude_sum / self.count as Float),
+            _ => elevation,
+        };
+        let pressure = Pressure::from_hpa(self.pressure_sum / self.count as Float);
+        let qnh = qnh_from_elevation(pressure, elevation);
+        (QNH_MIN_HPA..=QNH_MAX_HPA)
+            .contains(&qnh.to_hpa())
+            .then_some((qnh, elevation))
+    }
+
+    fn restart(&mut self) {
+        self.pressure_sum = 0.0;
+        self.altitude_sum = 0.0;
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        flight_physics::pressure,
+        system_of_units::{FloatToLength, FloatToPressure},
+    };
+
+    // Static pressure on a field at the elevation in m with the QNH in hPa
+    fn static_pressure(elevation: Float, qnh: Float) -> Pressure {
+        pressure(elevation.m()) + (qnh.hpa() - Pressure::AT_NN())
+    }
+
+    #[test]
+    fn test_gnss_elevation() {
+        let mut auto_qnh = AutoQnh::default();
+        let static_pressure = static_pressure(480.0, 1022.0);
+        for t in 0..GNSS_SETTLING_SECS {
+            // the GNSS altitude wanders around the elevation
+            let altitude = (480.0 + if t % 2 == 0 { 8.0 } else { -8.0 }).m();
+            let sats = if t < 5 { 4 } else { 9 };
+            let result = auto_qnh.update(
+                AutoQnhMode::Gnss,
+                false,
+                Some(static_pressure),
+                Some(altitude),
+                sats,
+                0.0.m(),
+            );
+            assert!(result.is_none());
+        }
+        // the samples with too few satellites are not counted
+        let mut result = None;
+        for _ in 0..5 {
+            result = result.or(auto_qnh.update(
+                AutoQnhMode::Gnss,
+                false,
+                Some(static_pressure),
+                Some(480.0.m()),
+                9,
+                0.0.m(),
+            ));
+        }
+        let (qnh, elevation) = result.unwrap();
+        assert!((qnh.to_hpa() - 1022.0).abs() < 0.2);
+        assert!((elevation.to_m() - 480.0).abs() < 0.5);
+
+        // the QNH is proposed only once
+        let result = auto_qnh.update(
+            AutoQnhMode::Gnss,
+            false,
+            Some(static_pressure),
+            Some(480.0.m()),
+            9,
+            0.0.m(),
+        );
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_pilot_elevation() {
+        let mut auto_qnh = AutoQnh::default();
+        let static_pressure = static_pressure(1200.0, 998.0);
+        let mut proposals = 0;
+        for _ in 0..2 * ELEVATION_SETTLING_SECS {
+            if let Some((qnh, elevation)) = auto_qnh.update(
+                AutoQnhMode::Elevation,
+                false,
+                Some(static_pressure),
+                None,
+                0,
+                1200.0.m(),
+            ) {
+                assert!((qnh.to_hpa() - 998.0).abs() < 0.2);
+                assert_eq!(elevation.to_m(), 1200.0);
+                proposals += 1;
+            }
+        }
+        assert_eq!(proposals, 1);
+
+        // an implausible QNH, e.g. from a wrong elevation, is not proposed
+        let mut auto_qnh = AutoQnh::default();
+        for _ in 0..ELEVATION_SETTLING_SECS {
+            let result = auto_qnh.update(
+                AutoQnhMode::Elevation,
+                false,
+                Some(static_pressure),
+                None,
+                0,
+                3500.0.m(),
+            );
+            assert!(result.is_none());
+        }
+    }
+
+    #[test]
+    fn test_no_static_pressure() {
+        let mut auto_qnh = AutoQnh::default();
+        let static_pressure = static_pressure(600.0, 1005.0);
+        let mut update =
+            |pressure| auto_qnh.update(AutoQnhMode::Elevation, false, pressure, None, 0, 600.0.m());
+
+        // only the samples after the first static pressure of the sensorbox are averaged
+        for _ in 0..ELEVATION_SETTLING_SECS {
+            assert!(update(None).is_none());
+        }
+        for _ in 0..ELEVATION_SETTLING_SECS - 1 {
+            assert!(update(Some(static_pressure)).is_none());
+        }
+        let (qnh, _) = update(Some(static_pressure)).unwrap();
+        assert!((qnh.to_hpa() - 1005.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_no_proposal_in_flight() {
+        let mut auto_qnh = AutoQnh::default();
+        let static_pressure = static_pressure(300.0, 1013.0);
+        let mut update =
+            |mode, flying| auto_qnh.update(mode, flying, Some(static_pressure), None, 0, 300.0.m());
+        for _ in 0..ELEVATION_SETTLING_SECS {
+            assert!(update(AutoQnhMode::Off, false).is_none());
+        }
+        for _ in 0..ELEVATION_SETTLING_SECS - 1 {
+            assert!(update(AutoQnhMode::Elevation, false).is_none());
+        }
+        // after take-off, the QNH is not changed anymore, even after landing
+        assert!(update(AutoQnhMode::Elevation, true).is_none());
+        for _ in 0..ELEVATION_SETTLING_SECS {
+            assert!(update(AutoQnhMode::Elevation, false).is_none());
+        }
+    }
+}
@@ -0,0 +1,465 @@
+use crate::{stm32_crc_update, CanFrame, Frame, FwBlock, GenericFrame, GenericId, FW_BLOCK_SIZE};
+
+/// Commands of the binary transfer, the first byte of each datagram
+mod cmd {
+    pub const START: u8 = 1; // u8 cmd, u8 vda, u16 reserved, u32 image length
+    pub const BLOCK: u8 = 2; // u8 cmd, u8 reserved, u16 block no, u16 block length
+    pub const DATA: u8 = 3; // u8 cmd, u8 frame no, up to 6 bytes of data
+    pub const END: u8 = 4; // u8 cmd, u8 reserved, u16 reserved, u32 crc
+    pub const ACK: u8 = 0x80; // u8 cmd, u8 acknowledged cmd, u8 status, u8 reserved, u16 block no
+}
+
+/// Status of an acknowledgement
+mod status {
+    pub const OK: u8 = 0;
+    pub const RETRY: u8 = 1;
+}
+
+const DATA_PER_FRAME: usize = 6;
+const FRAMES_PER_BLOCK: u8 = FW_BLOCK_SIZE.div_ceil(DATA_PER_FRAME) as u8;
+const TIMEOUT_MS: u16 = 500;
+const MAX_RETRIES: u8 = 5;
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    Idle,
+    Start,         // START sent, wait for acknowledgement
+    ReadBlock,     // wait for the next block of the image
+    SendBlock(u8), // send BLOCK (0) and the DATA frames (1..)
+    WaitForAck,    // wait for acknowledgement of the block
+    End,           // END sent, wait for acknowledgement
+    Finished,
+    Failed,
+}
+
+/// What the controller has to do next
+pub enum TransferAction {
+    None,
+    Send(Frame),
+    ReadBlock(u32),
+    Finished,
+    Failed,
+}
+
+/// Transfer of a firmware image to another device via GenericId::BinaryTransfer
+///
+/// The transfer starts with START, which contains the address of the receiver and the length of
+/// the image. Then the image is sent in blocks of FW_BLOCK_SIZE bytes. Each block consists of a
+/// BLOCK header and the DATA frames and has to be acknowledged by the receiver. Finally END
+/// contains the CRC of the complete image (stm32_crc, little endian words, the last word is
+/// padded with 0xff). Missing acknowledgements lead to a repetition after TIMEOUT_MS, after
+/// MAX_RETRIES repetitions the transfer is aborted.
+///
+/// The state machine is driven by tick_1ms(), which sends at most one frame per call. The image
+/// data is requested block by block, because it is read from the SD card in the idle loop.
+///
+/// The receiver in the sensorbox firmware has to implement exactly this frame layout, which is
+/// pinned byte by byte in test_wire_format.
+pub struct BinaryTransfer {
+    state: State,
+    vda: u16,
+    len: u32,
+    offset: u32, // offset of the current block
+    block: FwBlock,
+    crc: u32, // crc of all acknowledged blocks
+    pending: bool,
+    timeout: u16,
+    retries: u8,
+}
+
+impl Default for BinaryTransfer {
+    fn default() -> Self {
+        BinaryTransfer {
+            state: State::Idle,
+            vda: 0,
+            len: 0,
+            offset: 0,
+            block: FwBlock::new(0, &[]),
+            crc: 0xffff_ffff,
+            pending: false,
+            timeout: 0,
+            retries: 0,
+        }
+    }
+}
+
+impl BinaryTransfer {
+    /// Start the transfer of an image with len bytes to the device with address vda
+    pub fn start(&mut self, vda: u16, len: u32) {
+        *self = BinaryTransfer {
+            vda,
+            len,
+            ..Default::default()
+        };
+        self.set_state(State::Start);
+    }
+
+    /// Progress in percent
+    pub fn progress(&self) -> u8 {
+        if self.len == 0 {
+            0
+        } else {
+            (self.offset as u64 * 100 / self.len as u64) as u8
+        }
+    }
+
+    pub fn tick_1ms(&mut self) -> TransferAction {
+        match self.state {
+            State::Idle => TransferAction::None,
+            State::Start => self.wait_for(|t| {
+                Frame::generic(
+                    CanFrame::empty_from_id(0x00)
+                        .push_u8(cmd::START)
+                        .push_u8(t.vda as u8)
+                        .push_u16(0)
+                        .push_u32(t.len),
+                    GenericId::BinaryTransfer as u16,
+                )
+            }),
+            State::ReadBlock => {
+                if self.pending {
+                    self.pending = false;
+                    self.timeout = TIMEOUT_MS;
+                    TransferAction::ReadBlock(self.offset)
+                } else {
+                    self.tick_timeout(State::ReadBlock)
+                }
+            }
+            State::SendBlock(frame_no) => {
+                let can_frame = if frame_no == 0 {
+                    CanFrame::empty_from_id(0x00)
+                        .push_u8(cmd::BLOCK)
+                        .push_u8(0)
+                        .push_u16(self.block_no())
+                        .push_u16(self.block.len as u16)
+                } else {
+                    let start = (frame_no as usize - 1) * DATA_PER_FRAME;
+                    let end = (start + DATA_PER_FRAME).min(self.block.len as usize);
+                    CanFrame::empty_from_id(0x00)
+                        .push_u8(cmd::DATA)
+                        .push_u8(frame_no - 1)
+                        .push_slice(&self.block.data[start..end])
+                };
+                let frames = (self.block.len as usize).div_ceil(DATA_PER_FRAME) as u8;
+                if frame_no >= frames.min(FRAMES_PER_BLOCK) {
+                    self.timeout = TIMEOUT_MS;
+                    self.state = State::WaitForAck;
+                } else {
+                    self.state = State::SendBlock(frame_no + 1);
+                }
+                TransferAction::Send(Frame::generic(can_frame, GenericId::BinaryTransfer as u16))
+            }
+            State::WaitForAck => self.tick_timeout(State::SendBlock(0)),
+            State::End => self.wait_for(|t| {
+                Frame::generic(
+                    CanFrame::empty_from_id(0x00)
+                        .push_u8(cmd::END)
+                        .push_u8(0)
+                        .push_u16(0)
+                        .push_u32(t.crc),
+                    GenericId::BinaryTransfer as u16,
+                )
+            }),
+            State::Finished => {
+                self.state = State::Idle;
+                TransferAction::Finished
+            }
+            State::Failed => {
+                self.state = State::Idle;
+                TransferAction::Failed
+            }
+        }
+    }
+
+    /// The requested block of the image was read
+    pub fn block_read(&mut self, block: &FwBlock) {
+        let expected = (self.len - self.offset).min(FW_BLOCK_SIZE as u32);
+        if self.state == State::ReadBlock
+            && block.offset == self.offset
+            && block.len as u32 == expected
+        {
+            self.block = *block;
+            self.retries = 0;
+            self.state = State::SendBlock(0);
+        }
+    }
+
+    /// Evaluate the acknowledgements of the receiver
+    pub fn frame_received(&mut self, frame: &GenericFrame) {
+        if frame.can_frame.vda() != self.vda || frame.can_frame.dlc() < 6 {
+            return;
+        }
+        let mut rdr = frame.can_frame.reader();
+        if rdr.pop_u8() != cmd::ACK {
+            return;
+        }
+        let acknowledged = rdr.pop_u8();
+        let ack_status = rdr.pop_u8();
+        let _ = rdr.pop_u8();
+        let block_no = rdr.pop_u16();
+
+        match (self.state, acknowledged) {
+            (State::Start, cmd::START) => match ack_status {
+                status::OK => self.set_state(State::ReadBlock),
+                status::RETRY => self.retry(State::Start),
+                _ => self.state = State::Failed,
+            },
+            (State::WaitForAck, cmd::BLOCK) if block_no == self.block_no() => match ack_status {
+                status::OK => {
+                    self.crc = stm32_crc_update(self.crc, &block_words(&self.block));
+                    self.offset += self.block.len as u32;
+                    if self.offset >= self.len {
+                        self.set_state(State::End);
+                    } else {
+                        self.set_state(State::ReadBlock);
+                    }
+                }
+                status::RETRY => self.retry(State::SendBlock(0)),
+                _ => self.state = State::Failed,
+            },
+            (State::End, cmd::END) => match ack_status {
+                status::OK => self.state = State::Finished,
+                _ => self.state = State::Failed,
+            },
+            _ => (),
+        }
+    }
+
+    fn block_no(&self) -> u16 {
+        (self.offset / FW_BLOCK_SIZE as u32) as u16
+    }
+
+    fn set_state(&mut self, state: State) {
+        self.state = state;
+        self.pending = true;
+        self.retries = 0;
+    }
+
+    fn retry(&mut self, state: State) {
+        self.retries += 1;
+        if self.retries > MAX_RETRIES {
+            self.state = State::Failed;
+        } else {
+            self.state = state;
+            self.pending = true;
+        }
+    }
+
+    // Send the frame if pending, otherwise wait for the acknowledgement
+    fn wait_for(&mut self, frame: fn(&Self) -> Frame) -> TransferAction {
+        if self.pending {
+            self.pending = false;
+            self.timeout = TIMEOUT_MS;
+            TransferAction::Send(frame(self))
+        } else {
+            self.tick_timeout(self.state)
+        }
+    }
+
+    fn tick_timeout(&mut self, retry_state: State) -> TransferAction {
+        self.timeout = self.timeout.saturating_sub(1);
+        if self.timeout == 0 {
+            self.retry(retry_state);
+        }
+        TransferAction::None
+    }
+}
+
+// The CRC is calculated over little endian words, the last word is padded with 0xff
+fn block_words(block: &FwBlock) -> heapless::Vec<u32, { FW_BLOCK_SIZE / 4 }> {
+    block
+        .as_slice()
+        .chunks(4)
+        .map(|chunk| {
+            let mut word = [0xff_u8; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u32::from_le_bytes(word)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stm32_crc;
+    use std::vec::Vec;
+
+    const VDA: u16 = 5;
+
+    // Receiver of the image like the sensorbox
+    struct MockDevice {
+        image: Vec<u8>,
+        block: Vec<u8>,
+        block_no: u16,
+        block_len: usize,
+        lost_acks: usize,
+    }
+
+    impl MockDevice {
+        fn new(lost_acks: usize) -> Self {
+            MockDevice {
+                image: Vec::new(),
+                block: Vec::new(),
+                block_no: 0,
+                block_len: 0,
+                lost_acks,
+            }
+        }
+
+        fn receive(&mut self, frame: &Frame) -> Option<GenericFrame> {
+            let can_frame = frame.basic_frame();
+            let data = can_frame.data();
+            let mut rdr = can_frame.reader();
+            let command = rdr.pop_u8();
+            let _ = rdr.pop_u8();
+            let block_no = rdr.pop_u16();
+            let ack = match command {
+                cmd::START => Some((cmd::START, status::OK, 0)),
+                cmd::BLOCK => {
+                    self.block_no = block_no;
+                    self.block_len = rdr.pop_u16() as usize;
+                    self.block.clear();
+                    None
+                }
+                cmd::DATA => {
+                    self.block.extend_from_slice(&data[2..]);
+                    if self.block.len() == self.block_len {
+                        let offset = self.block_no as usize * FW_BLOCK_SIZE;
+                        self.image.truncate(offset);
+                        self.image.extend_from_slice(&self.block);
+                        Some((cmd::BLOCK, status::OK, self.block_no))
+                    } else {
+                        None
+                    }
+                }
+                cmd::END => {
+                    let crc = u32::from_le_bytes(data[4..8].try_into().unwrap());
+                    let mut padded = self.image.clone();
+                    padded.resize(self.image.len().div_ceil(4) * 4, 0xff);
+                    let words: Vec<u32> = padded
+                        .chunks(4)
+                        .map(|w| u32::from_le_bytes(w.try_into().unwrap()))
+                        .collect();
+                    let ok = stm32_crc(&words) == crc;
+                    Some((cmd::END, if ok { status::OK } else { 2 }, 0))
+                }
+                _ => None,
+            };
+            let (command, ack_status, block_no) = ack?;
+            if self.lost_acks > 0 {
+                self.lost_acks -= 1;
+                return None;
+            }
+            Some(GenericFrame {
+                can_frame: CanFrame::empty_from_id((VDA << 4) + 0x400 + 3)
+                    .push_u8(cmd::ACK)
+                    .push_u8(command)
+                    .push_u8(ack_status)
+                    .push_u8(0)
+                    .push_u16(block_no),
+                generic_id: GenericId::BinaryTransfer as u16,
+            })
+        }
+    }
+
+    fn transfer(image: &[u8], device: &mut MockDevice) -> bool {
+        let mut transfer = BinaryTransfer::default();
+        transfer.start(VDA, image.len() as u32);
+        for _ in 0..100_000 {
+            match transfer.tick_1ms() {
+                TransferAction::None => (),
+                TransferAction::Send(frame) => {
+                    if let Some(ack) = device.receive(&frame) {
+                        transfer.frame_received(&ack);
+                    }
+                }
+                TransferAction::ReadBlock(offset) => {
+                    let start = offset as usize;
+                    let end = (start + FW_BLOCK_SIZE).min(image.len());
+                    transfer.block_read(&FwBlock::new(offset, &image[start..end]));
+                }
+                TransferAction::Finished => return true,
+                TransferAction::Failed => return false,
+            }
+        }
+        false
+    }
+
+    fn image(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i * 7 % 251) as u8).collect()
+    }
+
+    #[test]
+    fn test_transfer() {
+        let image = image(1001);
+        let mut device = MockDevice::new(0);
+        assert!(transfer(&image, &mut device));
+        assert_eq!(device.image, image);
+    }
+
+    #[test]
+    fn test_transfer_retry() {
+        // the first acknowledgements are lost, the frames are repeated
+        let image = image(FW_BLOCK_SIZE * 3);
+        let mut device = MockDevice::new(MAX_RETRIES as usize);
+        assert!(transfer(&image, &mut device));
+        assert_eq!(device.image, image);
+    }
+
+    #[test]
+    fn test_transfer_failed() {
+        let image = image(500);
+        let mut device = MockDevice::new(MAX_RETRIES as usize + 1);
+        assert!(!transfer(&image, &mut device));
+    }
+
+    // Drive the transfer until the next frame is sent and return its data bytes
+    fn next_frame(transfer: &mut BinaryTransfer, image: &[u8]) -> Vec<u8> {
+        for _ in 0..1000 {
+            match transfer.tick_1ms() {
+                TransferAction::Send(frame) => return frame.basic_frame().data().to_vec(),
+                TransferAction::ReadBlock(offset) => {
+                    transfer.block_read(&FwBlock::new(offset, &image[offset as usize..]))
+                }
+                _ => (),
+            }
+        }
+        panic!("no frame sent");
+    }
+
+    fn ack(data: &[u8]) -> GenericFrame {
+        GenericFrame {
+            can_frame: CanFrame::empty_from_id((VDA << 4) + 0x400 + 3).push_slice(data),
+            generic_id: GenericId::BinaryTransfer as u16,
+        }
+    }
+
+    #[test]
+    fn test_wire_format() {
+        let image: Vec<u8> = (0x10..0x1a).collect();
+        let mut transfer = BinaryTransfer::default();
+        transfer.start(VDA, image.len() as u32);
+
+        assert_eq!(next_frame(&mut transfer, &image), [1, 5, 0, 0, 10, 0, 0, 0]);
+        transfer.frame_received(&ack(&[0x80, 1, 0, 0, 0, 0]));
+
+        assert_eq!(next_frame(&mut transfer, &image), [2, 0, 0, 0, 10, 0]);
+        assert_eq!(
+            next_frame(&mut transfer, &image),
+            [3, 0, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15]
+        );
+        assert_eq!(
+            next_frame(&mut transfer, &image),
+            [3, 1, 0x16, 0x17, 0x18, 0x19]
+        );
+        transfer.frame_received(&ack(&[0x80, 2, 0, 0, 0, 0]));
+
+        let crc = stm32_crc(&[0x1312_1110, 0x1716_1514, 0xffff_1918]).to_le_bytes();
+        assert_eq!(
+            next_frame(&mut transfer, &image),
+            [4, 0, 0, 0, crc[0], crc[1], crc[2], crc[3]]
+        );
+        transfer.frame_received(&ack(&[0x80, 4, 0, 0, 0, 0]));
+        assert!(matches!(transfer.tick_1ms(), TransferAction::Finished));
+    }
+}
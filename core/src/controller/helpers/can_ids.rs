@@ -11,6 +11,9 @@ pub enum SpecialId {
     Sound = 0,
     VoltTemp = 1,
     AvgClimbRates = 2,
+    FlightState = 3,
+    SyncSettings = 4,
+    ConfigValue = 5,
     #[default]
     Ignore,
 }
@@ -34,6 +37,32 @@ pub enum RemoteConfig {
     Set,
 }
 
+/// Command in byte 3 of a SetSysSetting frame of the frontend range
+///
+/// A push distributes a changed value between the frontends and is not answered. Get and set
+/// are requests of a configuration tool, they are answered with SpecialId::ConfigValue. A set
+/// request carries the value as f32 in bytes 4..8 for all ids.
+#[derive(FromPrimitive, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum SysSettingCmd {
+    Push = 0,
+    Get = 1,
+    Set = 2,
+    #[default]
+    Ignore,
+}
+
+/// Status of the answer to a get or set request
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(u8)]
+pub enum ConfigStatus {
+    Ok = 0,
+    UnknownId = 1,
+    ReadOnly = 2,
+    InvalidValue = 3,
+    NotAvailable = 4, // the owner of the id, e.g. the sensor box, is not on the bus
+}
+
 #[allow(dead_code)]
 #[derive(FromPrimitive)]
 #[repr(u16)]
@@ -77,6 +106,13 @@ pub enum CanConfigId {
     CmdReset = 0x3005,
 }
 
+impl CanConfigId {
+    /// Ids in 0x2000.. and 0x3000.. belong to the sensor box and are answered there
+    pub fn is_sensor_box(self) -> bool {
+        self as u16 >= CanConfigId::SensTiltRoll as u16
+    }
+}
+
 impl From<PersistenceId> for CanConfigId {
     fn from(value: PersistenceId) -> Self {
         match value {
@@ -103,6 +139,22 @@ pub mod object_id {
     pub const SENSOR: u16 = 2;
     pub const GPS: u16 = 3;
     pub const FRONTEND: u16 = 4;
+    pub const REMOTE: u16 = 5;
+    pub const ENGINE: u16 = 6;
+}
+
+#[rustfmt::skip]
+#[allow(unused)]
+pub mod remote {
+    pub const BUTTON: u16 = 0;              // u8 button, u8 state (1 pressed, 0 released)
+}
+
+#[rustfmt::skip]
+#[allow(unused)]
+pub mod engine {
+    pub const MOTOR: u16 = 0;               // f32 rpm, f32 motor temperature °C
+    pub const BATTERY: u16 = 1;             // f32 voltage V, f32 current A
+    pub const BATTERY_STATE: u16 = 2;       // f32 state of charge %, f32 battery temperature °C
 }
 
 #[rustfmt::skip]
@@ -181,6 +233,10 @@ pub mod audio_legacy {
 #[allow(unused)]
 pub mod frontend_masster {
     pub const AVG_CLIMB_RATES: u16 = 0x282; // Climb rates fromt the virtual master device
+    pub const FLIGHT_STATE: u16 = 0x283;    // u8 fly mode, u8 vario mode, u16 reserved,
+                                            // f32 MacCready suggestion (NaN if not available)
+    pub const SYNC_SETTINGS: u16 = 0x284;   // u8 user profile, u8 reserved, u16 reserved,
+                                            // u32 glider index
 }
 
 #[rustfmt::skip]
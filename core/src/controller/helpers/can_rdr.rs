@@ -1,12 +1,12 @@
 use crate::{
-    VarioMode, model::VarioModeControl,
+    VarioMode, model::{DataSource, VarioModeControl}, basic_config::{OAT_HUMIDITY_TIMEOUT, SENSOR_AIRSPEED_TIMEOUT, SENSOR_PRESSURE_TIMEOUT, SENSOR_TURN_RATE_TIMEOUT, SENSOR_WIND_TIMEOUT},
     controller::{
         helpers::{
-            can_ids::{gps, sensor, sensor_legacy},
-            frontend_masster, object_id, CanActive,
+            can_ids::{audio_legacy, engine, frontend_legacy, gps, remote, sensor, sensor_legacy},
+            frontend_masster, object_id, CanActive, ConfigStatus, RemoteConfig, SysSettingCmd,
         },
         persist, Echo,
-    }, into_range_0_360, into_range_180_180, model::{editable::Content, GpsState}, persist::set_vario_mode, AirSpeed, Angle, CanFrame, CoreController, CoreModel, F64ToCoord, FloatToAcceleration, FloatToAngularVelocity, FloatToDensity, FloatToLength, FloatToMass, FloatToPressure, FloatToSpeed, Frame, GenericFrame, GenericId, Latitude, Longitude, PersistenceId, SpecificFrame, Variant, DEGREE_PER_RAD
+    }, flight_physics::polar_store, into_range_0_360, into_range_180_180, model::{editable::Content, GpsState}, persist::set_vario_mode, AirSpeed, Angle, CanFrame, CoreController, CoreError, CoreModel, Event, F64ToCoord, FloatToAcceleration, FloatToAngularVelocity, FloatToDensity, FloatToLength, FloatToMass, FloatToPressure, FloatToSpeed, FloatToTemperature, Frame, FlyMode, GenericFrame, GenericId, Latitude, Longitude, PersistenceId, SpecificFrame, HwVersion, SwVersion, Variant, DEGREE_PER_RAD
 };
 use embedded_graphics::prelude::AngleUnit;
 
@@ -23,11 +23,44 @@ impl CoreController {
 
     fn can_frame_read_generic(&mut self, cm: &mut CoreModel, frame: &GenericFrame) {
         let mut rdr = frame.can_frame.reader();
-        #[allow(clippy::single_match)]
         match GenericId::from(frame.generic_id) {
+            GenericId::HwFwVersion => {
+                // u32 hardware version, u32 firmware version
+                let hw_version = HwVersion::from_bytes(rdr.pop_u32().to_le_bytes());
+                let sw_version = SwVersion {
+                    version: rdr.pop_u32().to_le_bytes(),
+                };
+                cm.can_bus
+                    .set_versions(frame.can_frame.vda(), hw_version, sw_version);
+                if let Some(idle_event) = self.sw_update.versions_received(
+                    cm,
+                    frame.can_frame.vda(),
+                    hw_version,
+                    sw_version,
+                ) {
+                    self.send_idle_event(idle_event);
+                }
+            }
+            GenericId::BinaryTransfer => self.sw_update.frame_received(frame),
             GenericId::SetSysSetting => {
-                let config_id = CanConfigId::from(rdr.pop_u16());
-                self.can_frame_read_sys_config_value(cm, config_id, &frame.can_frame)
+                let raw_id = rdr.pop_u16();
+                let config_id = CanConfigId::from(raw_id);
+                match SysSettingCmd::from(frame.can_frame.read_u8(3)) {
+                    SysSettingCmd::Push => {
+                        let vda = frame.can_frame.vda();
+                        let is_master = cm.can_bus.is_sync_master();
+                        if self.dual_seat.accept_edit(is_master, vda, config_id) {
+                            self.can_frame_read_sys_config_value(cm, config_id, &frame.can_frame)
+                        } else if let Some(frame) = cm.can_frame_sys_config(config_id) {
+                            // the master wins a simultaneous edit, send the own value again
+                            let _ = self.p_tx_frames.enqueue(frame);
+                        }
+                    }
+                    cmd @ (SysSettingCmd::Get | SysSettingCmd::Set) => {
+                        self.can_frame_read_config_request(cm, raw_id, cmd, &frame.can_frame)
+                    }
+                    SysSettingCmd::Ignore => (),
+                }
             }
             _ => (),
         }
@@ -38,10 +71,75 @@ impl CoreController {
         match frame.object_id {
             object_id::SENSOR => self.can_frame_read_sensor_values(cm, frame),
             object_id::GPS => self.can_frame_read_gps_values(cm, frame),
+            object_id::REMOTE => self.can_frame_read_remote(cm, frame),
+            object_id::ENGINE => self.can_frame_read_engine(cm, frame),
             _ => (),
         }
     }
 
+    /// Answer a get or set request of a configuration tool
+    ///
+    /// Requests for the sensor box are forwarded to it, the sensor box answers them itself.
+    fn can_frame_read_config_request(
+        &mut self,
+        cm: &mut CoreModel,
+        raw_id: u16,
+        cmd: SysSettingCmd,
+        frame: &CanFrame,
+    ) {
+        let config_id = CanConfigId::from(raw_id);
+        let value = frame.read_f32(4);
+        let status = if config_id as u16 != raw_id || matches!(config_id, CanConfigId::Ignore) {
+            ConfigStatus::UnknownId
+        } else if config_id.is_sensor_box() {
+            if cm.can_bus.devices().any(|d| d.object_id == object_id::SENSOR) {
+                let get_set = match cmd {
+                    SysSettingCmd::Set => RemoteConfig::Set,
+                    _ => RemoteConfig::Get,
+                };
+                let frame = cm.can_frame_sensor_box_config(config_id, get_set, value);
+                let _ = self.p_tx_frames.enqueue(frame);
+                return;
+            }
+            ConfigStatus::NotAvailable
+        } else if cmd == SysSettingCmd::Set {
+            self.set_sys_config_value(cm, config_id, value)
+        } else {
+            ConfigStatus::Ok
+        };
+
+        let value = match status {
+            ConfigStatus::Ok => cm.sys_config_value(config_id),
+            _ => None,
+        };
+        let frame = cm.can_frame_config_value(raw_id, status, value);
+        let _ = self.p_tx_frames.enqueue(frame);
+    }
+
+    fn set_sys_config_value(
+        &mut self,
+        cm: &mut CoreModel,
+        config_id: CanConfigId,
+        value: f32,
+    ) -> ConfigStatus {
+        if let CanConfigId::VarioMode = config_id {
+            if value != 0.0 && value != 1.0 {
+                return ConfigStatus::InvalidValue;
+            }
+            // only applied if the vario mode is controlled via CAN or NMEA
+            set_vario_mode(cm, self, VarioMode::from(value as u8), VarioModeControl::Can);
+            return ConfigStatus::Ok;
+        }
+        match persist::config_value(cm, config_id, value) {
+            Ok((variant, id)) => {
+                persist::persist_set(self, cm, variant, id, Echo::NmeaAndCan);
+                ConfigStatus::Ok
+            }
+            Err(CoreError::OutOfRange) => ConfigStatus::InvalidValue,
+            Err(_) => ConfigStatus::ReadOnly, // VarioModeControl is only set locally
+        }
+    }
+
     fn can_frame_read_sys_config_value(
         &mut self,
         cm: &mut CoreModel,
@@ -160,6 +258,33 @@ impl CoreController {
             if let Some(thermal_climb_rate) = rdr.pop_f32() {
                 cm.calculated.thermal_climb_rate = thermal_climb_rate.m_s();
             }
+        } else if id == frontend_masster::FLIGHT_STATE {
+            cm.control.sync_slave_ticks = 3; // 3s timeout for slave mode
+            cm.control.fly_mode = FlyMode::from(rdr.pop_u8());
+            let vario_mode = VarioMode::from(rdr.pop_u8());
+            set_vario_mode(cm, self, vario_mode, VarioModeControl::Auto);
+            rdr.pop_u16();
+            cm.calculated.mc_suggestion = rdr.pop_f32().map(|mc| mc.m_s());
+        } else if id == frontend_masster::SYNC_SETTINGS {
+            let user_profile = rdr.pop_u8();
+            rdr.pop_u8();
+            rdr.pop_u16();
+            let glider_idx = rdr.pop_u32();
+            if user_profile != cm.config.user_profile {
+                // the profile switch resets the device, the glider follows after the restart
+                cm.config.user_profile = user_profile;
+                persist::user_profile(self, cm);
+            } else if glider_idx as i32 != cm.config.glider_idx
+                && (glider_idx as usize) < polar_store::POLARS.len()
+            {
+                persist::persist_set(
+                    self,
+                    cm,
+                    Variant::U32(glider_idx),
+                    PersistenceId::Glider,
+                    Echo::None,
+                );
+            }
         } else {
             match id {
                 sensor_legacy::EULER_ANGLES => {
@@ -175,9 +300,11 @@ impl CoreController {
                     let tas = (rdr.pop_i16() as f32).km_h();
                     let ias = (rdr.pop_i16() as f32).km_h();
                     cm.sensor.airspeed = AirSpeed::from_speeds(ias, tas);
+                    cm.control.airspeed_ticks = SENSOR_AIRSPEED_TIMEOUT;
                 }
                 sensor_legacy::ATHMOSPHERE => {
                     cm.sensor.pressure = (rdr.pop_u32() as f32).n_m2();
+                    cm.control.pressure_ticks = SENSOR_PRESSURE_TIMEOUT;
                     cm.sensor.density = (rdr.pop_u32() as f32).g_m3();
                     cm.sensor
                         .pressure_altitude
@@ -223,6 +350,7 @@ impl CoreController {
                 sensor_legacy::TURN_COORD => {
                     cm.sensor.slip_angle = ((rdr.pop_i16() as f32) * 0.001).rad();
                     cm.sensor.turn_rate = ((rdr.pop_i16() as f32) * 0.001).rad_s();
+                    cm.control.turn_rate_ticks = SENSOR_TURN_RATE_TIMEOUT;
                     cm.sensor.nick_angle = ((rdr.pop_i16() as f32) * 0.001).rad();
                 }
                 sensor_legacy::VARIO => {
@@ -231,18 +359,33 @@ impl CoreController {
                     cm.control.can_devices |= CanActive::SensorboxLegacy as u32;
                 }
                 sensor_legacy::WIND => {
-                    cm.sensor
-                        .wind_vector
-                        .set_angle(((rdr.pop_i16() as f32) * 0.001).rad());
-                    cm.sensor
-                        .wind_vector
-                        .set_speed((rdr.pop_i16() as f32).km_h());
-                    cm.sensor
-                        .average_wind
-                        .set_angle(((rdr.pop_i16() as f32) * 0.001).rad());
-                    cm.sensor
-                        .average_wind
-                        .set_speed((rdr.pop_i16() as f32).km_h());
+                    cm.control.sensor_wind_ticks = SENSOR_WIND_TIMEOUT;
+                    if cm.control.wind_src == DataSource::Sensorbox {
+                        cm.sensor
+                            .wind_vector
+                            .set_angle(((rdr.pop_i16() as f32) * 0.001).rad());
+                        cm.sensor
+                            .wind_vector
+                            .set_speed((rdr.pop_i16() as f32).km_h());
+                        cm.sensor
+                            .average_wind
+                            .set_angle(((rdr.pop_i16() as f32) * 0.001).rad());
+                        cm.sensor
+                            .average_wind
+                            .set_speed((rdr.pop_i16() as f32).km_h());
+                    }
+                }
+                audio_legacy::TEMPERATURE | frontend_legacy::TEMPERATURE => {
+                    cm.sensor.oat = Some((rdr.pop_i32() as f32 * 0.001).deg_c());
+                    cm.control.oat_ticks = OAT_HUMIDITY_TIMEOUT;
+                }
+                audio_legacy::HUMIDY | frontend_legacy::HUMIDY => {
+                    cm.sensor.humidity = Some(rdr.pop_u32() as f32 * 0.001);
+                    cm.control.humidity_ticks = OAT_HUMIDITY_TIMEOUT;
+                }
+                audio_legacy::FLAPS_DATA => {
+                    // the switch pattern is not evaluated, the position is sufficient
+                    cm.sensor.flap_position = Some(rdr.pop_u16() as f32 * 0.01);
                 }
                 _ => (), // all other frames are ignored
             }
@@ -267,6 +410,7 @@ impl CoreController {
                 }
                 if let Some(turn_rate) = rdr.pop_f32() {
                     cm.sensor.turn_rate = turn_rate.rad_s();
+                    cm.control.turn_rate_ticks = SENSOR_TURN_RATE_TIMEOUT;
                 }
             }
             sensor::TAS_IAS => {
@@ -275,6 +419,7 @@ impl CoreController {
                 if tas.is_some() && ias.is_some() {
                     cm.sensor.airspeed =
                         AirSpeed::from_speeds(ias.unwrap().m_s(), tas.unwrap().m_s());
+                    cm.control.airspeed_ticks = SENSOR_AIRSPEED_TIMEOUT;
                 }
             }
             sensor::VARIO_AV_VARIO => {
@@ -288,14 +433,17 @@ impl CoreController {
                 }
             }
             sensor::WIND_DIR_SPEED => {
-                if let Some(wind_dir) = rdr.pop_f32() {
-                    cm.sensor.wind_vector.set_angle(wind_dir.rad());
-                }
-                if let Some(wind_speed) = rdr.pop_f32() {
-                    cm.sensor.wind_vector.set_speed(wind_speed.m_s());
+                cm.control.sensor_wind_ticks = SENSOR_WIND_TIMEOUT;
+                if cm.control.wind_src == DataSource::Sensorbox {
+                    if let Some(wind_dir) = rdr.pop_f32() {
+                        cm.sensor.wind_vector.set_angle(wind_dir.rad());
+                    }
+                    if let Some(wind_speed) = rdr.pop_f32() {
+                        cm.sensor.wind_vector.set_speed(wind_speed.m_s());
+                    }
                 }
             }
-            sensor::AV_WIND_DIR_SPEED => {
+            sensor::AV_WIND_DIR_SPEED if cm.control.wind_src == DataSource::Sensorbox => {
                 if let Some(avg_wind_dir) = rdr.pop_f32() {
                     cm.sensor.average_wind.set_angle(avg_wind_dir.rad());
                 }
@@ -306,6 +454,7 @@ impl CoreController {
             sensor::AMB_PRESS_AIR_DENS => {
                 if let Some(pressure) = rdr.pop_f32() {
                     cm.sensor.pressure = pressure.n_m2();
+                    cm.control.pressure_ticks = SENSOR_PRESSURE_TIMEOUT;
                     cm.sensor
                         .pressure_altitude
                         .set_static_pressure(cm.sensor.pressure);
@@ -354,6 +503,39 @@ impl CoreController {
         }
     }
 
+    fn can_frame_read_remote(&mut self, cm: &mut CoreModel, frame: &SpecificFrame) {
+        let mut rdr = frame.can_frame.reader();
+
+        if frame.specific_id == remote::BUTTON {
+            let button = rdr.pop_u8();
+            // the action is executed when the button is pressed
+            if rdr.pop_u8() == 1 {
+                self.event_handler(Event::RemoteItem(button), cm);
+            }
+        }
+    }
+
+    fn can_frame_read_engine(&mut self, cm: &mut CoreModel, frame: &SpecificFrame) {
+        let mut rdr = frame.can_frame.reader();
+
+        match frame.specific_id {
+            engine::MOTOR => {
+                cm.engine.rpm = rdr.pop_f32();
+                cm.engine.motor_temp = rdr.pop_f32().map(|temp| temp.deg_c());
+            }
+            engine::BATTERY => {
+                cm.engine.voltage = rdr.pop_f32();
+                cm.engine.current = rdr.pop_f32();
+            }
+            engine::BATTERY_STATE => {
+                cm.engine.soc = rdr.pop_f32();
+                cm.engine.battery_temp = rdr.pop_f32().map(|temp| temp.deg_c());
+            }
+            _ => return,
+        }
+        cm.engine.received();
+    }
+
     fn can_frame_read_gps_values(&mut self, cm: &mut CoreModel, frame: &SpecificFrame) {
         let mut rdr = frame.can_frame.reader();
 
@@ -1,5 +1,5 @@
 use crate::{
-    controller::helpers::RemoteConfig, model::editable::Content, CanFrame, CoreModel, Frame, GenericId, SpecialId, RAD_PER_DEGREE
+    controller::helpers::{frontend_legacy, ConfigStatus, RemoteConfig}, model::editable::Content, CanFrame, CoreModel, Frame, GenericId, SpecialId, RAD_PER_DEGREE
 };
 use byteorder::{ByteOrder, LittleEndian as LE};
 
@@ -23,7 +23,7 @@ impl CoreModel {
         Frame::specific(
             CanFrame::empty_from_id(0x00)
                 .push_u16(self.calculated.frequency)
-                .push_u16(self.config.snd_duty_cycle)
+                .push_u16(self.calculated.duty_cycle)
                 .push_u8(self.calculated.gain as u8)
                 .push_u8(if self.calculated.continuous { 1 } else { 0 }),
             SpecialId::Sound as u16,
@@ -51,6 +51,47 @@ impl CoreModel {
         )
     }
 
+    pub fn can_frame_flight_state(&self) -> Frame {
+        let mc_suggestion = match self.calculated.mc_suggestion {
+            Some(mc) => mc.to_m_s(),
+            None => f32::NAN,
+        };
+        Frame::specific(
+            CanFrame::empty_from_id(0x00)
+                .push_u8(self.control.fly_mode as u8)
+                .push_u8(self.control.vario_mode as u8)
+                .push_u16(0)
+                .push_f32(mc_suggestion),
+            SpecialId::FlightState as u16,
+            OBJECT_ID,
+        )
+    }
+
+    pub fn can_frame_sync_settings(&self) -> Frame {
+        Frame::specific(
+            CanFrame::empty_from_id(0x00)
+                .push_u8(self.config.user_profile)
+                .push_u8(0)
+                .push_u16(0)
+                .push_u32(self.config.glider_idx as u32),
+            SpecialId::SyncSettings as u16,
+            OBJECT_ID,
+        )
+    }
+
+    pub fn can_frame_flaps_status(&self) -> Option<Frame> {
+        let optimal = self.calculated.optimal_flap?;
+        let current = self.calculated.flap_setting.unwrap_or(optimal);
+        Some(Frame::Legacy(
+            CanFrame::empty_from_id(frontend_legacy::FLAPS_STATUS)
+                .push_u8(1)
+                .push_u8(current)
+                .push_u8(optimal)
+                .push_u8(if current != optimal { 1 } else { 0 })
+                .push_u8(100),
+        ))
+    }
+
     pub fn can_frame_sys_config(&self, config_id: CanConfigId) -> Option<Frame> {
         let mut data = [0u8; 6];
         match config_id {
@@ -95,6 +136,59 @@ impl CoreModel {
         ))
     }
 
+    /// Current value of a setting of the frontend range, None for unknown ids
+    pub fn sys_config_value(&self, config_id: CanConfigId) -> Option<f32> {
+        let value = match config_id {
+            CanConfigId::Volume => self.config.volume as f32,
+            CanConfigId::MacCready => self.config.mc_cready.to_m_s(),
+            CanConfigId::WaterBallast => self.glider_data.water_ballast.to_kg(),
+            CanConfigId::Bugs => self.glider_data.bugs,
+            CanConfigId::Qnh => self.sensor.pressure_altitude.qnh().to_hpa(),
+            CanConfigId::PilotWeight => self.glider_data.pilot_weight.to_kg(),
+            CanConfigId::VarioModeControl => self.control.vario_mode_control as u8 as f32,
+            CanConfigId::TcClimbRate => self.config.av2_climb_rate_tc,
+            CanConfigId::TcSpeedToFly => self.config.av_speed_to_fly_tc,
+            CanConfigId::VarioMode => self.control.vario_mode as u8 as f32,
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// Answer to a get or set request, the value is NaN if not available
+    pub fn can_frame_config_value(
+        &self,
+        config_id: u16,
+        status: ConfigStatus,
+        value: Option<f32>,
+    ) -> Frame {
+        Frame::specific(
+            CanFrame::empty_from_id(0x00)
+                .push_u16(config_id)
+                .push_u8(status as u8)
+                .push_u8(0)
+                .push_f32(value.unwrap_or(f32::NAN)),
+            SpecialId::ConfigValue as u16,
+            OBJECT_ID,
+        )
+    }
+
+    /// Request to the sensor box, it answers with sensor::CONFIG_VALUE
+    pub fn can_frame_sensor_box_config(
+        &self,
+        config_id: CanConfigId,
+        get_set: RemoteConfig,
+        value: f32,
+    ) -> Frame {
+        Frame::generic(
+            CanFrame::empty_from_id(0)
+                .push_u16(config_id as u16)
+                .push_u8(if get_set == RemoteConfig::Set { 1 } else { 0 })
+                .push_u8(0)
+                .push_f32(value),
+            GenericId::SetSysSetting as u16,
+        )
+    }
+
     pub fn can_frame_remote_config(
         &mut self,
         config_id: CanConfigId,
@@ -0,0 +1,125 @@
+use crate::controller::helpers::CanConfigId;
+use heapless::Vec;
+
+/// Flight settings sent to a frontend that joins the bus
+const SYNC_SETTINGS: &[CanConfigId] = &[
+    CanConfigId::MacCready,
+    CanConfigId::WaterBallast,
+    CanConfigId::Bugs,
+    CanConfigId::Qnh,
+    CanConfigId::PilotWeight,
+    CanConfigId::TcClimbRate,
+    CanConfigId::TcSpeedToFly,
+];
+
+/// 100ms ticks during which an own edit has precedence over an edit of the partner
+const EDIT_TICKS: u8 = 5;
+
+/// Synchronisation of the two frontends of a two-seater
+///
+/// The frontend with the lowest address is the master. This is the one that got the desired
+/// VDA in the arbitration of the CAN dispatcher, the other one got a replacement address. The
+/// master broadcasts its computed state, the slave mirrors it. Edits of the flight settings are
+/// exchanged in both directions via GenericId::SetSysSetting.
+///
+/// If both pilots edit the same setting at the same time, the master wins: it rejects the edit
+/// of the slave and sends its own value again. A frontend that joins the bus receives all
+/// flight settings of the master, one per 100ms tick.
+pub struct DualSeatSync {
+    partner: Option<u16>,
+    pending: usize,
+    edits: Vec<(u16, u8), 8>, // config id, remaining ticks
+}
+
+impl Default for DualSeatSync {
+    fn default() -> Self {
+        DualSeatSync {
+            partner: None,
+            pending: SYNC_SETTINGS.len(),
+            edits: Vec::new(),
+        }
+    }
+}
+
+impl DualSeatSync {
+    /// Track the partner, the settings are sent when it appears
+    pub fn tick_1s(&mut self, partner: Option<u16>, is_master: bool) {
+        if partner.is_some() && partner != self.partner && is_master {
+            self.pending = 0;
+        }
+        self.partner = partner;
+    }
+
+    /// Returns the next setting to send to the partner
+    pub fn tick_100ms(&mut self) -> Option<CanConfigId> {
+        for edit in self.edits.iter_mut() {
+            edit.1 -= 1;
+        }
+        self.edits.retain(|edit| edit.1 > 0);
+
+        let config_id = SYNC_SETTINGS.get(self.pending).copied();
+        if config_id.is_some() {
+            self.pending += 1;
+        }
+        config_id
+    }
+
+    /// A setting was changed by this frontend and sent to the bus
+    pub fn local_edit(&mut self, config_id: CanConfigId) {
+        let id = config_id as u16;
+        self.edits.retain(|edit| edit.0 != id);
+        let _ = self.edits.push((id, EDIT_TICKS));
+    }
+
+    /// Decide whether a setting received from the device with address vda is accepted
+    pub fn accept_edit(&self, is_master: bool, vda: u16, config_id: CanConfigId) -> bool {
+        let id = config_id as u16;
+        let conflict = self.edits.iter().any(|edit| edit.0 == id);
+        !(is_master && self.partner == Some(vda) && conflict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partner_joins() {
+        let mut sync = DualSeatSync::default();
+        sync.tick_1s(None, true);
+        assert!(sync.tick_100ms().is_none());
+
+        // all settings are sent once
+        sync.tick_1s(Some(41), true);
+        let mut count = 0;
+        while sync.tick_100ms().is_some() {
+            count += 1;
+        }
+        assert_eq!(count, SYNC_SETTINGS.len());
+        sync.tick_1s(Some(41), true);
+        assert!(sync.tick_100ms().is_none());
+
+        // the slave does not send settings
+        let mut sync = DualSeatSync::default();
+        sync.tick_1s(Some(40), false);
+        assert!(sync.tick_100ms().is_none());
+    }
+
+    #[test]
+    fn test_conflict() {
+        let mut sync = DualSeatSync::default();
+        sync.tick_1s(Some(41), true);
+        assert!(sync.accept_edit(true, 41, CanConfigId::MacCready));
+
+        // the master wins for a short time after its own edit
+        sync.local_edit(CanConfigId::MacCready);
+        assert!(!sync.accept_edit(true, 41, CanConfigId::MacCready));
+        assert!(sync.accept_edit(true, 41, CanConfigId::Bugs));
+        assert!(sync.accept_edit(true, 12, CanConfigId::MacCready));
+        assert!(sync.accept_edit(false, 41, CanConfigId::MacCready));
+        for _ in 0..EDIT_TICKS {
+            sync.tick_100ms();
+        }
+        assert!(sync.accept_edit(true, 41, CanConfigId::MacCready));
+    }
+}
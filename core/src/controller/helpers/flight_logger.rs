@@ -0,0 +1,69 @@
+use crate::{
+    model::GpsState, system_of_units::FloatToSpeed, CoreModel, IgcFix, IgcHeader, SdCardCmd,
+};
+
+/// Seconds of movement before a takeoff is detected
+const TAKEOFF_TICKS: u16 = 10;
+/// Seconds of standstill before a landing is detected
+const LANDING_TICKS: u16 = 60;
+
+#[derive(Clone, Copy, PartialEq)]
+enum FlightState {
+    OnGround,
+    Flying,
+}
+
+/// Detects takeoff and landing and generates the commands for the IGC file on the SD card
+///
+/// A takeoff is detected, if ground speed or airspeed are above the flying limit for
+/// TAKEOFF_TICKS seconds and a GPS position is available. The landing is detected, if both
+/// speeds are below the standstill limit for LANDING_TICKS seconds.
+pub struct FlightLogger {
+    state: FlightState,
+    ticks: u16,
+}
+
+impl Default for FlightLogger {
+    fn default() -> Self {
+        FlightLogger {
+            state: FlightState::OnGround,
+            ticks: 0,
+        }
+    }
+}
+
+impl FlightLogger {
+    pub fn is_flying(&self) -> bool {
+        self.state == FlightState::Flying
+    }
+
+    pub fn tick_1s(&mut self, cm: &CoreModel) -> Option<SdCardCmd> {
+        let ground_speed = cm.sensor.gps_ground_speed;
+        let airspeed = cm.sensor.airspeed.ias();
+        match self.state {
+            FlightState::OnGround => {
+                let moving = ground_speed > 40.0.km_h() || airspeed > 50.0.km_h();
+                let gps_ok = cm.sensor.gps_state != GpsState::NoGps;
+                self.ticks = if moving && gps_ok { self.ticks + 1 } else { 0 };
+                if self.ticks >= TAKEOFF_TICKS {
+                    self.state = FlightState::Flying;
+                    self.ticks = 0;
+                    Some(SdCardCmd::IgcStart(IgcHeader::new(cm)))
+                } else {
+                    None
+                }
+            }
+            FlightState::Flying => {
+                let stopped = ground_speed < 10.0.km_h() && airspeed < 30.0.km_h();
+                self.ticks = if stopped { self.ticks + 1 } else { 0 };
+                if self.ticks >= LANDING_TICKS {
+                    self.state = FlightState::OnGround;
+                    self.ticks = 0;
+                    Some(SdCardCmd::IgcStop)
+                } else {
+                    Some(SdCardCmd::IgcFix(IgcFix::new(cm)))
+                }
+            }
+        }
+    }
+}
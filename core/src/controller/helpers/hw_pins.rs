@@ -1,4 +1,4 @@
-use crate::{model::TypeOfInfo, CoreModel, FloatToMass, PinState, VarioMode};
+use crate::{CoreModel, FloatToMass, PinState, VarioMode};
 
 pub const PIN_NONE: &str = "Not connected";
 pub const PIN_IN_CLOSE: &str = "When closed";
@@ -191,13 +191,6 @@ impl DrainControl {
                 InPinFunction::None => false,
             }
         };
-        if self.is_flowing {
-            if cm.config.info_active == TypeOfInfo::None {
-                cm.config.info_active = TypeOfInfo::WaterBallast;
-            }
-        } else if cm.config.info_active == TypeOfInfo::WaterBallast {
-            cm.config.info_active = TypeOfInfo::None
-        }
     }
 }
 
@@ -346,7 +339,7 @@ impl Default for GearAlarmControl {
 
 impl GearAlarmControl {
     // sets the pin state and returns alarm active true/false
-    pub fn set_gear_pin_state(&mut self, cm: &mut CoreModel, state: PinState) -> bool {
+    pub fn set_gear_pin_state(&mut self, state: PinState) -> bool {
         self.gear_state = match self.pin_gear_or_both_function {
             InPinFunction::None => false,
             InPinFunction::OnClose => match state {
@@ -358,7 +351,7 @@ impl GearAlarmControl {
                 PinState::Low => false,
             },
         };
-        self.alarm_is_active(cm)
+        self.alarm_is_active()
     }
 
     pub fn gear_pin_function(&self) -> InPinFunction {
@@ -369,7 +362,7 @@ impl GearAlarmControl {
         self.pin_gear_or_both_function = function;
     }
 
-    pub fn set_airbrakes_pin_state(&mut self, cm: &mut CoreModel, state: PinState) -> bool {
+    pub fn set_airbrakes_pin_state(&mut self, state: PinState) -> bool {
         self.airbrakes_state = match self.pin_airbrakes_function {
             InPinFunction::None => false,
             InPinFunction::OnClose => match state {
@@ -381,7 +374,11 @@ impl GearAlarmControl {
                 PinState::Low => false,
             },
         };
-        self.alarm_is_active(cm)
+        self.alarm_is_active()
+    }
+
+    pub fn airbrakes_extended(&self) -> bool {
+        self.airbrakes_state
     }
 
     pub fn airbrakes_pin_function(&self) -> InPinFunction {
@@ -400,16 +397,10 @@ impl GearAlarmControl {
         self.gear_pins = mode;
     }
 
-    fn alarm_is_active(&self, cm: &mut CoreModel) -> bool {
-        let alarm = match self.gear_pins {
+    fn alarm_is_active(&self) -> bool {
+        match self.gear_pins {
             GearPins::OnePinMode => self.gear_state,
             GearPins::TwoPinMode => self.gear_state && self.airbrakes_state,
-        };
-        if alarm {
-            cm.config.info_active = TypeOfInfo::GearAlarm;
-        } else if cm.config.info_active == TypeOfInfo::GearAlarm {
-            cm.config.info_active = TypeOfInfo::None;
         }
-        alarm
     }
 }
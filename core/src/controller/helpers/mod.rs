@@ -1,14 +1,30 @@
+mod auto_qnh;
+mod binary_transfer;
 pub mod can_frame;
 pub mod can_ids;
+mod dual_seat;
+mod flight_logger;
 mod can_rdr;
 mod can_wtr;
 mod hw_pins;
 mod nmea_buffer;
 mod nmea_handler;
+mod remote_control;
 mod scheduler;
+mod speed_monitor;
 
+pub(crate) use auto_qnh::AutoQnh;
+pub(crate) use binary_transfer::{BinaryTransfer, TransferAction};
 pub use can_ids::*;
+pub(crate) use dual_seat::DualSeatSync;
+pub(crate) use flight_logger::FlightLogger;
 pub use hw_pins::*;
 pub use nmea_buffer::NmeaBuffer;
 pub use nmea_handler::nmea_cyclic_200ms;
+pub use remote_control::{RemoteAction, RemoteControl, MAX_REMOTE_BUTTONS};
 pub use scheduler::{IntToDuration, Scheduler, Tim};
+pub(crate) use speed_monitor::{load_factor, SpeedMonitor};
+
+// QNH has no editable, it is set via the sensorbox, the flight computer or a configuration tool
+pub(crate) const QNH_MIN_HPA: f32 = 900.0;
+pub(crate) const QNH_MAX_HPA: f32 = 1100.0;
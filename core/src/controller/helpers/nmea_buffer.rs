@@ -1,3 +1,4 @@
+use super::nmea_handler::NmeaSentence;
 use crate::{CoreError, PersistenceId};
 use heapless::{Deque, Vec};
 use tfmt::{uWrite, uwrite};
@@ -7,7 +8,7 @@ const HEX_TAB: &[u8; 16] = b"0123456789ABCDEF";
 pub struct NmeaBuffer {
     pub rx: RxBuffer,
     pub tx: TxBuffer,
-    pub to_send: Vec<u8, 10>,
+    pub to_send: Vec<NmeaSentence, 10>,
     pub pers_id: Deque<PersistenceId, 16>,
 }
 
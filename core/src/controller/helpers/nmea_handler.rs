@@ -1,13 +1,124 @@
 use crate::{
-    controller::{persist, Echo, persist::set_vario_mode},
-    model::{GpsState, VarioModeControl},
+    controller::{persist, persist::set_vario_mode, Alarm, CanConfigId, Echo},
+    model::{FlarmAlarmLevel, GpsState, NmeaProfile, TrafficItem, VarioModeControl, Waypoint},
     utils::ParseSlice,
-    CoreController, CoreError, CoreModel, FloatToPressure, FloatToSpeed, PersistenceId, Variant,
-    VarioMode, STANDARD_GRAVITY,
+    Coord, CoreController, CoreError, CoreModel, FloatToLength, FloatToSpeed, Latitude, Longitude,
+    PersistenceId, VarioMode, STANDARD_GRAVITY,
 };
+use embedded_graphics::geometry::AngleUnit;
 use heapless::Vec;
 use tfmt::uwrite;
 
+/// Sentences which are sent cyclically to the flight computer
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NmeaSentence {
+    Gprmc,
+    Gpgga,
+    PlarwAverage,
+    PlarwInstant,
+    Plara,
+    Plarb,
+    Plard,
+    Plarv,
+    Lxwp0,
+    Pov,
+    Pbb50,
+}
+
+// The sentences of each output profile, sent every second and every 200 ms
+const LARUS_SENTENCES: (&[NmeaSentence], &[NmeaSentence]) = (
+    &[
+        NmeaSentence::Gprmc,
+        NmeaSentence::Gpgga,
+        NmeaSentence::PlarwAverage,
+        NmeaSentence::Plard,
+        NmeaSentence::Plarb,
+    ],
+    &[
+        NmeaSentence::PlarwInstant,
+        NmeaSentence::Plara,
+        NmeaSentence::Plarv,
+    ],
+);
+const LXNAV_SENTENCES: (&[NmeaSentence], &[NmeaSentence]) = (
+    &[NmeaSentence::Gprmc, NmeaSentence::Gpgga],
+    &[NmeaSentence::Lxwp0],
+);
+const OPEN_VARIO_SENTENCES: (&[NmeaSentence], &[NmeaSentence]) = (
+    &[NmeaSentence::Gprmc, NmeaSentence::Gpgga],
+    &[NmeaSentence::Pov],
+);
+const BORGELT_SENTENCES: (&[NmeaSentence], &[NmeaSentence]) = (
+    &[NmeaSentence::Gprmc, NmeaSentence::Gpgga],
+    &[NmeaSentence::Pbb50],
+);
+
+fn profile_sentences(profile: NmeaProfile) -> (&'static [NmeaSentence], &'static [NmeaSentence]) {
+    match profile {
+        NmeaProfile::Larus => LARUS_SENTENCES,
+        NmeaProfile::Lxnav => LXNAV_SENTENCES,
+        NmeaProfile::OpenVario => OPEN_VARIO_SENTENCES,
+        NmeaProfile::Borgelt => BORGELT_SENTENCES,
+    }
+}
+
+// Keys of the settings exchanged via $PLARS, all of them have a CanConfigId
+const PLARS_KEYS: [(&[u8], PersistenceId); 9] = [
+    (b"MC", PersistenceId::McCready),
+    (b"BAL", PersistenceId::WaterBallast),
+    (b"BUGS", PersistenceId::Bugs),
+    (b"QNH", PersistenceId::Qnh),
+    (b"CIR", PersistenceId::VarioMode),
+    (b"VOL", PersistenceId::Volume),
+    (b"PILOT", PersistenceId::PilotWeight),
+    (b"TCCR", PersistenceId::TcClimbRate),
+    (b"TCSTF", PersistenceId::TcSpeedToFly),
+];
+
+// Empty fields are allowed in FLARM datagrams, e.g. in stealth mode
+fn opt_f32(slice: &[u8]) -> Result<Option<f32>, CoreError> {
+    if slice.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(f32::from_slice(slice)?))
+    }
+}
+
+fn hex_from_slice(slice: &[u8]) -> Result<u32, CoreError> {
+    if slice.is_empty() || slice.len() > 8 {
+        return Err(CoreError::ParseError);
+    }
+    let mut u: u32 = 0;
+    for c in slice {
+        let digit = match c {
+            b'0'..=b'9' => c - b'0',
+            b'A'..=b'F' => c - b'A' + 10,
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => return Err(CoreError::ParseError),
+        };
+        u = (u << 4) | digit as u32;
+    }
+    Ok(u)
+}
+
+// NMEA coordinates are coded as dddmm.mmmm with the hemisphere in the following field, the
+// hemisphere letters are N/S for a latitude and E/W for a longitude
+fn coord_from_slices(
+    value: &[u8],
+    hemisphere: &[u8],
+    positive: &[u8],
+    negative: &[u8],
+) -> Result<Coord, CoreError> {
+    let value = f32::from_slice(value)? as f64;
+    let degrees = (value / 100.0) as i32 as f64;
+    let degrees = degrees + (value - degrees * 100.0) / 60.0;
+    match hemisphere {
+        h if h == positive => Ok(Coord::from_deg(degrees)),
+        h if h == negative => Ok(Coord::from_deg(-degrees)),
+        _ => Err(CoreError::ParseError),
+    }
+}
+
 impl CoreController {
     pub fn recv_u8(&mut self, cm: &mut CoreModel, b: u8) {
         if self.nmea_buffer.rx.recv_u8(b) {
@@ -28,10 +139,129 @@ impl CoreController {
         match self.nmea_buffer.rx.next_chunk()? {
             b"$PLARS" => self.nmea_parse_plars(cm),
             b"$g" => self.nmea_parse_g(cm),
+            b"$PFLAU" => self.nmea_parse_pflau(cm),
+            b"$PFLAA" => self.nmea_parse_pflaa(cm),
+            b"$GPRMB" => self.nmea_parse_gprmb(cm),
             _ => Err(CoreError::ParseError),
         }
     }
 
+    fn nmea_parse_pflau(&mut self, cm: &mut CoreModel) -> Result<(), CoreError> {
+        // $PFLAU,<RX>,<TX>,<GPS>,<Power>,<AlarmLevel>,<RelativeBearing>,<AlarmType>,
+        //   <RelativeVertical>,<RelativeDistance>,<ID>
+        let rx = u32::from_slice(self.nmea_buffer.rx.next_chunk()?)?;
+        let _tx = self.nmea_buffer.rx.next_chunk()?;
+        let gps = u32::from_slice(self.nmea_buffer.rx.next_chunk()?)?;
+        let _power = self.nmea_buffer.rx.next_chunk()?;
+        let alarm_level = u32::from_slice(self.nmea_buffer.rx.next_chunk()?)?;
+        let rel_bearing = opt_f32(self.nmea_buffer.rx.next_chunk()?)?;
+        let _alarm_type = self.nmea_buffer.rx.next_chunk()?;
+        let rel_vertical = opt_f32(self.nmea_buffer.rx.next_chunk()?)?;
+        let rel_distance = opt_f32(self.nmea_buffer.rx.next_chunk()?)?;
+        // the ID is not sent by older FLARM versions
+        let alarm_id = match self.nmea_buffer.rx.next_chunk() {
+            Ok(s) => hex_from_slice(s).unwrap_or(0),
+            Err(_) => 0,
+        };
+
+        let flarm = &mut cm.flarm;
+        flarm.rx = rx.min(u8::MAX as u32) as u8;
+        flarm.gps_ok = gps > 0;
+        flarm.alarm_level = FlarmAlarmLevel::from(alarm_level as u8);
+        flarm.rel_bearing = rel_bearing.map(|b| b.deg());
+        flarm.rel_vertical = rel_vertical.unwrap_or(0.0).m();
+        flarm.rel_distance = rel_distance.unwrap_or(0.0).m();
+        flarm.alarm_id = alarm_id;
+        flarm.refresh();
+
+        self.sound_control
+            .set_alarm(Alarm::Flarm, cm.flarm.alarm_active());
+        Ok(())
+    }
+
+    fn nmea_parse_pflaa(&mut self, cm: &mut CoreModel) -> Result<(), CoreError> {
+        // $PFLAA,<AlarmLevel>,<RelativeNorth>,<RelativeEast>,<RelativeVertical>,<IDType>,<ID>,
+        //   <Track>,<TurnRate>,<GroundSpeed>,<ClimbRate>,<AcftType>
+        let alarm_level = u32::from_slice(self.nmea_buffer.rx.next_chunk()?)?;
+        let rel_north = f32::from_slice(self.nmea_buffer.rx.next_chunk()?)?;
+        // RelativeEast is empty for non-directional targets
+        let rel_east = opt_f32(self.nmea_buffer.rx.next_chunk()?)?;
+        let rel_vertical = opt_f32(self.nmea_buffer.rx.next_chunk()?)?;
+        let _id_type = self.nmea_buffer.rx.next_chunk()?;
+        let id = hex_from_slice(self.nmea_buffer.rx.next_chunk()?)?;
+        let track = opt_f32(self.nmea_buffer.rx.next_chunk()?)?;
+        let _turn_rate = self.nmea_buffer.rx.next_chunk()?;
+        let _ground_speed = self.nmea_buffer.rx.next_chunk()?;
+        let climb_rate = opt_f32(self.nmea_buffer.rx.next_chunk()?)?;
+
+        let mut item = TrafficItem::new(
+            id,
+            FlarmAlarmLevel::from(alarm_level as u8),
+            rel_north.m(),
+            rel_east.map(|e| e.m()),
+            rel_vertical.unwrap_or(0.0).m(),
+        );
+        item.track = track.map(|t| t.deg());
+        item.climb_rate = climb_rate.map(|c| c.m_s());
+        cm.flarm.update_traffic(item);
+        Ok(())
+    }
+
+    fn nmea_parse_gprmb(&mut self, cm: &mut CoreModel) -> Result<(), CoreError> {
+        // $GPRMB,<Status>,<XTE>,<Steer>,<OriginID>,<DestID>,<DestLat>,<N/S>,<DestLon>,<E/W>,
+        //   <Range>,<Bearing>,<ClosingVelocity>,<Arrival>
+        let status = self.nmea_buffer.rx.next_chunk()?;
+        if status != b"A" {
+            cm.navigation.waypoint = None;
+            return Ok(());
+        }
+        let _xte = self.nmea_buffer.rx.next_chunk()?;
+        let _steer = self.nmea_buffer.rx.next_chunk()?;
+        let _origin_id = self.nmea_buffer.rx.next_chunk()?;
+        let _dest_id = self.nmea_buffer.rx.next_chunk()?;
+        let lat: Vec<u8, 12> = Vec::from_slice(self.nmea_buffer.rx.next_chunk()?)
+            .map_err(|_| CoreError::ParseError)?;
+        let lat = coord_from_slices(&lat, self.nmea_buffer.rx.next_chunk()?, b"N", b"S")?;
+        let lon: Vec<u8, 12> = Vec::from_slice(self.nmea_buffer.rx.next_chunk()?)
+            .map_err(|_| CoreError::ParseError)?;
+        let lon = coord_from_slices(&lon, self.nmea_buffer.rx.next_chunk()?, b"E", b"W")?;
+
+        // $GPRMB contains no elevation, keep the one of the last waypoint within about 30 m,
+        // otherwise it is unknown until $PLARS,H,WP supplies it
+        let same_position = |wp: &Waypoint| {
+            (wp.lat.0.to_rad() - lat.to_rad()).abs() < 5e-6
+                && (wp.lon.0.to_rad() - lon.to_rad()).abs() < 5e-6
+        };
+        let elevation = match cm.navigation.waypoint {
+            Some(wp) if same_position(&wp) => wp.elevation,
+            _ => None,
+        };
+        cm.navigation.waypoint = Some(Waypoint {
+            lat: Latitude(lat),
+            lon: Longitude(lon),
+            elevation,
+        });
+        Ok(())
+    }
+
+    fn nmea_parse_plars_wp(&mut self, cm: &mut CoreModel) -> Result<(), CoreError> {
+        // $PLARS,H,WP,<Lat>,<Lon>,<Elevation> with signed coordinates in degrees and elevation
+        // in m, empty fields delete the waypoint
+        let lat = opt_f32(self.nmea_buffer.rx.next_chunk()?)?;
+        let lon = opt_f32(self.nmea_buffer.rx.next_chunk()?)?;
+        let elevation = opt_f32(self.nmea_buffer.rx.next_chunk()?)?;
+        cm.navigation.waypoint = match (lat, lon) {
+            (Some(lat), Some(lon)) if lat.abs() <= 90.0 && lon.abs() <= 180.0 => Some(Waypoint {
+                lat: Latitude(Coord::from_deg(lat as f64)),
+                lon: Longitude(Coord::from_deg(lon as f64)),
+                elevation: elevation.map(|e| e.m()),
+            }),
+            (None, None) => None,
+            _ => return Err(CoreError::ParseError),
+        };
+        Ok(())
+    }
+
     fn nmea_parse_g(&mut self, cm: &mut CoreModel) -> Result<(), CoreError> {
         match self.nmea_buffer.rx.next_chunk()? {
             b"s0" => set_vario_mode(cm, self, VarioMode::Vario, VarioModeControl::Nmea),
@@ -46,116 +276,107 @@ impl CoreController {
     }
 
     fn nmea_parse_plars(&mut self, cm: &mut CoreModel) -> Result<(), CoreError> {
-        fn in_range(val: f32, lower: f32, upper: f32) -> Result<f32, CoreError> {
-            if val >= lower && val <= upper {
-                Ok(val)
-            } else {
-                Err(CoreError::ParseError)
-            }
-        }
-
-        self.nmea_buffer.rx.compare_chunk(b"H")?;
+        // $PLARS,H,<Key>,<Value> sets a value, $PLARS,R,<Key> requests $PLARS,L,<Key>,<Value>
+        let query = match self.nmea_buffer.rx.next_chunk()? {
+            b"H" => false,
+            b"R" => true,
+            _ => return Err(CoreError::ParseError),
+        };
 
         let cmd: Vec<u8, 10> = Vec::from_slice(self.nmea_buffer.rx.next_chunk()?)
             .map_err(|_| CoreError::ParseError)?;
+        if cmd.as_slice() == b"WP" && !query {
+            return self.nmea_parse_plars_wp(cm);
+        }
+        let id = PLARS_KEYS
+            .iter()
+            .find(|(key, _)| *key == cmd.as_slice())
+            .map(|(_, id)| *id)
+            .ok_or(CoreError::ParseError)?;
+
+        if query {
+            // no error if deque is full
+            let _ = self.nmea_buffer.pers_id.push_back(id);
+            return Ok(());
+        }
 
         let s = self.nmea_buffer.rx.next_chunk()?;
         let val = f32::from_slice(s)?;
 
-        match cmd.as_slice() {
-            b"MC" => {
-                let val = in_range(val, 0.0, 9.9)?.m_s();
-                persist::persist_set(
-                    self,
-                    cm,
-                    Variant::Speed(val),
-                    PersistenceId::McCready,
-                    Echo::Can,
-                )
-            }
-            b"BAL" => {
-                cm.glider_data
-                    .set_ballast_fraction(in_range(val, 0.00, 1.00)?);
-                let val = cm.glider_data.water_ballast;
-                persist::persist_set(
-                    self,
-                    cm,
-                    Variant::Mass(val),
-                    PersistenceId::WaterBallast,
-                    Echo::Can,
-                );
-            }
-            b"BUGS" => {
-                let val = 1.0 + in_range(val, 0.0, 50.0)? / 100.0;
-                persist::persist_set(self, cm, Variant::F32(val), PersistenceId::Bugs, Echo::Can);
+        // convert to SI units, the ballast is sent as fraction of the maximum, the bugs in %
+        let val = match id {
+            PersistenceId::VarioMode => {
+                match val as i32 {
+                    0 => set_vario_mode(cm, self, VarioMode::SpeedToFly, VarioModeControl::Nmea),
+                    1 => set_vario_mode(cm, self, VarioMode::Vario, VarioModeControl::Nmea),
+                    _ => return Err(CoreError::ParseError),
+                }
+                return Ok(());
             }
-            b"QNH" => {
-                let val = in_range(val, 900.0, 1100.0)?.hpa();
-                persist::persist_set(
-                    self,
-                    cm,
-                    Variant::Pressure(val),
-                    PersistenceId::Qnh,
-                    Echo::Can,
-                );
+            PersistenceId::WaterBallast if !(0.0..=1.0).contains(&val) => {
+                return Err(CoreError::ParseError);
             }
-            b"CIR" => match val as i32 {
-                0 => set_vario_mode(cm, self, VarioMode::SpeedToFly, VarioModeControl::Nmea),
-                1 => set_vario_mode(cm, self, VarioMode::Vario, VarioModeControl::Nmea),
-                _ => return Err(CoreError::ParseError),
-            },
-            _ => return Err(CoreError::ParseError),
-        }
+            PersistenceId::WaterBallast => val * cm.glider_data.basic_glider_data.max_ballast,
+            PersistenceId::Bugs => 1.0 + val / 100.0,
+            _ => val,
+        };
+        let (variant, id) = persist::config_value(cm, CanConfigId::from(id), val)?;
+        persist::persist_set(self, cm, variant, id, Echo::Can);
         Ok(())
     }
 
-    pub fn nmea_send_config_data(&mut self, id: PersistenceId) {
+    pub fn nmea_send_config_data(&mut self, cm: &CoreModel, id: PersistenceId) {
         // no error if deque is full
-        match id {
-            PersistenceId::Bugs
-            | PersistenceId::McCready
-            | PersistenceId::WaterBallast
-            | PersistenceId::Qnh 
-            | PersistenceId::VarioMode => {
+        match (cm.control.nmea_profile, id) {
+            (NmeaProfile::Larus, _) if !matches!(CanConfigId::from(id), CanConfigId::Ignore) => {
+                let _ = self.nmea_buffer.pers_id.push_back(id);
+            }
+            // $LXWP2 contains all three values, so it is sent only once
+            (
+                NmeaProfile::Lxnav,
+                PersistenceId::Bugs | PersistenceId::McCready | PersistenceId::WaterBallast,
+            ) if self.nmea_buffer.pers_id.is_empty() => {
                 let _ = self.nmea_buffer.pers_id.push_back(id);
             }
             _ => (),
         }
     }
 
-    pub fn nmea_cyclic_1s(&mut self) {
-        let _ = self.nmea_buffer.to_send.extend_from_slice(&[0, 1, 2, 3, 4]);
+    pub fn nmea_cyclic_1s(&mut self, cm: &CoreModel) {
+        let (sentences, _) = profile_sentences(cm.control.nmea_profile);
+        let _ = self.nmea_buffer.to_send.extend_from_slice(sentences);
     }
 
-    pub fn nmea_cyclic_200ms(&mut self) {
-        let _ = self.nmea_buffer.to_send.extend_from_slice(&[5, 6, 7]);
+    pub fn nmea_cyclic_200ms(&mut self, cm: &CoreModel) {
+        let (_, sentences) = profile_sentences(cm.control.nmea_profile);
+        let _ = self.nmea_buffer.to_send.extend_from_slice(sentences);
     }
 
     pub fn nmea_next(&mut self, cm: &mut CoreModel) -> Option<&[u8]> {
-        if !self.nmea_buffer.pers_id.is_empty() {
-            let id = self.nmea_buffer.pers_id.pop_front().unwrap();
-            return self.nmea_plars(cm, id);
-        }
-        if let Some(idx) = self.nmea_buffer.to_send.pop() {
-            match idx {
-                // rarely sent
-                0 => Some(self.nmea_gprmc(cm)),
-                1 => Some(self.nmea_gpgga(cm)),
-                2 => Some(self.nmea_plarw(cm, true)),
-                3 => Some(self.nmea_plard(cm)),
-                4 => Some(self.nmea_plarb(cm)),
-
-                // often sent
-                5 => Some(self.nmea_plarw(cm, false)),
-                6 => Some(self.nmea_plara(cm)),
-                7 => Some(self.nmea_plarv(cm)),
-
-                // not known
-                _ => None,
-            }
-        } else {
-            None
+        if let Some(id) = self.nmea_buffer.pers_id.pop_front() {
+            return match (cm.control.nmea_profile, id) {
+                (
+                    NmeaProfile::Lxnav,
+                    PersistenceId::Bugs | PersistenceId::McCready | PersistenceId::WaterBallast,
+                ) => Some(self.nmea_lxwp2(cm)),
+                _ => self.nmea_plars(cm, id),
+            };
         }
+        let sentence = self.nmea_buffer.to_send.pop()?;
+        let datagram = match sentence {
+            NmeaSentence::Gprmc => self.nmea_gprmc(cm),
+            NmeaSentence::Gpgga => self.nmea_gpgga(cm),
+            NmeaSentence::PlarwAverage => self.nmea_plarw(cm, true),
+            NmeaSentence::PlarwInstant => self.nmea_plarw(cm, false),
+            NmeaSentence::Plara => self.nmea_plara(cm),
+            NmeaSentence::Plarb => self.nmea_plarb(cm),
+            NmeaSentence::Plard => self.nmea_plard(cm),
+            NmeaSentence::Plarv => self.nmea_plarv(cm),
+            NmeaSentence::Lxwp0 => self.nmea_lxwp0(cm),
+            NmeaSentence::Pov => self.nmea_pov(cm),
+            NmeaSentence::Pbb50 => self.nmea_pbb50(cm),
+        };
+        Some(datagram)
     }
 
     fn nmea_gprmc(&mut self, cm: &mut CoreModel) -> &[u8] {
@@ -194,6 +415,79 @@ impl CoreController {
         self.nmea_buffer.tx.finish()
     }
 
+    fn nmea_lxwp0(&mut self, cm: &mut CoreModel) -> &[u8] {
+        // LXNAV: logger, IAS, baro altitude, 6 vario values, heading, wind direction and speed
+        self.nmea_buffer.tx.reset();
+        let _ = uwrite!(
+            self.nmea_buffer.tx,
+            "$LXWP0,N,{:.1},{:.1},{:.2},,,,,,{:.0},{:.0},{:.1}",
+            cm.sensor.airspeed.ias().to_km_h(),
+            cm.sensor.pressure_altitude.qne_altitude().to_m(),
+            cm.calculated.vario_climb_rate.to_m_s(),
+            cm.sensor.euler_yaw.to_degrees(),
+            cm.sensor.wind_vector.angle().to_degrees(),
+            cm.sensor.wind_vector.speed().to_km_h(),
+        );
+        self.nmea_buffer.tx.finish()
+    }
+
+    fn nmea_lxwp2(&mut self, cm: &mut CoreModel) -> &[u8] {
+        // LXNAV: MacCready, ballast as overload factor, bugs in percent
+        self.nmea_buffer.tx.reset();
+        let _ = uwrite!(
+            self.nmea_buffer.tx,
+            "$LXWP2,{:.1},{:.2},{:.0}",
+            cm.config.mc_cready.to_m_s(),
+            cm.glider_data.overload(),
+            (cm.glider_data.bugs - 1.0) * 100.0,
+        );
+        self.nmea_buffer.tx.finish()
+    }
+
+    fn nmea_pov(&mut self, cm: &mut CoreModel) -> &[u8] {
+        // OpenVario: pairs of a type letter and a value, T and H only if measured
+        self.nmea_buffer.tx.reset();
+        let _ = uwrite!(
+            self.nmea_buffer.tx,
+            "$POV,E,{:.2},P,{:.2},S,{:.1}",
+            cm.calculated.vario_climb_rate.to_m_s(),
+            cm.sensor.pressure.to_hpa(),
+            cm.sensor.airspeed.tas().to_km_h(),
+        );
+        if let Some(oat) = cm.sensor.oat {
+            let _ = uwrite!(self.nmea_buffer.tx, ",T,{:.1}", oat.to_deg_c());
+        }
+        if let Some(humidity) = cm.sensor.humidity {
+            let _ = uwrite!(self.nmea_buffer.tx, ",H,{:.1}", humidity);
+        }
+        self.nmea_buffer.tx.finish()
+    }
+
+    fn nmea_pbb50(&mut self, cm: &mut CoreModel) -> &[u8] {
+        // Borgelt B50: TAS, vario, MacCready, IAS², bugs, overload, cruise/climb, temperature
+        self.nmea_buffer.tx.reset();
+        let ias = cm.sensor.airspeed.ias().to_kt();
+        let _ = uwrite!(
+            self.nmea_buffer.tx,
+            "$PBB50,{:.0},{:.1},{:.1},{:.0},{:.0},{:.2},{},",
+            cm.sensor.airspeed.tas().to_kt(),
+            cm.calculated.vario_climb_rate.to_kt(),
+            cm.config.mc_cready.to_kt(),
+            ias * ias,
+            (cm.glider_data.bugs - 1.0) * 100.0,
+            cm.glider_data.overload(),
+            if cm.control.vario_mode == VarioMode::SpeedToFly {
+                1
+            } else {
+                0
+            },
+        );
+        if let Some(oat) = cm.sensor.oat {
+            let _ = uwrite!(self.nmea_buffer.tx, "{:.0}", oat.to_deg_c());
+        }
+        self.nmea_buffer.tx.finish()
+    }
+
     fn nmea_plara(&mut self, cm: &mut CoreModel) -> &[u8] {
         self.nmea_buffer.tx.reset();
         let _ = uwrite!(
@@ -258,6 +552,26 @@ impl CoreController {
                     0
                 }
             ),
+            PersistenceId::Volume => uwrite!(
+                self.nmea_buffer.tx,
+                "$PLARS,L,VOL,{}",
+                cm.config.volume as i32
+            ),
+            PersistenceId::PilotWeight => uwrite!(
+                self.nmea_buffer.tx,
+                "$PLARS,L,PILOT,{:.0}",
+                cm.glider_data.pilot_weight.to_kg()
+            ),
+            PersistenceId::TcClimbRate => uwrite!(
+                self.nmea_buffer.tx,
+                "$PLARS,L,TCCR,{:.0}",
+                cm.config.av2_climb_rate_tc
+            ),
+            PersistenceId::TcSpeedToFly => uwrite!(
+                self.nmea_buffer.tx,
+                "$PLARS,L,TCSTF,{:.0}",
+                cm.config.av_speed_to_fly_tc
+            ),
             _ => return None,
         };
         Some(self.nmea_buffer.tx.finish())
@@ -268,7 +582,7 @@ impl CoreController {
         let _ = uwrite!(
             self.nmea_buffer.tx,
             "$PLARV,{:.2},{:.2},{:.0},{:.0},{:.2}",
-            cm.sensor.climb_rate.to_m_s(),
+            cm.calculated.vario_climb_rate.to_m_s(),
             cm.sensor.average_climb_rate.to_m_s(),
             cm.sensor.pressure_altitude.qne_altitude().to_m(),
             cm.sensor.airspeed.tas().to_km_h(),
@@ -295,18 +609,75 @@ impl CoreController {
     }
 }
 
-pub fn nmea_cyclic_200ms(_cm: &mut CoreModel, cc: &mut CoreController) {
-    cc.nmea_cyclic_200ms();
+pub fn nmea_cyclic_200ms(cm: &mut CoreModel, cc: &mut CoreController) {
+    cc.nmea_cyclic_200ms(cm);
 }
 
-/*#[cfg(test)]
+#[cfg(test)]
 mod tests {
+    use super::NmeaSentence;
     use crate::{
-        model::GpsState, utils::tests::cores, AirSpeed, Coord, FloatToDensity, FloatToLength,
-        FloatToMass, FloatToPressure, FloatToSpeed, Latitude, Longitude, WindVector,
+        assert_float_eq,
+        model::{FlarmAlarmLevel, GpsState, NmeaProfile},
+        utils::tests::cores,
+        AirSpeed, Coord, FloatToDensity, FloatToLength, FloatToMass, FloatToPressure,
+        FloatToSpeed, FloatToTemperature, Latitude, Longitude, PersistenceId, VarioMode,
+        WindVector,
     };
     use embedded_graphics::geometry::AngleUnit;
 
+    #[test]
+    fn pflau() {
+        let (mut cm, mut cc) = cores();
+
+        // older FLARM versions do not send the ID
+        cc.nmea_recv_slice(&mut cm, b"$PFLAU,3,1,2,1,2,-30,2,-32,755*56\r\n");
+        assert!(cm.flarm.alarm_level == FlarmAlarmLevel::Important);
+        assert_float_eq!(cm.flarm.rel_bearing.unwrap().to_degrees(), -30.0);
+        assert_float_eq!(cm.flarm.rel_vertical.to_m(), -32.0);
+        assert_float_eq!(cm.flarm.rel_distance.to_m(), 755.0);
+        assert_eq!(cm.flarm.alarm_id, 0);
+
+        // non-directional alarm, the bearing is empty
+        cc.nmea_recv_slice(&mut cm, b"$PFLAU,2,1,2,1,3,,3,100,450,4B3A2C*3B\r\n");
+        assert!(cm.flarm.alarm_level == FlarmAlarmLevel::Urgent);
+        assert!(cm.flarm.rel_bearing.is_none());
+        assert_float_eq!(cm.flarm.rel_distance.to_m(), 450.0);
+        assert_eq!(cm.flarm.alarm_id, 0x4B3A2C);
+
+        // no traffic, all values empty
+        cc.nmea_recv_slice(&mut cm, b"$PFLAU,3,1,2,1,0,,0,,,*4F\r\n");
+        assert!(cm.flarm.is_available());
+        assert!(!cm.flarm.alarm_active());
+        assert!(cm.flarm.rel_bearing.is_none());
+    }
+
+    #[test]
+    fn pflaa() {
+        let (mut cm, mut cc) = cores();
+
+        cc.nmea_recv_slice(
+            &mut cm,
+            b"$PFLAA,0,-1234,1234,220,2,DD8F12,180,,30,-1.4,1*19\r\n",
+        );
+        let traffic = cm.flarm.traffic().find(|t| t.id == 0xDD8F12).unwrap();
+        assert_float_eq!(traffic.distance().to_m(), 1745.13);
+        assert_float_eq!(traffic.bearing().unwrap().to_degrees(), 135.0);
+        assert_float_eq!(traffic.rel_vertical.to_m(), 220.0);
+        assert_float_eq!(traffic.track.unwrap().to_degrees(), 180.0);
+        assert_float_eq!(traffic.climb_rate.unwrap().to_m_s(), -1.4);
+
+        // non-directional target, RelativeNorth contains the distance
+        cc.nmea_recv_slice(&mut cm, b"$PFLAA,2,1500,,220,1,4B3A2C,,,,,9*0D\r\n");
+        let traffic = cm.flarm.traffic().find(|t| t.id == 0x4B3A2C).unwrap();
+        assert!(traffic.alarm_level == FlarmAlarmLevel::Important);
+        assert_float_eq!(traffic.distance().to_m(), 1500.0);
+        assert!(traffic.bearing().is_none());
+        assert!(traffic.track.is_none());
+        assert!(traffic.climb_rate.is_none());
+        assert_eq!(cm.flarm.traffic().count(), 2);
+    }
+
     #[test]
     fn gpgga() {
         let (mut cm, mut cc) = cores();
@@ -376,37 +747,96 @@ mod tests {
     fn plars() {
         let (mut cm, mut cc) = cores();
         cm.config.mc_cready = 1.7.m_s();
-        cm.config.glider_idx = 105;
-        cm.glider_data.basic_glider_data.empty_mass = 295.0;
-        cm.glider_data.pilot_weight = 90.0.kg();
+        cm.glider_data.basic_glider_data.max_ballast = 121.0;
         cm.glider_data.water_ballast = 100.0.kg();
         cm.glider_data.bugs = 1.23;
         cm.sensor.pressure_altitude.set_qnh(1031.37.hpa());
 
-        let s = cc.nmea_plars(&mut cm, crate::PersistenceId::McCready);
+        let s = cc.nmea_plars(&mut cm, PersistenceId::McCready);
         assert_eq!(s.unwrap(), b"$PLARS,L,MC,1.7*1A\r\n");
 
-        let s = cc.nmea_plars(&mut cm, crate::PersistenceId::WaterBallast);
+        let s = cc.nmea_plars(&mut cm, PersistenceId::WaterBallast);
         assert_eq!(s.unwrap(), b"$PLARS,L,BAL,0.826*51\r\n");
 
-        let s = cc.nmea_plars(&mut cm, crate::PersistenceId::Bugs);
+        let s = cc.nmea_plars(&mut cm, PersistenceId::Bugs);
         assert_eq!(s.unwrap(), b"$PLARS,L,BUGS,23*3E\r\n");
 
-        let s = cc.nmea_plars(&mut cm, crate::PersistenceId::Qnh);
+        let s = cc.nmea_plars(&mut cm, PersistenceId::Qnh);
         assert_eq!(s.unwrap(), b"$PLARS,L,QNH,1031.4*72\r\n");
+
+        let s = cc.nmea_plars(&mut cm, PersistenceId::TcSpeedToFly);
+        assert_eq!(s.unwrap(), b"$PLARS,L,TCSTF,5*5F\r\n");
+    }
+
+    #[test]
+    fn plars_set_and_query() {
+        let (mut cm, mut cc) = cores();
+
+        cc.nmea_recv_slice(&mut cm, b"$PLARS,H,PILOT,85*7B\r\n");
+        cc.nmea_recv_slice(&mut cm, b"$PLARS,H,BUGS,60*3D\r\n");
+        assert_eq!(cm.glider_data.pilot_weight.to_kg(), 85.0);
+        assert_eq!(cm.glider_data.bugs, 1.6);
+
+        // values outside of the editor limits are ignored
+        cc.nmea_recv_slice(&mut cm, b"$PLARS,H,TCCR,5*0B\r\n");
+        cc.nmea_recv_slice(&mut cm, b"$PLARS,H,VOL,51*69\r\n");
+        assert_eq!(cm.config.av2_climb_rate_tc, 30.0);
+        assert_eq!(cm.config.volume, 2);
+
+        cc.nmea_recv_slice(&mut cm, b"$PLARS,H,TCCR,45*3F\r\n");
+        assert_eq!(cm.config.av2_climb_rate_tc, 45.0);
+
+        cc.nmea_recv_slice(&mut cm, b"$PLARS,R,VOL*5B\r\n");
+        assert_eq!(cc.nmea_next(&mut cm).unwrap(), b"$PLARS,L,VOL,2*5B\r\n");
+    }
+
+    #[test]
+    fn gprmb() {
+        let (mut cm, mut cc) = cores();
+
+        // the hemisphere letter must match the axis
+        cc.nmea_recv_slice(
+            &mut cm,
+            b"$GPRMB,A,0.66,L,003,004,4917.24,E,12309.57,W,001.3,052.5,000.5,V*2B\r\n",
+        );
+        cc.nmea_recv_slice(
+            &mut cm,
+            b"$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,S,001.3,052.5,000.5,V*24\r\n",
+        );
+        assert!(cm.navigation.waypoint.is_none());
+
+        // $GPRMB has no elevation, it stays unknown until $PLARS,H,WP supplies it
+        cc.nmea_recv_slice(
+            &mut cm,
+            b"$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20\r\n",
+        );
+        let wp = cm.navigation.waypoint.unwrap();
+        assert!((wp.lat.0.to_deg() - 49.287333).abs() < 1e-5);
+        assert!((wp.lon.0.to_deg() + 123.1595).abs() < 1e-5);
+        assert!(wp.elevation.is_none());
+
+        cc.nmea_recv_slice(&mut cm, b"$PLARS,H,WP,49.287333,-123.1595,520*1E\r\n");
+        assert_eq!(cm.navigation.waypoint.unwrap().elevation.unwrap().to_m(), 520.0);
+
+        // the elevation is kept as long as the navigation software sends the same waypoint
+        cc.nmea_recv_slice(
+            &mut cm,
+            b"$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20\r\n",
+        );
+        assert_eq!(cm.navigation.waypoint.unwrap().elevation.unwrap().to_m(), 520.0);
     }
 
     #[test]
     fn plarv() {
         let (mut cm, mut cc) = cores();
-        cm.sensor.climb_rate = 2.50.m_s();
+        cm.calculated.vario_climb_rate = 2.50.m_s();
         cm.sensor.average_climb_rate = 1.25.m_s();
         cm.sensor
             .pressure_altitude
             .set_static_pressure(97_717.0_f32.n_m2());
         cm.sensor.airspeed = AirSpeed::from_tas_at_nn(111.1.km_h());
         let s = cc.nmea_plarv(&mut cm);
-        assert_eq!(s, b"$PLARV,2.50,1.25,305,111*5F\r\n");
+        assert_eq!(s, b"$PLARV,2.50,1.25,305,111,1.00*6C\r\n");
     }
 
     #[test]
@@ -421,22 +851,95 @@ mod tests {
         assert_eq!(s, b"$PLARW,321,46,I,A*62\r\n");
     }
 
-    /*fn cores() -> (CoreModel, CoreController) {
-        let (p_tx_frames, _c_tx_frames) = {
-            static mut Q_TX_FRAMES: QTxFrames<MAX_TX_FRAMES> = Queue::new();
-            // Note: unsafe is ok here, because [heapless::spsc] queue protects against UB
-            unsafe { Q_TX_FRAMES.split() }
-        };
+    #[test]
+    fn lxwp0() {
+        let (mut cm, mut cc) = cores();
+        cm.sensor.airspeed = AirSpeed::from_speeds(111.1.km_h(), 120.0.km_h());
+        cm.sensor
+            .pressure_altitude
+            .set_static_pressure(97_717.0_f32.n_m2());
+        cm.calculated.vario_climb_rate = (-1.25).m_s();
+        cm.sensor.euler_yaw = 12.3_f32.deg();
+        cm.sensor.wind_vector = WindVector::new(45.6.km_h(), 321.0_f32.deg());
+        let s = cc.nmea_lxwp0(&mut cm);
+        assert_eq!(s, b"$LXWP0,N,111.1,305.4,-1.25,,,,,,12,321,45.6*70\r\n");
+    }
 
-        // This queue routes the StorageItems from the controller to the idle loop.
-        let (p_idle_events, _c_idle_events) = {
-            static mut Q_IDLE_EVENTS: QIdleEvents = Queue::new();
-            // Note: unsafe is ok here, because [heapless::spsc] queue protects against UB
-            unsafe { Q_IDLE_EVENTS.split() }
-        };
+    #[test]
+    fn lxwp2() {
+        let (mut cm, mut cc) = cores();
+        cm.config.mc_cready = 1.7.m_s();
+        cm.glider_data.basic_glider_data.empty_mass = 310.0;
+        cm.glider_data.pilot_weight = 90.0.kg();
+        cm.glider_data.water_ballast = 100.0.kg();
+        cm.glider_data.bugs = 1.1;
+        let s = cc.nmea_lxwp2(&mut cm);
+        assert_eq!(s, b"$LXWP2,1.7,1.25,10*3C\r\n");
+    }
 
-        let mut model = CoreModel::new(1234_u32, HW_VERSION, SW_VERSION);
-        let controller = CoreController::new(&mut model, p_idle_events, p_tx_frames);
-        (model, controller)
-    }*/
-}*/
+    #[test]
+    fn pov() {
+        let (mut cm, mut cc) = cores();
+        cm.calculated.vario_climb_rate = 2.15.m_s();
+        cm.sensor.pressure = 1013.25.hpa();
+        cm.sensor.airspeed = AirSpeed::from_tas_at_nn(123.4.km_h());
+        let s = cc.nmea_pov(&mut cm);
+        assert_eq!(s, b"$POV,E,2.15,P,1013.25,S,123.4*17\r\n");
+
+        cm.sensor.oat = Some(22.5.deg_c());
+        cm.sensor.humidity = Some(55.0);
+        let s = cc.nmea_pov(&mut cm);
+        assert_eq!(s, b"$POV,E,2.15,P,1013.25,S,123.4,T,22.5,H,55.0*0E\r\n");
+    }
+
+    #[test]
+    fn pbb50() {
+        let (mut cm, mut cc) = cores();
+        cm.sensor.airspeed = AirSpeed::from_speeds(100.0.kt(), 110.0.kt());
+        cm.calculated.vario_climb_rate = 2.0.kt();
+        cm.config.mc_cready = 1.5.kt();
+        cm.glider_data.basic_glider_data.empty_mass = 310.0;
+        cm.glider_data.pilot_weight = 90.0.kg();
+        cm.control.vario_mode = VarioMode::SpeedToFly;
+        let s = cc.nmea_pbb50(&mut cm);
+        assert_eq!(s, b"$PBB50,110,2.0,1.5,10000,0,1.00,1,*4C\r\n");
+
+        cm.sensor.oat = Some((-3.0).deg_c());
+        let s = cc.nmea_pbb50(&mut cm);
+        assert_eq!(s, b"$PBB50,110,2.0,1.5,10000,0,1.00,1,-3*52\r\n");
+    }
+
+    #[test]
+    fn output_profiles() {
+        let (mut cm, mut cc) = cores();
+        cc.nmea_cyclic_200ms(&cm);
+        assert_eq!(
+            cc.nmea_buffer.to_send.as_slice(),
+            &[
+                NmeaSentence::PlarwInstant,
+                NmeaSentence::Plara,
+                NmeaSentence::Plarv
+            ]
+        );
+        assert!(cc.nmea_next(&mut cm).unwrap().starts_with(b"$PLARV,"));
+
+        cm.control.nmea_profile = NmeaProfile::OpenVario;
+        cc.nmea_buffer.to_send.clear();
+        cc.nmea_cyclic_1s(&cm);
+        cc.nmea_cyclic_200ms(&cm);
+        assert!(cc.nmea_next(&mut cm).unwrap().starts_with(b"$POV,"));
+        assert!(cc.nmea_next(&mut cm).unwrap().starts_with(b"$GPGGA,"));
+        assert!(cc.nmea_next(&mut cm).unwrap().starts_with(b"$GPRMC,"));
+        assert!(cc.nmea_next(&mut cm).is_none());
+
+        // config values are sent as $LXWP2 once, OpenVario has no config sentence
+        cc.nmea_send_config_data(&cm, PersistenceId::McCready);
+        assert!(cc.nmea_next(&mut cm).is_none());
+        cm.control.nmea_profile = NmeaProfile::Lxnav;
+        cc.nmea_send_config_data(&cm, PersistenceId::McCready);
+        cc.nmea_send_config_data(&cm, PersistenceId::Bugs);
+        cc.nmea_send_config_data(&cm, PersistenceId::Qnh);
+        assert!(cc.nmea_next(&mut cm).unwrap().starts_with(b"$LXWP2,"));
+        assert!(cc.nmea_next(&mut cm).is_none());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::KeyEvent;
+
+/// Number of buttons of a stick remote or button panel that can be configured
+pub const MAX_REMOTE_BUTTONS: usize = 4;
+
+/// Function assigned to a button of a CAN stick remote or button panel
+///
+/// Enter, Escape, Up and Down behave like the local keys, the other functions are executed
+/// directly, independent of the current display. VarioToggle works only with the vario mode
+/// control set to CAN, otherwise the vario mode is switched automatically or by the input pin.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum RemoteAction {
+    None,
+    Enter,
+    Escape,
+    Up,
+    Down,
+    VarioToggle,
+    McUp,
+    McDown,
+    Mute,
+    NextDisplay,
+}
+
+const REMOTE_ACTIONS: [RemoteAction; 10] = [
+    RemoteAction::None,
+    RemoteAction::Enter,
+    RemoteAction::Escape,
+    RemoteAction::Up,
+    RemoteAction::Down,
+    RemoteAction::VarioToggle,
+    RemoteAction::McUp,
+    RemoteAction::McDown,
+    RemoteAction::Mute,
+    RemoteAction::NextDisplay,
+];
+
+impl From<u8> for RemoteAction {
+    fn from(value: u8) -> Self {
+        REMOTE_ACTIONS
+            .get(value as usize)
+            .copied()
+            .unwrap_or(RemoteAction::None)
+    }
+}
+
+impl RemoteAction {
+    pub const COUNT: usize = REMOTE_ACTIONS.len();
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RemoteAction::None => "Not used",
+            RemoteAction::Enter => "Enter",
+            RemoteAction::Escape => "Escape",
+            RemoteAction::Up => "Up",
+            RemoteAction::Down => "Down",
+            RemoteAction::VarioToggle => "Vario / StF",
+            RemoteAction::McUp => "MacCready up",
+            RemoteAction::McDown => "MacCready down",
+            RemoteAction::Mute => "Mute",
+            RemoteAction::NextDisplay => "Next display",
+        }
+    }
+
+    /// Local key that corresponds to the action
+    pub fn key_event(&self) -> Option<KeyEvent> {
+        match self {
+            RemoteAction::Enter => Some(KeyEvent::BtnEnc),
+            RemoteAction::Escape => Some(KeyEvent::BtnEsc),
+            RemoteAction::Up => Some(KeyEvent::Rotary1Left),
+            RemoteAction::Down => Some(KeyEvent::Rotary1Right),
+            _ => None,
+        }
+    }
+}
+
+/// Mapping of the remote buttons to their functions
+pub struct RemoteControl {
+    actions: [RemoteAction; MAX_REMOTE_BUTTONS],
+}
+
+impl Default for RemoteControl {
+    fn default() -> Self {
+        RemoteControl {
+            actions: [
+                RemoteAction::McUp,
+                RemoteAction::McDown,
+                RemoteAction::Mute,
+                RemoteAction::None,
+            ],
+        }
+    }
+}
+
+impl RemoteControl {
+    pub fn action(&self, button: u8) -> RemoteAction {
+        self.actions
+            .get(button as usize)
+            .copied()
+            .unwrap_or(RemoteAction::None)
+    }
+
+    pub fn set_action(&mut self, button: u8, action: RemoteAction) {
+        if let Some(entry) = self.actions.get_mut(button as usize) {
+            *entry = action;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remote_control() {
+        let mut remote_control = RemoteControl::default();
+        assert!(remote_control.action(0) == RemoteAction::McUp);
+        assert!(remote_control.action(9) == RemoteAction::None);
+
+        remote_control.set_action(3, RemoteAction::from(RemoteAction::VarioToggle as u8));
+        assert!(remote_control.action(3) == RemoteAction::VarioToggle);
+        assert!(RemoteAction::from(42) == RemoteAction::None);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::system_of_units::{Acceleration, Angle, Float, FloatToSpeed, Speed, STANDARD_GRAVITY};
+
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
+/// Above this bank angle, the load factor of a coordinated turn is not used
+const MAX_BANK: Float = 75.0 * core::f32::consts::PI / 180.0;
+
+/// Load factor for the stall speed
+///
+/// The measured load factor lags behind in a steepening turn, so the load factor of a
+/// coordinated turn at the current bank angle is used if it is higher.
+pub fn load_factor(g_force: Acceleration, roll: Angle) -> Float {
+    let measured = g_force.to_m_s2() / STANDARD_GRAVITY;
+    let bank = roll.to_radians().abs().min(MAX_BANK);
+    measured.max(1.0 / bank.cos())
+}
+
+/// Monitoring of the airspeed against the limits of the glider
+///
+/// The warnings are only given in flight. A warning ends with a hysteresis, so that it does not
+/// flutter at the limit.
+#[derive(Default)]
+pub struct SpeedMonitor {
+    stall: bool,
+    overspeed: bool,
+}
+
+impl SpeedMonitor {
+    /// Returns true if the airspeed is below the stall speed plus margin in %
+    pub fn stall_warning(
+        &mut self,
+        ias: Speed,
+        stall_speed: Speed,
+        margin: Float,
+        flying: bool,
+    ) -> bool {
+        let limit = stall_speed * (1.0 + margin / 100.0);
+        self.stall = match self.stall {
+            false => flying && ias < limit,
+            true => flying && ias < limit + 3.0.km_h(),
+        };
+        self.stall
+    }
+
+    /// Returns true if the airspeed is above the never exceed speed, never with an unknown one
+    pub fn overspeed_warning(&mut self, ias: Speed, vne: Option<Speed>, flying: bool) -> bool {
+        self.overspeed = match (self.overspeed, vne) {
+            (_, None) => false,
+            (false, Some(vne)) => flying && ias > vne,
+            (true, Some(vne)) => flying && ias > vne - 3.0.km_h(),
+        };
+        self.overspeed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+    use crate::system_of_units::FloatToAcceleration;
+
+    #[test]
+    fn test_load_factor() {
+        let one_g = STANDARD_GRAVITY.m_s2();
+        assert_float_eq!(load_factor(one_g, Angle::from_degrees(0.0)), 1.0);
+        assert_float_eq!(load_factor(one_g, Angle::from_degrees(-60.0)), 2.0);
+        assert_float_eq!(load_factor(one_g * 2.5, Angle::from_degrees(30.0)), 2.5);
+        assert_float_eq!(load_factor(one_g, Angle::from_degrees(90.0)), 3.864);
+    }
+
+    #[test]
+    fn test_stall_warning() {
+        let mut monitor = SpeedMonitor::default();
+        let stall_speed = 65.0.km_h();
+        assert!(!monitor.stall_warning(72.0.km_h(), stall_speed, 10.0, true));
+        assert!(monitor.stall_warning(71.0.km_h(), stall_speed, 10.0, true));
+        assert!(monitor.stall_warning(73.0.km_h(), stall_speed, 10.0, true));
+        assert!(!monitor.stall_warning(75.0.km_h(), stall_speed, 10.0, true));
+
+        // no warning on the ground
+        assert!(!monitor.stall_warning(0.0.km_h(), stall_speed, 10.0, false));
+    }
+
+    #[test]
+    fn test_overspeed_warning() {
+        let mut monitor = SpeedMonitor::default();
+        let vne = Some(270.0.km_h());
+        assert!(!monitor.overspeed_warning(269.0.km_h(), vne, true));
+        assert!(monitor.overspeed_warning(271.0.km_h(), vne, true));
+        assert!(monitor.overspeed_warning(268.0.km_h(), vne, true));
+        assert!(!monitor.overspeed_warning(266.0.km_h(), vne, true));
+        assert!(!monitor.overspeed_warning(300.0.km_h(), vne, false));
+        assert!(!monitor.overspeed_warning(300.0.km_h(), None, true));
+    }
+}
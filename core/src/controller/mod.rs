@@ -1,13 +1,17 @@
 mod helpers;
 pub use helpers::{
     can_frame::*,
-    can_ids::{audio_legacy, frontend_legacy, sensor_legacy, GenericId, SpecialId},
-    CanActive, CanConfigId, IntToDuration, NmeaBuffer, RemoteConfig, Scheduler, Tim,
+    can_ids::{
+        audio_legacy, frontend_legacy, object_id, sensor_legacy, ConfigStatus, GenericId,
+        SpecialId, SysSettingCmd,
+    },
+    CanActive, CanConfigId, IntToDuration, NmeaBuffer, RemoteAction, RemoteConfig, RemoteControl,
+    Scheduler, Tim, MAX_REMOTE_BUTTONS,
 };
 pub(crate) use helpers::{
-    DrainControl, FlashControl, GearAlarmControl, GearPins, InPinFunction, InTogglePinFunction,
-    OutPinFunction, SpeedToFlyControl, ONE_PIN_MODE, PIN_IN_CLOSE, PIN_IN_OPEN, PIN_IN_TOGGLE,
-    PIN_NONE, PIN_OUT_CLOSE, PIN_OUT_OPEN, TWO_PIN_MODE,
+    AutoQnh, DrainControl, DualSeatSync, FlashControl, FlightLogger, GearAlarmControl, GearPins,
+    InPinFunction, InTogglePinFunction, OutPinFunction, SpeedMonitor, SpeedToFlyControl, ONE_PIN_MODE,
+    PIN_IN_CLOSE, PIN_IN_OPEN, PIN_IN_TOGGLE, PIN_NONE, PIN_OUT_CLOSE, PIN_OUT_OPEN, TWO_PIN_MODE,
 };
 
 mod editor;
@@ -19,6 +23,9 @@ pub use menu::{close_menu_display, MenuControl};
 mod fw_update;
 use fw_update::SwUpdateController;
 
+mod alarm_mixer;
+pub(crate) use alarm_mixer::Alarm;
+
 mod sound;
 pub(crate) use sound::SoundControl;
 
@@ -31,14 +38,16 @@ pub use persist::{store_persistence_ids, Echo, PersistenceId, profile_always_0};
 use crate::{
     basic_config::{CONTROLLER_TICK_RATE, MAX_TX_FRAMES},
     common::PTxFrames,
-    flight_physics::Polar,
-    model::{DataSource, DisplayActive, EditMode, VarioModeControl},
-    system_of_units::{FloatToSpeed, Speed},
-    utils::{KeyEvent, PIdleEvents, Pt1},
+    flight_physics::{CirclingWind, Polar},
+    model::{
+        DataSource, DisplayActive, EditMode, SensorboxUpdate, VarioModeControl, VarioSource,
+    },
+    system_of_units::{Angle, FloatToSpeed, Speed, STANDARD_GRAVITY},
+    utils::{KeyEvent, PIdleEvents, Pt1, Variant},
     CoreModel, DeviceEvent, Editable, Event, IdleEvent, InputPinState, PersistenceItem, SdCardCmd,
     VarioMode,
 };
-use helpers::nmea_cyclic_200ms;
+use helpers::{load_factor, nmea_cyclic_200ms};
 
 #[allow(unused_imports)]
 use micromath::F32Ext;
@@ -74,10 +83,19 @@ pub struct CoreController {
     pub flash_control: FlashControl,
     pub speed_to_fly_control: SpeedToFlyControl,
     pub gear_alarm_control: GearAlarmControl,
+    pub remote_control: RemoteControl,
+    flight_logger: FlightLogger,
+    speed_monitor: SpeedMonitor,
+    circling_wind: CirclingWind,
+    auto_qnh: AutoQnh,
+    dual_seat: DualSeatSync,
     sw_update: SwUpdateController,
     sound_control: SoundControl,
     ms: u16,
     last_vario_mode: VarioMode,
+    last_gps_track: Option<Angle>,
+    last_thermal_count: u32,
+    engine_return_display: Option<DisplayActive>,
     av2_climb_rate: Pt1<Speed>,
     av_speed_to_fly: Pt1<Speed>,
     av_supply_voltage: Pt1<f32>,
@@ -126,9 +144,18 @@ impl CoreController {
             flash_control: FlashControl::default(),
             speed_to_fly_control: SpeedToFlyControl::default(),
             gear_alarm_control: GearAlarmControl::default(),
+            remote_control: RemoteControl::default(),
+            flight_logger: FlightLogger::default(),
+            speed_monitor: SpeedMonitor::default(),
+            circling_wind: CirclingWind::default(),
+            auto_qnh: AutoQnh::default(),
+            dual_seat: DualSeatSync::default(),
             sound_control: SoundControl::default(),
             ms: 0,
             last_vario_mode: VarioMode::Vario,
+            last_gps_track: None,
+            last_thermal_count: 0,
+            engine_return_display: None,
             sw_update: SwUpdateController::new(),
             av2_climb_rate,
             av_speed_to_fly,
@@ -148,6 +175,7 @@ impl CoreController {
             Event::KeyItem(key_event) => self.key_action(cm, key_event),
             Event::DeviceItem(device_event) => self.device_action(cm, &device_event),
             Event::InputItem(input_event) => self.input_action(cm, input_event),
+            Event::RemoteItem(button) => self.remote_action(cm, button),
         }
     }
 
@@ -169,6 +197,8 @@ impl CoreController {
                     // alternatively: execute a callback every ms as long as available
                     if let Some(callback) = self.scheduler.next_callback() {
                         callback(cm, self);
+                    } else if self.p_tx_frames.ready() {
+                        self.tick_sensorbox_update(cm);
                     }
                 }
             }
@@ -206,14 +236,73 @@ impl CoreController {
         core_model.calculated.speed_to_fly_dif =
             core_model.calculated.av_speed_to_fly - core_model.sensor.airspeed.ias();
 
+        // Calculate the climb rate according to the selected vario source
+        core_model.calculated.vario_climb_rate = match core_model.control.vario_source {
+            VarioSource::TotalEnergy => climb_rate,
+            VarioSource::Netto => climb_rate - self.netto_sink_rate(core_model),
+            VarioSource::Relative => {
+                climb_rate - self.netto_sink_rate(core_model) + self.polar.min_sink_rate()
+            }
+        };
+
         let can_frame = core_model.can_frame_avg_climb_rates();
         let _ = self.p_tx_frames.enqueue(can_frame); // ignore when queue is full
 
+        // the master of a two-seater shares its state with the other frontend
+        let config_id = self.dual_seat.tick_100ms();
+        if core_model.can_bus.is_sync_master() && core_model.can_bus.sync_partner().is_some() {
+            let can_frame = core_model.can_frame_flight_state();
+            let _ = self.p_tx_frames.enqueue(can_frame);
+            if let Some(can_frame) = config_id.and_then(|id| core_model.can_frame_sys_config(id)) {
+                let _ = self.p_tx_frames.enqueue(can_frame);
+            }
+        }
+
         // calc moving average from supply voltage
         self.av_supply_voltage
             .tick(core_model.device.supply_voltage);
         core_model.calculated.av_supply_voltage = self.av_supply_voltage.value();
 
+        // warn of a low battery until the voltage is good again
+        let supply_voltage = core_model.calculated.av_supply_voltage;
+        if supply_voltage < core_model.config.battery_low {
+            self.sound_control.set_alarm(Alarm::LowBattery, true);
+        } else if supply_voltage > core_model.config.battery_good {
+            self.sound_control.set_alarm(Alarm::LowBattery, false);
+        }
+        self.sound_control
+            .set_alarm(Alarm::WaterBallast, self.drain_control.is_flowing());
+
+        // the speed warnings need a measured airspeed, not only a GNSS that shows flight
+        let flying = self.flight_logger.is_flying() && core_model.control.airspeed_ticks > 0;
+
+        // warn of a stall, the stall speed rises with the load factor
+        let sensor = &core_model.sensor;
+        let stall_speed = self
+            .polar
+            .stall_speed(load_factor(sensor.g_force, sensor.euler_roll));
+        let stall = self.speed_monitor.stall_warning(
+            sensor.airspeed.ias(),
+            stall_speed.ias(),
+            core_model.config.stall_margin,
+            flying,
+        );
+        self.sound_control.set_alarm(Alarm::Stall, stall);
+
+        // warn of an overspeed, the limits decrease with the altitude
+        let limits = core_model.glider_data.basic_glider_data.limits;
+        let altitude = core_model.sensor.pressure_altitude.qne_altitude();
+        let ballast = core_model.glider_data.water_ballast.to_kg() > 0.0;
+        let airbrakes = self.gear_alarm_control.airbrakes_extended();
+        core_model.calculated.vne = limits.map(|limits| limits.vne(altitude, airbrakes, ballast));
+        core_model.calculated.rough_air_speed = limits.map(|limits| limits.rough_air(altitude));
+        let overspeed = self.speed_monitor.overspeed_warning(
+            core_model.sensor.airspeed.ias(),
+            core_model.calculated.vne,
+            flying,
+        );
+        self.sound_control.set_alarm(Alarm::Overspeed, overspeed);
+
         // calc sound params
         if let Some(event) = self.sound_control.sound(core_model) {
             self.send_idle_event(event);
@@ -224,6 +313,42 @@ impl CoreController {
         let _ = self.p_tx_frames.enqueue(can_frame); // ignore when queue is full
     }
 
+    // Sink rate of the glider at the current airspeed and load factor
+    fn netto_sink_rate(&self, core_model: &CoreModel) -> Speed {
+        let load_factor = core_model.sensor.vertical_g_force.to_m_s2() / STANDARD_GRAVITY;
+        self.polar
+            .sink_rate_at_load(core_model.sensor.airspeed, load_factor)
+    }
+
+    // Send the next frame of a running sensorbox update
+    fn tick_sensorbox_update(&mut self, cm: &mut CoreModel) {
+        match cm.control.sensorbox_update {
+            Some(SensorboxUpdate::Running(_)) => (),
+            // the offer is withdrawn at take-off
+            Some(SensorboxUpdate::Offered(_)) if !self.on_ground(cm) => {
+                cm.control.sensorbox_update = None;
+                cm.config.display_active = cm.config.last_display_active;
+                return;
+            }
+            _ => return,
+        }
+        let (frame, idle_event) = self.sw_update.tick_1ms(cm);
+        if let Some(frame) = frame {
+            let _ = self.p_tx_frames.enqueue(frame);
+        }
+        if let Some(idle_event) = idle_event {
+            self.send_idle_event(idle_event);
+        }
+        if cm.control.sensorbox_update.is_none() {
+            cm.config.display_active = cm.config.last_display_active;
+        }
+    }
+
+    // The vario is offline during a sensorbox update, so it is only done on the ground
+    fn on_ground(&self, cm: &CoreModel) -> bool {
+        !self.flight_logger.is_flying() && cm.sensor.airspeed.ias() < 30.0.km_h()
+    }
+
     pub fn send_idle_event(&mut self, idle_event: IdleEvent) {
         let _ = self.p_idle_events.enqueue(idle_event);
     }
@@ -234,14 +359,60 @@ impl CoreController {
             InputPinState::Io1(state) => self.drain_control.set_state(cm, state),
             InputPinState::Io2(state) => self.speed_to_fly_control.set_state(state),
             InputPinState::Io3(state) => {
-                let active = self.gear_alarm_control.set_gear_pin_state(cm, state);
-                self.sound_control
-                    .set_scenario(sound::SoundScenario::GearAlarm, active);
+                let active = self.gear_alarm_control.set_gear_pin_state(state);
+                self.sound_control.set_alarm(Alarm::Gear, active);
             }
             InputPinState::Io4(state) => {
-                let active = self.gear_alarm_control.set_airbrakes_pin_state(cm, state);
-                self.sound_control
-                    .set_scenario(sound::SoundScenario::GearAlarm, active);
+                let active = self.gear_alarm_control.set_airbrakes_pin_state(state);
+                self.sound_control.set_alarm(Alarm::Gear, active);
+            }
+        }
+    }
+
+    fn remote_action(&mut self, cm: &mut CoreModel, button: u8) {
+        let action = self.remote_control.action(button);
+        match action {
+            RemoteAction::VarioToggle => {
+                // like the other remote switches only active with vario mode control CAN
+                let vario_mode = !cm.control.vario_mode;
+                persist::set_vario_mode(cm, self, vario_mode, VarioModeControl::Can);
+            }
+            RemoteAction::McUp | RemoteAction::McDown => {
+                let unit = cm.config.units.vertical_speed;
+                let inc = if action == RemoteAction::McUp {
+                    unit.increment()
+                } else {
+                    -unit.increment()
+                };
+                let mc_cready = unit.speed(unit.value(cm.config.mc_cready) + inc).to_m_s();
+                persist::persist_set(
+                    self,
+                    cm,
+                    Variant::Speed(mc_cready.clamp(0.0, 5.0).m_s()),
+                    PersistenceId::McCready,
+                    Echo::NmeaAndCan,
+                );
+            }
+            RemoteAction::Mute => self.sound_control.toggle_mute(),
+            RemoteAction::NextDisplay => {
+                let display_active = match cm.config.display_active {
+                    DisplayActive::Vario => DisplayActive::Horizon,
+                    DisplayActive::Horizon if cm.engine.is_available() => DisplayActive::Engine,
+                    DisplayActive::Horizon | DisplayActive::Engine => DisplayActive::Vario,
+                    _ => return,
+                };
+                persist::persist_set(
+                    self,
+                    cm,
+                    Variant::U32(display_active as u32),
+                    PersistenceId::Display,
+                    Echo::None,
+                );
+            }
+            _ => {
+                if let Some(key_event) = action.key_event() {
+                    self.key_action(cm, key_event);
+                }
             }
         }
     }
@@ -256,7 +427,21 @@ impl CoreController {
             DeviceEvent::UploadFinished => {
                 core_model.config.display_active = core_model.config.last_display_active
             }
-
+            DeviceEvent::SensorboxFwAvailable(_) => {
+                // an update of the own firmware has precedence, in flight there is no update
+                if core_model.config.display_active != DisplayActive::FirmwareUpdate
+                    && self.on_ground(core_model)
+                {
+                    core_model.config.last_display_active = core_model.config.display_active;
+                    core_model.config.display_active = DisplayActive::FirmwareUpdate;
+                    self.sw_update.sensorbox_action(core_model, device_event);
+                }
+                return;
+            }
+            DeviceEvent::SensorboxFwBlock(_) => {
+                self.sw_update.sensorbox_action(core_model, device_event);
+                return;
+            }
             _ => (),
         }
         if core_model.config.display_active == DisplayActive::FirmwareUpdate {
@@ -267,7 +452,117 @@ impl CoreController {
 
     // Event handler for keystrokes
     fn key_action(&mut self, cm: &mut CoreModel, mut key_event: KeyEvent) {
+        // escape silences the current alarm first
+        if key_event == KeyEvent::BtnEsc && self.sound_control.acknowledge_alarm() {
+            return;
+        }
+        // the sensorbox update is confirmed with enter and skipped with escape
+        if cm.config.display_active == DisplayActive::FirmwareUpdate
+            && cm.control.sensorbox_update.is_some()
+        {
+            self.sensorbox_dialog_action(cm, key_event);
+            return;
+        }
+        // the proposed QNH is applied with enter and rejected with escape
+        if cm.config.display_active == DisplayActive::QnhDialog {
+            self.qnh_dialog_action(cm, key_event);
+            return;
+        }
         editor::key_action(&mut key_event, cm, self);
         menu::key_action(&mut key_event, cm, self);
     }
+
+    // Event handler for keystrokes while a sensorbox update is offered, running or failed
+    fn sensorbox_dialog_action(&mut self, cm: &mut CoreModel, key_event: KeyEvent) {
+        let update = cm.control.sensorbox_update;
+        match (update, key_event) {
+            (Some(SensorboxUpdate::Offered(_)), KeyEvent::BtnEnc) if self.on_ground(cm) => {
+                self.sw_update.sensorbox_start(cm)
+            }
+            (
+                Some(SensorboxUpdate::Offered(_) | SensorboxUpdate::Failed),
+                KeyEvent::BtnEnc | KeyEvent::BtnEsc,
+            ) => {
+                cm.control.sensorbox_update = None;
+                cm.config.display_active = cm.config.last_display_active;
+            }
+            _ => (),
+        }
+    }
+
+    // Event handler for keystrokes while the automatic QNH is proposed
+    fn qnh_dialog_action(&mut self, cm: &mut CoreModel, key_event: KeyEvent) {
+        let accepted = match key_event {
+            KeyEvent::BtnEnc => true,
+            KeyEvent::BtnEsc => false,
+            _ => return,
+        };
+        if let (true, Some(qnh)) = (accepted, cm.calculated.qnh_proposal) {
+            persist::persist_set(
+                self,
+                cm,
+                Variant::Pressure(qnh),
+                PersistenceId::Qnh,
+                Echo::NmeaAndCan,
+            );
+        }
+        cm.calculated.qnh_proposal = None;
+        cm.config.display_active = cm.config.last_display_active;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_float_eq, flight_physics::AirSpeed, model::editable::Content,
+        system_of_units::Density, utils::tests::cores, utils::TString, FloatToAcceleration,
+    };
+
+    // Selects the vario source like the pilot in the editor
+    fn select(cm: &mut CoreModel, cc: &mut CoreController, source: VarioSource) {
+        let content = Content::Enum(TString::<16>::from_str(source.as_str()));
+        Editable::VarioSource.set_content(cm, cc, content);
+    }
+
+    #[test]
+    fn test_vario_source() {
+        let (mut cm, mut cc) = cores();
+        cm.sensor.density = Density::AT_NN();
+        cc.recalc_glider(&mut cm);
+        recalc_polar(&mut cm, &mut cc);
+
+        // LS-3 WL at 120 km/h sinks 0.84 m/s, its minimum sink is 0.59 m/s
+        cm.sensor.airspeed = AirSpeed::from_tas_at_nn(120.0.km_h());
+        cm.sensor.vertical_g_force = STANDARD_GRAVITY.m_s2();
+        cm.sensor.climb_rate = 1.0.m_s();
+        assert_float_eq!(cc.polar.sink_rate(cm.sensor.airspeed).to_m_s(), -0.8405);
+        assert_float_eq!(cc.polar.min_sink_rate().to_m_s(), -0.5947);
+
+        select(&mut cm, &mut cc, VarioSource::TotalEnergy);
+        cc.tick_100ms(&mut cm);
+        assert_eq!(cm.calculated.vario_climb_rate.to_m_s(), 1.0);
+
+        // the air rises with the climb rate plus the sink rate of the glider
+        select(&mut cm, &mut cc, VarioSource::Netto);
+        assert!(cm.control.vario_source == VarioSource::Netto);
+        cc.tick_100ms(&mut cm);
+        assert_float_eq!(cm.calculated.vario_climb_rate.to_m_s(), 1.8405);
+
+        // the glider sinks more in a turn
+        cm.sensor.vertical_g_force = (2.0 * STANDARD_GRAVITY).m_s2();
+        cc.tick_100ms(&mut cm);
+        assert_float_eq!(cm.calculated.vario_climb_rate.to_m_s(), 1.8544);
+
+        // the climb rate when circling with minimum sink in this air
+        cm.sensor.vertical_g_force = STANDARD_GRAVITY.m_s2();
+        select(&mut cm, &mut cc, VarioSource::Relative);
+        assert!(cm.control.vario_source == VarioSource::Relative);
+        cc.tick_100ms(&mut cm);
+        assert_float_eq!(cm.calculated.vario_climb_rate.to_m_s(), 1.2458);
+
+        select(&mut cm, &mut cc, VarioSource::TotalEnergy);
+        cc.tick_100ms(&mut cm);
+        assert_eq!(cm.calculated.vario_climb_rate.to_m_s(), 1.0);
+    }
 }
@@ -17,22 +17,26 @@
 use heapless::Vec;
 use num_enum::FromPrimitive;
 
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
 use super::{
     helpers::{GearPins, InPinFunction, InTogglePinFunction, OutPinFunction},
-    DataSource, VarioModeControl, MAX_PERS_IDS,
+    DataSource, VarioModeControl, VarioSource, MAX_PERS_IDS,
 };
 use crate::{
     basic_config::PERSISTENCE_TIMEOUT,
     controller::{
-        helpers::{CanConfigId, IntToDuration},
+        helpers::{CanConfigId, IntToDuration, QNH_MAX_HPA, QNH_MIN_HPA},
         RemoteConfig,
     },
     flight_physics::polar_store,
-    system_of_units::Speed,
+    model::{AutoMcMode, AutoQnhMode, Editable, GlideTarget, NmeaProfile, TonePreset, Units},
+    system_of_units::{Coord, FloatToLength, Latitude, Longitude, Speed},
     utils::Variant,
     view::{viewable::{centerview::CenterView, lineview::LineView}},
-    CoreController, CoreModel, FloatToSpeed, IdleEvent, Mass, PersistenceItem, Pressure,
-    ResetReason, Rotation, VarioMode,
+    CoreController, CoreError, CoreModel, FloatToMass, FloatToPressure, FloatToSpeed, IdleEvent,
+    Mass, PersistenceItem, Pressure, ResetReason, Rotation, VarioMode,
 };
 
 /// It is not permitted to change the sequence or assignment, as the number references the memory 
@@ -85,7 +89,44 @@ pub enum PersistenceId {
     StfClimbrateAlt = 42,
     TcCircleHysteresis = 43,
     EnergyArrowMult = 44,
-    LastItem = 45, // Items smaller than this are stored in eeprom
+    VarioSource = 45,
+    Units = 46,
+    GlideTarget = 47,
+    SafetyAltitude = 48,
+    HomeLatitude = 49,
+    HomeLongitude = 50,
+    HomeElevation = 51,
+    AutoMcMode = 52,
+    NmeaProfile = 53,
+    RemoteButton1 = 54,
+    RemoteButton2 = 55,
+    RemoteButton3 = 56,
+    RemoteButton4 = 57,
+    EngineRunTime = 58,
+    ToneProfile = 59,
+    ToneDeadBand = 60,
+    TonePitch = 61,
+    TonePulseRate = 62,
+    StallMargin = 63,
+    FlapPolar1 = 64,
+    FlapPolar2 = 65,
+    FlapPolar3 = 66,
+    FlapPolar4 = 67,
+    FlapPolar5 = 68,
+    FlapPolar6 = 69,
+    FlapPolar7 = 70,
+    FlapPolar8 = 71,
+    FlapPolar9 = 72,
+    FlapPolar10 = 73,
+    FlapPolar11 = 74,
+    FlapPolar12 = 75,
+    FlapPolar13 = 76,
+    FlapPolar14 = 77,
+    FlapPolar15 = 78,
+    WindSource = 79,
+    AutoQnhMode = 80,
+    FieldElevation = 81,
+    LastItem = 82, // Items smaller than this are stored in eeprom
 
     // Special function Ids
     VarioMode = 65532,
@@ -107,6 +148,21 @@ const PROFILE_0_IDS: &[PersistenceId] = &[
     PersistenceId::PolarValueSi1,
     PersistenceId::PolarValueSi2,
     PersistenceId::PolarValueSi3,
+    PersistenceId::FlapPolar1,
+    PersistenceId::FlapPolar2,
+    PersistenceId::FlapPolar3,
+    PersistenceId::FlapPolar4,
+    PersistenceId::FlapPolar5,
+    PersistenceId::FlapPolar6,
+    PersistenceId::FlapPolar7,
+    PersistenceId::FlapPolar8,
+    PersistenceId::FlapPolar9,
+    PersistenceId::FlapPolar10,
+    PersistenceId::FlapPolar11,
+    PersistenceId::FlapPolar12,
+    PersistenceId::FlapPolar13,
+    PersistenceId::FlapPolar14,
+    PersistenceId::FlapPolar15,
     PersistenceId::DrainPinConfig,
     PersistenceId::FlowEmpty,
     PersistenceId::FlowSlope,
@@ -115,6 +171,12 @@ const PROFILE_0_IDS: &[PersistenceId] = &[
     PersistenceId::GearPinConfig,
     PersistenceId::AirbrakesPinConfig,
     PersistenceId::GearAlarmMode,
+    PersistenceId::NmeaProfile,
+    PersistenceId::RemoteButton1,
+    PersistenceId::RemoteButton2,
+    PersistenceId::RemoteButton3,
+    PersistenceId::RemoteButton4,
+    PersistenceId::EngineRunTime,
 ];
 
 /// This list defines which data is destroyed when a profile is deleted
@@ -148,6 +210,22 @@ const DELETE_CONFIG_LIST: &[PersistenceId] = &[
     PersistenceId::StfClimbrateAlt,
     PersistenceId::TcCircleHysteresis,
     PersistenceId::EnergyArrowMult,
+    PersistenceId::VarioSource,
+    PersistenceId::Units,
+    PersistenceId::GlideTarget,
+    PersistenceId::SafetyAltitude,
+    PersistenceId::HomeLatitude,
+    PersistenceId::HomeLongitude,
+    PersistenceId::HomeElevation,
+    PersistenceId::AutoMcMode,
+    PersistenceId::ToneProfile,
+    PersistenceId::ToneDeadBand,
+    PersistenceId::TonePitch,
+    PersistenceId::TonePulseRate,
+    PersistenceId::StallMargin,
+    PersistenceId::WindSource,
+    PersistenceId::AutoQnhMode,
+    PersistenceId::FieldElevation,
 ];
 
 /// The following data is deleted when a new glider is selected
@@ -161,6 +239,21 @@ const SPECIFIC_POLAR_SETTINGS: &[PersistenceId] = &[
     PersistenceId::PolarValueSi1,
     PersistenceId::PolarValueSi2,
     PersistenceId::PolarValueSi3,
+    PersistenceId::FlapPolar1,
+    PersistenceId::FlapPolar2,
+    PersistenceId::FlapPolar3,
+    PersistenceId::FlapPolar4,
+    PersistenceId::FlapPolar5,
+    PersistenceId::FlapPolar6,
+    PersistenceId::FlapPolar7,
+    PersistenceId::FlapPolar8,
+    PersistenceId::FlapPolar9,
+    PersistenceId::FlapPolar10,
+    PersistenceId::FlapPolar11,
+    PersistenceId::FlapPolar12,
+    PersistenceId::FlapPolar13,
+    PersistenceId::FlapPolar14,
+    PersistenceId::FlapPolar15,
 ];
 
 /// Check if PersistenceId is in the list of profile 0
@@ -189,14 +282,18 @@ pub enum Echo {
 pub fn restore_item(cc: &mut CoreController, cm: &mut CoreModel, item: PersistenceItem) {
     match item.id {
         PersistenceId::UserProfile => cm.config.user_profile = item.to_u8(),
-        PersistenceId::Volume => cm.config.volume = item.to_i8(),
+        PersistenceId::Volume => {
+            // a new volume ends a temporary mute
+            cm.config.volume = item.to_i8();
+            cc.sound_control.unmute();
+        }
         PersistenceId::McCready => cm.config.mc_cready = Speed::from_m_s(item.to_f32()),
         PersistenceId::WaterBallast => cm.glider_data.water_ballast = Mass::from_kg(item.to_f32()),
         PersistenceId::PilotWeight => cm.glider_data.pilot_weight = Mass::from_kg(item.to_f32()),
         PersistenceId::Glider => {
             let raw_idx = item.to_i32();
             cm.config.glider_idx = raw_idx;
-            cm.glider_data.basic_glider_data = polar_store::POLARS[raw_idx as usize];
+            cm.glider_data.select_glider(polar_store::POLARS[raw_idx as usize]);
         }
         PersistenceId::VarioModeControl => {
             cm.control.vario_mode_control = VarioModeControl::from(item.to_u8())
@@ -231,24 +328,12 @@ pub fn restore_item(cc: &mut CoreController, cm: &mut CoreModel, item: Persisten
         PersistenceId::ReferenceWeight => {
             cm.glider_data.basic_glider_data.reference_weight = item.to_f32()
         }
-        PersistenceId::PolarValueV1 => {
-            cm.glider_data.basic_glider_data.polar_values[0][0] = item.to_f32()
-        }
-        PersistenceId::PolarValueV2 => {
-            cm.glider_data.basic_glider_data.polar_values[1][0] = item.to_f32()
-        }
-        PersistenceId::PolarValueV3 => {
-            cm.glider_data.basic_glider_data.polar_values[2][0] = item.to_f32()
-        }
-        PersistenceId::PolarValueSi1 => {
-            cm.glider_data.basic_glider_data.polar_values[0][1] = item.to_f32()
-        }
-        PersistenceId::PolarValueSi2 => {
-            cm.glider_data.basic_glider_data.polar_values[1][1] = item.to_f32()
-        }
-        PersistenceId::PolarValueSi3 => {
-            cm.glider_data.basic_glider_data.polar_values[2][1] = item.to_f32()
-        }
+        PersistenceId::PolarValueV1 => set_polar_value(cm, 0, 0, item.to_f32()),
+        PersistenceId::PolarValueV2 => set_polar_value(cm, 1, 0, item.to_f32()),
+        PersistenceId::PolarValueV3 => set_polar_value(cm, 2, 0, item.to_f32()),
+        PersistenceId::PolarValueSi1 => set_polar_value(cm, 0, 1, item.to_f32()),
+        PersistenceId::PolarValueSi2 => set_polar_value(cm, 1, 1, item.to_f32()),
+        PersistenceId::PolarValueSi3 => set_polar_value(cm, 2, 1, item.to_f32()),
         PersistenceId::GliderSymbol => cm.config.glider_symbol = item.to_bool(),
         PersistenceId::BatteryGood => cm.config.battery_good = item.to_f32(),
         PersistenceId::BatteryLow => cm.config.battery_low = item.to_f32(),
@@ -281,6 +366,61 @@ pub fn restore_item(cc: &mut CoreController, cm: &mut CoreModel, item: Persisten
         PersistenceId::StfClimbrateAlt => cm.config.alt_stf_thermal_climb = item.to_bool(),
         PersistenceId::TcCircleHysteresis => cm.config.circle_hysteresis_tc = item.to_i8(),
         PersistenceId::EnergyArrowMult => cm.control.energy_arrow_mult = item.to_f32(),
+        PersistenceId::VarioSource => cm.control.vario_source = VarioSource::from(item.to_u8()),
+        PersistenceId::Units => cm.config.units = Units::from(item.to_u32()),
+        PersistenceId::GlideTarget => cm.navigation.target = GlideTarget::from(item.to_u8()),
+        PersistenceId::SafetyAltitude => cm.navigation.safety_altitude = item.to_f32().m(),
+        PersistenceId::HomeLatitude => {
+            cm.navigation.home_mut().lat = Latitude(Coord::from_deg(item.to_i32() as f64 * 1e-7))
+        }
+        PersistenceId::HomeLongitude => {
+            cm.navigation.home_mut().lon = Longitude(Coord::from_deg(item.to_i32() as f64 * 1e-7))
+        }
+        PersistenceId::HomeElevation => {
+            cm.navigation.home_mut().elevation = Some(item.to_f32().m())
+        }
+        PersistenceId::AutoMcMode => cm.control.auto_mc_mode = AutoMcMode::from(item.to_u8()),
+        PersistenceId::NmeaProfile => cm.control.nmea_profile = NmeaProfile::from(item.to_u8()),
+        PersistenceId::RemoteButton1 => cc.remote_control.set_action(0, item.to_u8().into()),
+        PersistenceId::RemoteButton2 => cc.remote_control.set_action(1, item.to_u8().into()),
+        PersistenceId::RemoteButton3 => cc.remote_control.set_action(2, item.to_u8().into()),
+        PersistenceId::RemoteButton4 => cc.remote_control.set_action(3, item.to_u8().into()),
+        PersistenceId::EngineRunTime => cm.flight_stats.set_engine_total_secs(item.to_u32()),
+        PersistenceId::ToneProfile => cm.config.tone_preset = TonePreset::from(item.to_u8()),
+        PersistenceId::ToneDeadBand => {
+            let [sink, lift] = unpack_tone_pair(item.to_u32());
+            cm.config.custom_tone.set_sink_limit(sink);
+            cm.config.custom_tone.set_lift_limit(lift);
+        }
+        PersistenceId::TonePitch => {
+            let [sink, lift] = unpack_tone_pair(item.to_u32());
+            cm.config.custom_tone.set_sink_pitch(sink);
+            cm.config.custom_tone.set_lift_pitch(lift);
+        }
+        PersistenceId::TonePulseRate => {
+            let [slow, fast] = unpack_tone_pair(item.to_u32());
+            cm.config.custom_tone.set_slow_pulse_rate(slow);
+            cm.config.custom_tone.set_fast_pulse_rate(fast);
+        }
+        PersistenceId::StallMargin => cm.config.stall_margin = item.to_f32(),
+        PersistenceId::FlapPolar1 => set_flap_polar_point(cm, 0, item.to_u32()),
+        PersistenceId::FlapPolar2 => set_flap_polar_point(cm, 1, item.to_u32()),
+        PersistenceId::FlapPolar3 => set_flap_polar_point(cm, 2, item.to_u32()),
+        PersistenceId::FlapPolar4 => set_flap_polar_point(cm, 3, item.to_u32()),
+        PersistenceId::FlapPolar5 => set_flap_polar_point(cm, 4, item.to_u32()),
+        PersistenceId::FlapPolar6 => set_flap_polar_point(cm, 5, item.to_u32()),
+        PersistenceId::FlapPolar7 => set_flap_polar_point(cm, 6, item.to_u32()),
+        PersistenceId::FlapPolar8 => set_flap_polar_point(cm, 7, item.to_u32()),
+        PersistenceId::FlapPolar9 => set_flap_polar_point(cm, 8, item.to_u32()),
+        PersistenceId::FlapPolar10 => set_flap_polar_point(cm, 9, item.to_u32()),
+        PersistenceId::FlapPolar11 => set_flap_polar_point(cm, 10, item.to_u32()),
+        PersistenceId::FlapPolar12 => set_flap_polar_point(cm, 11, item.to_u32()),
+        PersistenceId::FlapPolar13 => set_flap_polar_point(cm, 12, item.to_u32()),
+        PersistenceId::FlapPolar14 => set_flap_polar_point(cm, 13, item.to_u32()),
+        PersistenceId::FlapPolar15 => set_flap_polar_point(cm, 14, item.to_u32()),
+        PersistenceId::WindSource => cm.control.wind_src = DataSource::from(item.to_u8()),
+        PersistenceId::AutoQnhMode => cm.control.auto_qnh_mode = AutoQnhMode::from(item.to_u8()),
+        PersistenceId::FieldElevation => cm.config.field_elevation = item.to_f32().m(),
 
         PersistenceId::VarioMode => cm.control.vario_mode = VarioMode::from(item.to_u8()),
 
@@ -290,6 +430,45 @@ pub fn restore_item(cc: &mut CoreController, cm: &mut CoreModel, item: Persisten
     }
 }
 
+// Points entered by the pilot replace the polar curve of the glider
+fn set_polar_value(cm: &mut CoreModel, point: usize, value: usize, content: f32) {
+    let bgd = &mut cm.glider_data.basic_glider_data;
+    bgd.polar_values[point][value] = content;
+    bgd.curve = None;
+}
+
+/// Two parameters of the custom tone are stored together in thousandths
+pub fn pack_tone_pair(pair: [f32; 2]) -> u32 {
+    let first = (pair[0] * 1000.0).round() as i16;
+    let second = (pair[1] * 1000.0).round() as i16;
+    (first as u16 as u32) << 16 | second as u16 as u32
+}
+
+fn unpack_tone_pair(packed: u32) -> [f32; 2] {
+    let first = (packed >> 16) as u16 as i16 as f32 / 1000.0;
+    let second = packed as u16 as i16 as f32 / 1000.0;
+    [first, second]
+}
+
+// The points of all flap polars are stored one after another
+fn set_flap_polar_point(cm: &mut CoreModel, idx: usize, packed: u32) {
+    let speed = (packed >> 16) as u16 as f32 / 10.0;
+    let sink = packed as u16 as i16 as f32 / 1000.0;
+    cm.glider_data.flap_polars[idx / 3][idx % 3] = [speed, sink];
+}
+
+/// Id of a point of the polar of a flap setting
+pub fn flap_polar_id(flap: usize, point: usize) -> PersistenceId {
+    PersistenceId::from(PersistenceId::FlapPolar1 as u16 + (flap * 3 + point) as u16)
+}
+
+/// A point of a flap polar is stored as speed in 0.1 km/h and sink rate in mm/s
+pub fn pack_polar_point(point: [f32; 2]) -> u32 {
+    let speed = (point[0] * 10.0).round() as u16;
+    let sink = (point[1] * 1000.0).round() as i16;
+    (speed as u32) << 16 | sink as u16 as u32
+}
+
 pub fn persist_set(
     cc: &mut CoreController,
     cm: &mut CoreModel,
@@ -315,6 +494,7 @@ pub fn persist_set(
         let frame = cm.can_frame_sys_config(CanConfigId::from(id));
         if let Some(frame) = frame {
             let _ = cc.p_tx_frames.enqueue(frame);
+            cc.dual_seat.local_edit(CanConfigId::from(id));
         }
     }
 
@@ -349,6 +529,56 @@ pub fn send_can_config_frame(
     }
 }
 
+/// Check a configuration value received via CAN or NMEA, ready to be passed to persist_set()
+///
+/// The value is given in SI units, the bugs as factor. It has to be within the limits of the
+/// editor, which uses the units of the display, otherwise CoreError::OutOfRange is returned. Ids
+/// without a value that can be set remotely return CoreError::ParseError, the vario mode is not
+/// handled here, because CAN and NMEA code it differently.
+pub fn config_value(
+    cm: &CoreModel,
+    config_id: CanConfigId,
+    value: f32,
+) -> Result<(Variant, PersistenceId), CoreError> {
+    let units = cm.config.units;
+    let in_params = match config_id {
+        CanConfigId::Volume => Editable::Volume.in_params(cm, value),
+        CanConfigId::MacCready => {
+            Editable::McCready.in_params(cm, units.vertical_speed.value(value.m_s()))
+        }
+        CanConfigId::WaterBallast if value > cm.glider_data.basic_glider_data.max_ballast => {
+            Err(CoreError::OutOfRange)
+        }
+        CanConfigId::WaterBallast => {
+            Editable::WaterBallast.in_params(cm, units.mass.value(value.kg()))
+        }
+        CanConfigId::Bugs => Editable::Bugs.in_params(cm, (value - 1.0) * 100.0),
+        CanConfigId::Qnh if !(QNH_MIN_HPA..=QNH_MAX_HPA).contains(&value) => {
+            Err(CoreError::OutOfRange)
+        }
+        CanConfigId::PilotWeight => {
+            Editable::PilotWeight.in_params(cm, units.mass.value(value.kg()))
+        }
+        CanConfigId::TcClimbRate => Editable::TcClimbRate.in_params(cm, value),
+        CanConfigId::TcSpeedToFly => Editable::TcSpeedToFly.in_params(cm, value),
+        _ => Ok(value),
+    };
+    if !value.is_finite() || in_params.is_err() {
+        return Err(CoreError::OutOfRange);
+    }
+    match config_id {
+        CanConfigId::Volume => Ok((Variant::I8(value as i8), PersistenceId::Volume)),
+        CanConfigId::MacCready => Ok((Variant::Speed(value.m_s()), PersistenceId::McCready)),
+        CanConfigId::WaterBallast => Ok((Variant::Mass(value.kg()), PersistenceId::WaterBallast)),
+        CanConfigId::Bugs => Ok((Variant::F32(value), PersistenceId::Bugs)),
+        CanConfigId::Qnh => Ok((Variant::Pressure(value.hpa()), PersistenceId::Qnh)),
+        CanConfigId::PilotWeight => Ok((Variant::Mass(value.kg()), PersistenceId::PilotWeight)),
+        CanConfigId::TcClimbRate => Ok((Variant::F32(value), PersistenceId::TcClimbRate)),
+        CanConfigId::TcSpeedToFly => Ok((Variant::F32(value), PersistenceId::TcSpeedToFly)),
+        _ => Err(CoreError::ParseError),
+    }
+}
+
 pub fn delete_config(cc: &mut CoreController) {
     cc.send_idle_event(IdleEvent::ClearEepromItems(DELETE_CONFIG_LIST));
     cc.send_idle_event(IdleEvent::ResetDevice(ResetReason::ConfigChanged));
@@ -389,7 +619,7 @@ pub fn store_persistence_ids(cm: &mut CoreModel, cc: &mut CoreController) {
     cc.nmea_vals.clear();
     while let Some(id) = ids.pop() {
         // Send data via NMEA
-        cc.nmea_send_config_data(id);
+        cc.nmea_send_config_data(cm, id);
     }
 
     // send remote value if necessary
@@ -422,4 +652,37 @@ pub fn set_vario_mode(cm: &mut CoreModel, cc: &mut CoreController, vario_mode: V
         };
         persist_set(cc, cm, Variant::U8(vario_mode as u8), PersistenceId::VarioMode, echo);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{editable::Content, CustomTone},
+        utils::tests::cores,
+    };
+
+    #[test]
+    fn test_custom_tone() {
+        let (mut cm, mut cc) = cores();
+        Editable::ToneSinkLimit.set_content(&mut cm, &mut cc, Content::F32(Some(-1.5)));
+        Editable::ToneLiftLimit.set_content(&mut cm, &mut cc, Content::F32(Some(0.5)));
+        Editable::ToneSinkPitch.set_content(&mut cm, &mut cc, Content::F32(Some(0.45)));
+        Editable::ToneFastBeeps.set_content(&mut cm, &mut cc, Content::F32(Some(6.0)));
+        assert!(cm.config.tone_preset == TonePreset::Custom);
+
+        // two parameters share one item, so changing one keeps the other
+        let items = cc.pers_vals.clone();
+        cm.config.custom_tone = CustomTone::default();
+        for item in items.iter() {
+            restore_item(&mut cc, &mut cm, *item);
+        }
+        let tone = cm.config.custom_tone;
+        assert_eq!(tone.sink_limit(), -1.5);
+        assert_eq!(tone.lift_limit(), 0.5);
+        assert_eq!(tone.sink_pitch(), 0.45);
+        assert_eq!(tone.lift_pitch(), 2.0);
+        assert_eq!(tone.slow_pulse_rate(), 1.65);
+        assert_eq!(tone.fast_pulse_rate(), 6.0);
+    }
+}
@@ -1,61 +1,66 @@
-use crate::{CoreModel, IdleEvent, VarioMode};
+use super::alarm_mixer::{Alarm, AlarmMixer, AlarmPattern};
+use crate::{
+    model::{EditMode, Editable, FlarmAlarmLevel, Tone, TypeOfInfo},
+    CoreModel, IdleEvent, VarioMode,
+};
 use num::clamp;
 
 #[allow(unused_imports)]
 use micromath::F32Ext;
 
-#[allow(unused)]
-pub enum SoundScenario {
-    Standard = 0b0000_0000,
-    GearAlarm = 0b0000_1000,
-}
-
-impl core::ops::BitAnd<u8> for SoundScenario {
-    type Output = bool;
-
-    fn bitand(self, rhs: u8) -> Self::Output {
-        self as u8 & rhs != 0
-    }
-}
-
+#[derive(Default)]
 pub struct SoundControl {
-    scenario: u8,
+    alarms: AlarmMixer,
+    current_alarm: Option<Alarm>,
     tick: u16,
+    preview_tick: u16,
+    muted: bool, // vario tone only, not stored
 }
 
-impl Default for SoundControl {
-    fn default() -> Self {
-        SoundControl {
-            scenario: SoundScenario::Standard as u8,
-            tick: 0,
-        }
+impl SoundControl {
+    pub fn set_alarm(&mut self, alarm: Alarm, active: bool) {
+        self.alarms.set(alarm, active);
     }
-}
 
-impl SoundControl {
-    pub fn activate_scenariio(&mut self, scenariio: SoundScenario) {
-        self.scenario |= scenariio as u8;
+    pub fn raise_alarm(&mut self, alarm: Alarm) {
+        self.alarms.raise(alarm);
     }
 
-    pub fn clear_scenariio(&mut self, scenariio: SoundScenario) {
-        self.scenario &= !(scenariio as u8);
+    /// Silence the current alarm, returns false if there is nothing to silence
+    pub fn acknowledge_alarm(&mut self) -> bool {
+        self.alarms.acknowledge()
     }
 
-    pub fn set_scenario(&mut self, scenariio: SoundScenario, active: bool) {
-        if active {
-            self.activate_scenariio(scenariio);
-        } else {
-            self.clear_scenariio(scenariio);
-        }
+    /// Mute or unmute the vario tone, alarms are always sounded
+    pub fn toggle_mute(&mut self) {
+        self.muted = !self.muted;
+    }
+
+    pub fn unmute(&mut self) {
+        self.muted = false;
     }
 
     // is called every 100ms
     pub fn sound(&mut self, cm: &mut CoreModel) -> Option<IdleEvent> {
-        let (frequency, continuous, gain) = if SoundScenario::GearAlarm & self.scenario {
-            self.gear_alarm_sound(cm)
-        } else {
+        self.alarms.tick_100ms();
+        let alarm = self.alarms.current();
+        if alarm != self.current_alarm {
+            self.current_alarm = alarm;
             self.tick = 0;
-            self.vario_sound(cm)
+        }
+        // the shown info always belongs to the sounded alarm
+        cm.config.info_active = alarm.map_or(TypeOfInfo::None, |alarm| alarm.info());
+
+        // the alarms use the default beeps, the vario tone sets its own
+        cm.calculated.duty_cycle = cm.config.snd_duty_cycle;
+        let (frequency, continuous, gain) = match alarm.map(|alarm| alarm.pattern()) {
+            Some(AlarmPattern::Flarm) => self.flarm_alarm_sound(cm),
+            Some(AlarmPattern::Stall) => self.stall_alarm_sound(cm),
+            Some(AlarmPattern::Overspeed) => self.overspeed_alarm_sound(cm),
+            Some(AlarmPattern::Gear) => self.gear_alarm_sound(cm),
+            Some(AlarmPattern::Engine) => self.engine_alarm_sound(cm),
+            Some(AlarmPattern::Reminder) => self.reminder_sound(cm),
+            Some(AlarmPattern::Silent) | None => self.vario_sound(cm),
         };
 
         cm.calculated.frequency = clamp(
@@ -78,29 +83,53 @@ impl SoundControl {
 
     fn vario_sound(&mut self, cm: &mut CoreModel) -> (u16, bool, i8) {
         // calculate sound parameters and push can frame to queue
-        let cms = &cm.sensor;
         let cmc = &cm.config;
-        match cm.control.vario_mode {
-            VarioMode::Vario => (
-                (cmc.snd_center_freq * (cmc.snd_exp_mul * cms.climb_rate.to_m_s()).exp()) as u16,
-                cms.climb_rate.to_m_s() < 0.0,
-                cmc.volume,
-            ),
-            VarioMode::SpeedToFly => {
-                let stf_dif = -cm.calculated.speed_to_fly_dif.to_km_h();
-                let stf_val_ms = stf_dif / 10.0;
-                if stf_dif < cm.config.stf_upper_limit.to_km_h()
-                    && stf_dif > cm.config.stf_lower_limit.to_km_h()
-                {
-                    (500, true, 0) // speed to fly is ok, so be quiet
-                } else {
-                    (
-                        (cmc.snd_center_freq * (cmc.snd_exp_mul * stf_val_ms).exp()) as u16,
-                        stf_val_ms < 0.0,
-                        cmc.volume,
-                    )
+        let profile = cmc.tone_preset.profile(&cmc.custom_tone);
+        let tone = if cm.control.editor.mode != EditMode::Off
+            && matches!(
+                cm.control.editor.target,
+                Editable::ToneProfile
+                    | Editable::ToneSinkLimit
+                    | Editable::ToneLiftLimit
+                    | Editable::ToneSinkPitch
+                    | Editable::ToneLiftPitch
+                    | Editable::ToneSlowBeeps
+                    | Editable::ToneFastBeeps
+            ) {
+            // preview the edited profile with a sweep from 5 to -5 m/s and back in 10 s
+            self.preview_tick = (self.preview_tick + 1) % 100;
+            let climb = (self.preview_tick as f32 - 50.0).abs() / 5.0 - 5.0;
+            profile.tone(climb, cmc.snd_center_freq)
+        } else {
+            match cm.control.vario_mode {
+                VarioMode::Vario => {
+                    let climb_rate = cm.calculated.vario_climb_rate.to_m_s();
+                    profile.tone(climb_rate, cmc.snd_center_freq)
                 }
+                VarioMode::SpeedToFly => {
+                    let stf_dif = -cm.calculated.speed_to_fly_dif.to_km_h();
+                    if stf_dif < cmc.stf_upper_limit.to_km_h()
+                        && stf_dif > cmc.stf_lower_limit.to_km_h()
+                    {
+                        None // speed to fly is ok, so be quiet
+                    } else {
+                        Some(profile.curve(stf_dif / 10.0, cmc.snd_center_freq))
+                    }
+                }
+            }
+        };
+
+        match tone {
+            Some(_) if self.muted => (500, true, 0),
+            Some(Tone {
+                frequency,
+                continuous,
+                duty_cycle,
+            }) => {
+                cm.calculated.duty_cycle = duty_cycle;
+                (frequency, continuous, cmc.volume)
             }
+            None => (500, true, 0),
         }
     }
 
@@ -139,4 +168,109 @@ impl SoundControl {
             }
         }
     }
+
+    fn flarm_alarm_sound(&mut self, cm: &mut CoreModel) -> (u16, bool, i8) {
+        // short beeps, the higher the alarm level, the faster and higher the beeps
+        let (frequency, period) = match cm.flarm.alarm_level {
+            FlarmAlarmLevel::Urgent => (1800, 2),
+            FlarmAlarmLevel::Important => (1500, 3),
+            _ => (1200, 5),
+        };
+
+        self.tick += 1;
+        if self.tick >= period {
+            self.tick = 0;
+        }
+
+        match self.tick {
+            0 => (frequency, true, cm.control.alarm_volume),
+            _ => (frequency, false, 0), // silence
+        }
+    }
+
+    fn engine_alarm_sound(&mut self, cm: &mut CoreModel) -> (u16, bool, i8) {
+        // two long low beeps every three seconds, distinct from the gear and flarm alarms
+        const FREQUENCY: u16 = 500;
+
+        self.tick += 1;
+        if self.tick >= 30 {
+            self.tick = 0;
+        }
+
+        match self.tick {
+            0..=4 | 7..=11 => (FREQUENCY, true, cm.control.alarm_volume),
+            _ => (FREQUENCY, false, 0), // silence
+        }
+    }
+
+    fn stall_alarm_sound(&mut self, cm: &mut CoreModel) -> (u16, bool, i8) {
+        // continuous warble between two tones
+        self.tick = (self.tick + 1) % 2;
+        match self.tick {
+            0 => (800, true, cm.control.alarm_volume),
+            _ => (1100, true, cm.control.alarm_volume),
+        }
+    }
+
+    fn overspeed_alarm_sound(&mut self, cm: &mut CoreModel) -> (u16, bool, i8) {
+        // rising sweeps, one per second
+        const START_FREQ: u16 = 1000;
+        const INC_FREQ: u16 = 80;
+
+        self.tick = (self.tick + 1) % 10;
+        (
+            START_FREQ + self.tick * INC_FREQ,
+            true,
+            cm.control.alarm_volume,
+        )
+    }
+
+    fn reminder_sound(&mut self, cm: &mut CoreModel) -> (u16, bool, i8) {
+        // two short beeps every ten seconds
+        const FREQUENCY: u16 = 900;
+
+        self.tick += 1;
+        if self.tick >= 100 {
+            self.tick = 0;
+        }
+
+        match self.tick {
+            1 | 3 => (FREQUENCY, true, cm.control.alarm_volume),
+            _ => (FREQUENCY, false, 0), // silence
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{utils::tests::cores, FloatToSpeed};
+
+    #[test]
+    fn test_mute() {
+        let (mut cm, _cc) = cores();
+        let mut sound_control = SoundControl::default();
+        cm.control.vario_mode = VarioMode::Vario;
+        cm.calculated.vario_climb_rate = 3.0.m_s();
+        cm.config.volume = 12;
+
+        sound_control.sound(&mut cm);
+        assert_eq!(cm.calculated.gain, 12);
+
+        // the vario tone is muted without changing the volume
+        sound_control.toggle_mute();
+        sound_control.sound(&mut cm);
+        assert_eq!(cm.calculated.gain, 0);
+        assert_eq!(cm.config.volume, 12);
+
+        sound_control.toggle_mute();
+        sound_control.sound(&mut cm);
+        assert_eq!(cm.calculated.gain, 12);
+
+        // alarms are sounded anyway
+        sound_control.toggle_mute();
+        sound_control.raise_alarm(Alarm::Stall);
+        sound_control.sound(&mut cm);
+        assert_eq!(cm.calculated.gain, cm.control.alarm_volume);
+    }
 }
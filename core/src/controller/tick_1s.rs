@@ -1,14 +1,54 @@
 use crate::{
-    controller::persist::{set_vario_mode, persist_set},
-    model::{GpsState, SystemState, TcrMode, VarioModeControl},
+    basic_config::AUTO_MC_THERMALS,
+    controller::{
+        persist::{persist_set, set_vario_mode},
+        Alarm,
+    },
+    flight_physics::{distance_and_course, FinalGlide},
+    into_range_180_180,
+    model::{
+        AutoMcMode, DataSource, DisplayActive, GpsState, SystemState, TcrMode, VarioModeControl,
+        Waypoint,
+    },
     utils::Variant,
-    CoreController, CoreModel, Echo, FloatToSpeed, FlyMode, IdleEvent, PersistenceId, VarioMode,
+    CoreController, CoreModel, Echo, FloatToAngularVelocity, FloatToSpeed, FlyMode, IdleEvent,
+    PersistenceId, VarioMode,
 };
 use num::clamp;
 
+// icing conditions: OAT in °C and relative humidity in %, the second value is the hysteresis
+const ICING_MIN_OAT: (f32, f32) = (-5.0, 1.0);
+const ICING_MAX_OAT: (f32, f32) = (3.0, 1.0);
+const ICING_MIN_HUMIDITY: (f32, f32) = (80.0, 5.0);
+
+// ground speed in m/s, below which the GNSS track is not used for the turn rate
+const GNSS_TRACK_MIN_SPEED: f32 = 10.0;
+
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
 pub fn recalc_polar(cm: &mut CoreModel, cc: &mut CoreController) {
     cc.polar.recalc(&cm.glider_data, cm.sensor.density);
 
+    let _ = cc.scheduler.chain(gnss_turn_rate);
+}
+
+fn gnss_turn_rate(cm: &mut CoreModel, cc: &mut CoreController) {
+    // without a turn rate from the sensorbox, it is derived from the change of the GNSS track,
+    // which is only meaningful at a reasonable ground speed
+    let gps_track = (cm.sensor.gps_state != GpsState::NoGps
+        && cm.sensor.gps_ground_speed.to_m_s() > GNSS_TRACK_MIN_SPEED)
+        .then_some(cm.sensor.gps_track);
+    if cm.control.turn_rate_ticks > 0 {
+        cm.control.turn_rate_ticks -= 1;
+    } else {
+        cm.sensor.turn_rate = match (cc.last_gps_track, gps_track) {
+            (Some(last), Some(track)) => into_range_180_180(track - last).to_radians().rad_s(),
+            _ => 0.0.rad_s(),
+        };
+    }
+    cc.last_gps_track = gps_track;
+
     let _ = cc.scheduler.chain(speed_to_fly);
 }
 
@@ -16,13 +56,18 @@ fn speed_to_fly(cm: &mut CoreModel, cc: &mut CoreController) {
     let stf = cc.polar.speed_to_fly(0.0.m_s(), 0.0.m_s());
     cm.control.speed_to_fly_limit = stf.ias() * cm.control.vario_mode_switch_ratio;
 
+    // the slave of a two-seater receives fly mode and vario mode from the master
+    let sync_slave = cm.control.sync_slave_ticks > 0;
+
     // In auto mode switch between Vario and SpeedToFly
-    if cm.sensor.airspeed.ias() > cm.control.speed_to_fly_limit
-        && cm.control.fly_mode == FlyMode::StraightFlight
-    {
-        set_vario_mode(cm, cc, VarioMode::SpeedToFly, VarioModeControl::Auto);
-    } else {
-        set_vario_mode(cm, cc, VarioMode::Vario, VarioModeControl::Auto);
+    if !sync_slave {
+        if cm.sensor.airspeed.ias() > cm.control.speed_to_fly_limit
+            && cm.control.fly_mode == FlyMode::StraightFlight
+        {
+            set_vario_mode(cm, cc, VarioMode::SpeedToFly, VarioModeControl::Auto);
+        } else {
+            set_vario_mode(cm, cc, VarioMode::Vario, VarioModeControl::Auto);
+        }
     }
 
     // in pin mode set according to pin state
@@ -90,10 +135,10 @@ fn speed_to_fly(cm: &mut CoreModel, cc: &mut CoreController) {
     };
     hyst = clamp(hyst, 0, cm.config.circle_hysteresis_tc);
 
-    if hyst == cm.config.circle_hysteresis_tc {
+    if hyst == cm.config.circle_hysteresis_tc && !sync_slave {
         cm.control.fly_mode = FlyMode::Circling;
     }
-    if hyst == 0 {
+    if hyst == 0 && !sync_slave {
         cm.control.fly_mode = FlyMode::StraightFlight;
     }
     cm.calculated.circle_hysteresis = hyst;
@@ -134,7 +179,7 @@ fn send_can_nmea(cm: &mut CoreModel, cc: &mut CoreController) {
     let can_frame = cm.can_frame_volt_temp();
     let _ = cc.p_tx_frames.enqueue(can_frame);
 
-    cc.nmea_cyclic_1s();
+    cc.nmea_cyclic_1s(cm);
     let _ = cc.scheduler.chain(process_hardware_pins);
 }
 
@@ -154,4 +199,338 @@ fn process_hardware_pins(cm: &mut CoreModel, cc: &mut CoreController) {
     if let Some(state) = cc.flash_control.tick_1s(cm) {
         let _ = cc.p_idle_events.enqueue(IdleEvent::Output1(state));
     }
+
+    let _ = cc.scheduler.chain(age_flarm_data);
+}
+
+fn age_flarm_data(cm: &mut CoreModel, cc: &mut CoreController) {
+    // remove outdated traffic and stop the alarm if FLARM is silent
+    cm.flarm.tick_1s();
+    cc.sound_control
+        .set_alarm(Alarm::Flarm, cm.flarm.alarm_active());
+
+    let _ = cc.scheduler.chain(log_flight);
+}
+
+fn log_flight(cm: &mut CoreModel, cc: &mut CoreController) {
+    // detect takeoff and landing, write the IGC file to the SD card
+    if let Some(cmd) = cc.flight_logger.tick_1s(cm) {
+        cc.send_idle_event(IdleEvent::SdCardItem(cmd));
+    }
+
+    let _ = cc.scheduler.chain(flight_statistics);
+}
+
+fn flight_statistics(cm: &mut CoreModel, cc: &mut CoreController) {
+    // record thermals and glides, uses the flight state of the logger
+    let mut flight_stats = cm.flight_stats;
+    flight_stats.tick_1s(cm, cc.flight_logger.is_flying());
+    cm.flight_stats = flight_stats;
+
+    let _ = cc.scheduler.chain(auto_mc_cready);
+}
+
+fn auto_mc_cready(cm: &mut CoreModel, cc: &mut CoreController) {
+    // derive the MacCready value from the recent thermals, updated after each thermal
+    if cm.control.auto_mc_mode == AutoMcMode::Off {
+        cm.calculated.mc_suggestion = None;
+        cc.last_thermal_count = 0;
+    } else if cm.control.sync_slave_ticks > 0 {
+        // the suggestion of the master is used, it is received via CAN
+    } else if cm.flight_stats.thermal_count() != cc.last_thermal_count {
+        cc.last_thermal_count = cm.flight_stats.thermal_count();
+        let suggestion = cm
+            .flight_stats
+            .recent_climb_rate(AUTO_MC_THERMALS)
+            .map(|climb_rate| (clamp(climb_rate.to_m_s(), 0.0, 5.0) * 10.0).round() / 10.0);
+        cm.calculated.mc_suggestion = suggestion.map(|mc| mc.m_s());
+
+        if let (AutoMcMode::Auto, Some(mc)) = (cm.control.auto_mc_mode, suggestion) {
+            persist_set(
+                cc,
+                cm,
+                Variant::Speed(mc.m_s()),
+                PersistenceId::McCready,
+                Echo::NmeaAndCan,
+            );
+        }
+    }
+
+    let _ = cc.scheduler.chain(estimate_wind);
+}
+
+fn estimate_wind(cm: &mut CoreModel, cc: &mut CoreController) {
+    let sensor_wind = cm.control.sensor_wind_ticks > 0;
+    if sensor_wind {
+        cm.control.sensor_wind_ticks -= 1;
+    }
+
+    let circling =
+        cm.control.fly_mode == FlyMode::Circling && cm.sensor.gps_state != GpsState::NoGps;
+    let estimate =
+        cc.circling_wind
+            .update(circling, cm.sensor.gps_track, cm.sensor.gps_ground_speed);
+
+    // the circling wind replaces the sensorbox wind, if it is selected or missing
+    if let Some(estimate) = estimate {
+        cm.calculated.wind_quality = estimate.quality;
+        if cm.control.wind_src == DataSource::Frontend || !sensor_wind {
+            cm.sensor.wind_vector = estimate.wind;
+            cm.sensor.average_wind = cc.circling_wind.average().unwrap_or(estimate.wind);
+        }
+    }
+
+    let _ = cc.scheduler.chain(calc_final_glide);
+}
+
+fn calc_final_glide(cm: &mut CoreModel, cc: &mut CoreController) {
+    // final glide to the selected destination, requires a GPS position and the elevation
+    cm.navigation.final_glide = match cm.navigation.destination() {
+        Some(Waypoint {
+            lat,
+            lon,
+            elevation: Some(elevation),
+        }) if cm.sensor.gps_state != GpsState::NoGps => {
+            let (distance, course) =
+                distance_and_course(cm.sensor.gps_lat, cm.sensor.gps_lon, lat, lon);
+            FinalGlide::calc(
+                &cc.polar,
+                cm.config.mc_cready,
+                cm.sensor.wind_vector,
+                distance,
+                course,
+                cm.sensor.pressure_altitude.qnh_altitude(),
+                elevation + cm.navigation.safety_altitude,
+            )
+        }
+        _ => None,
+    };
+
+    let _ = cc.scheduler.chain(icing_warning);
+}
+
+fn icing_warning(cm: &mut CoreModel, cc: &mut CoreController) {
+    // the values are unknown, if the sensor stops sending
+    cm.control.oat_ticks = cm.control.oat_ticks.saturating_sub(1);
+    if cm.control.oat_ticks == 0 {
+        cm.sensor.oat = None;
+    }
+    cm.control.humidity_ticks = cm.control.humidity_ticks.saturating_sub(1);
+    if cm.control.humidity_ticks == 0 {
+        cm.sensor.humidity = None;
+    }
+
+    // the limits are widened by the hysteresis as long as there is a risk of icing
+    let margin = |limit: (f32, f32)| match cm.calculated.icing_risk {
+        true => limit.1,
+        false => 0.0,
+    };
+    let icing_risk = match (cm.sensor.oat, cm.sensor.humidity) {
+        (Some(oat), Some(humidity)) => {
+            let oat = oat.to_deg_c();
+            oat >= ICING_MIN_OAT.0 - margin(ICING_MIN_OAT)
+                && oat <= ICING_MAX_OAT.0 + margin(ICING_MAX_OAT)
+                && humidity >= ICING_MIN_HUMIDITY.0 - margin(ICING_MIN_HUMIDITY)
+        }
+        _ => false,
+    };
+
+    // the warning is shown for some seconds when the risk arises
+    cc.sound_control.set_alarm(Alarm::Icing, icing_risk);
+    cm.calculated.icing_risk = icing_risk;
+
+    let _ = cc.scheduler.chain(flap_recommendation);
+}
+
+fn flap_recommendation(cm: &mut CoreModel, cc: &mut CoreController) {
+    // compare the flap position with the speed band of the current airspeed and wing loading
+    let bgd = &cm.glider_data.basic_glider_data;
+    match bgd.flaps {
+        Some(flaps) if bgd.wing_area > 0.0 => {
+            let mass = bgd.empty_mass
+                + (cm.glider_data.pilot_weight + cm.glider_data.water_ballast).to_kg();
+            cm.calculated.optimal_flap =
+                Some(flaps.optimal_setting(cm.sensor.airspeed.ias(), mass / bgd.wing_area));
+            let sensor = cm
+                .sensor
+                .flap_position
+                .map(|position| flaps.setting_from_position(position));
+            // a setting selected by the pilot overrides the flap sensor
+            cm.calculated.flap_setting = cm.control.flap_select.or(sensor);
+        }
+        _ => {
+            // without a flap table only the setting selected by the pilot is known
+            cm.calculated.optimal_flap = None;
+            cm.calculated.flap_setting = cm.control.flap_select;
+        }
+    }
+    cc.polar.set_flap(cm.calculated.flap_setting);
+
+    if let Some(frame) = cm.can_frame_flaps_status() {
+        let _ = cc.p_tx_frames.enqueue(frame);
+    }
+
+    let _ = cc.scheduler.chain(dual_seat_sync);
+}
+
+fn dual_seat_sync(cm: &mut CoreModel, cc: &mut CoreController) {
+    // the slave mode ends, if the master is silent
+    cm.control.sync_slave_ticks = cm.control.sync_slave_ticks.saturating_sub(1);
+
+    // the master of a two-seater shares profile and glider with the other frontend
+    let partner = cm.can_bus.sync_partner();
+    let is_master = cm.can_bus.is_sync_master();
+    cc.dual_seat.tick_1s(partner, is_master);
+    if is_master && partner.is_some() {
+        let can_frame = cm.can_frame_sync_settings();
+        let _ = cc.p_tx_frames.enqueue(can_frame);
+    }
+
+    let _ = cc.scheduler.chain(engine_monitor);
+}
+
+fn engine_monitor(cm: &mut CoreModel, cc: &mut CoreController) {
+    let was_running = cm.engine.is_running();
+    let warning = cm.engine.tick_1s();
+    let running = cm.engine.is_running();
+
+    // the total run time is stored every minute and when the motor stops
+    cm.flight_stats.engine_tick_1s(running);
+    if (running && cm.flight_stats.engine_secs().is_multiple_of(60)) || (was_running && !running) {
+        persist_set(
+            cc,
+            cm,
+            Variant::U32(cm.flight_stats.engine_total_secs()),
+            PersistenceId::EngineRunTime,
+            Echo::None,
+        );
+    }
+
+    // show the engine page while the motor runs
+    if running && !was_running {
+        let display_active = cm.config.display_active;
+        if matches!(
+            display_active,
+            DisplayActive::Vario | DisplayActive::Horizon
+        ) {
+            cc.engine_return_display = Some(display_active);
+            cm.config.display_active = DisplayActive::Engine;
+        }
+    } else if !running && was_running {
+        if let Some(display_active) = cc.engine_return_display.take() {
+            if cm.config.display_active == DisplayActive::Engine {
+                cm.config.display_active = display_active;
+            }
+        }
+    }
+
+    // a new warning is sounded and shown for some seconds
+    if warning.is_some() {
+        cc.sound_control.raise_alarm(Alarm::Engine);
+    }
+    cc.sound_control
+        .set_alarm(Alarm::Engine, cm.engine.warning.is_some());
+
+    let _ = cc.scheduler.chain(age_airspeed);
+}
+
+fn age_airspeed(cm: &mut CoreModel, cc: &mut CoreController) {
+    // the speed warnings are given only while the sensorbox sends an airspeed
+    cm.control.airspeed_ticks = cm.control.airspeed_ticks.saturating_sub(1);
+
+    let _ = cc.scheduler.chain(auto_qnh);
+}
+
+fn auto_qnh(cm: &mut CoreModel, cc: &mut CoreController) {
+    let flying = cc.flight_logger.is_flying();
+    let gnss_altitude = match cm.sensor.gps_state {
+        GpsState::NoGps => None,
+        _ => Some(cm.sensor.gps_altitude),
+    };
+    // the static pressure is the default one, until the sensorbox sends it
+    let pressure = (cm.control.pressure_ticks > 0).then_some(cm.sensor.pressure);
+    cm.control.pressure_ticks = cm.control.pressure_ticks.saturating_sub(1);
+    if let Some((qnh, elevation)) = cc.auto_qnh.update(
+        cm.control.auto_qnh_mode,
+        flying,
+        pressure,
+        gnss_altitude,
+        cm.sensor.gps_sats,
+        cm.config.field_elevation,
+    ) {
+        cm.calculated.qnh_proposal = Some(qnh);
+        cm.calculated.qnh_elevation = elevation;
+    }
+
+    // the dialog waits for the vario or horizon page and is withdrawn at take-off
+    let display_active = cm.config.display_active;
+    if flying {
+        let proposal = cm.calculated.qnh_proposal.take();
+        if proposal.is_some() && display_active == DisplayActive::QnhDialog {
+            cm.config.display_active = cm.config.last_display_active;
+        }
+    } else if cm.calculated.qnh_proposal.is_some()
+        && matches!(
+            display_active,
+            DisplayActive::Vario | DisplayActive::Horizon
+        )
+    {
+        cm.config.last_display_active = display_active;
+        cm.config.display_active = DisplayActive::QnhDialog;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        basic_config::{OAT_HUMIDITY_TIMEOUT, SENSOR_TURN_RATE_TIMEOUT},
+        utils::tests::cores,
+        AngleUnit, FloatToTemperature,
+    };
+
+    #[test]
+    fn test_icing_timeout() {
+        let (mut cm, mut cc) = cores();
+        cm.sensor.oat = Some(0.0.deg_c());
+        cm.sensor.humidity = Some(90.0);
+        cm.control.oat_ticks = OAT_HUMIDITY_TIMEOUT;
+        cm.control.humidity_ticks = OAT_HUMIDITY_TIMEOUT;
+        icing_warning(&mut cm, &mut cc);
+        assert!(cm.calculated.icing_risk);
+
+        // the values are dropped, if the sensor is silent
+        for _ in 1..OAT_HUMIDITY_TIMEOUT {
+            icing_warning(&mut cm, &mut cc);
+        }
+        assert!(cm.sensor.oat.is_none());
+        assert!(cm.sensor.humidity.is_none());
+        assert!(!cm.calculated.icing_risk);
+    }
+
+    #[test]
+    fn test_circling_from_gnss_track() {
+        let (mut cm, mut cc) = cores();
+        cm.sensor.gps_state = GpsState::PosAvail;
+        cm.sensor.gps_ground_speed = 25.0.m_s();
+
+        // a circle in 24 s without a turn rate from the sensorbox
+        for t in 0..10 {
+            cm.sensor.gps_track = into_range_180_180((t as f32 * 15.0).deg());
+            gnss_turn_rate(&mut cm, &mut cc);
+            speed_to_fly(&mut cm, &mut cc);
+        }
+        assert!(cm.sensor.turn_rate.to_rad_s() > 0.2);
+        assert!(cm.control.fly_mode == FlyMode::Circling);
+
+        // the turn rate of the sensorbox takes precedence
+        for _ in 0..10 {
+            cm.sensor.turn_rate = 0.0.rad_s();
+            cm.control.turn_rate_ticks = SENSOR_TURN_RATE_TIMEOUT;
+            cm.sensor.gps_track += 15.0.deg();
+            gnss_turn_rate(&mut cm, &mut cc);
+            speed_to_fly(&mut cm, &mut cc);
+        }
+        assert!(cm.control.fly_mode == FlyMode::StraightFlight);
+    }
 }
@@ -5,7 +5,10 @@
 /// - see also 'fit.ipynb' in the doc directory
 /// - see [Ambiance](https://pypi.org/project/ambiance/)
 ///
-use crate::system_of_units::{Density, Float, Length, Pressure};
+use crate::system_of_units::{Density, Float, Length, Pressure, Temperature};
+
+#[allow(unused_imports)]
+use micromath::F32Ext;
 
 #[inline]
 fn horner(x: Float, coefs: &[Float]) -> Float {
@@ -40,12 +43,12 @@ pub fn density(altitude: Length) -> Density {
     Density(horner(altitude.0, &COEFS))
 }
 
-/* Not used at the moment
 // Error below 0.01% between 0..10_000m
+#[allow(clippy::excessive_precision)]
 pub fn temperature(altitude: Length) -> Temperature {
     const COEFS: [Float; 2] = [-6.48978914e-03, 2.88133007e+02];
     Temperature(horner(altitude.0, &COEFS))
-}*/
+}
 
 // Error below 4m between 0..10_000m
 #[allow(clippy::excessive_precision)]
@@ -61,6 +64,21 @@ pub fn altitude(static_air_pressure: Pressure) -> Length {
     Length(horner(static_air_pressure.0, &COEFS))
 }
 
+/// Density altitude, the altitude in the ISA at which the air has the given density
+///
+/// Inverse of the troposphere equation of the ICAO standard atmosphere.
+pub fn density_altitude(density: Density) -> Length {
+    const EXPONENT: Float = 0.234969; // 1 / (g * M / (R * L) - 1)
+    Length(44330.8 * (1.0 - (density.0 / Density::AT_NN().0).powf(EXPONENT)))
+}
+
+/// QNH at which the QNH altitude of the static pressure is the given elevation
+///
+/// Inverse of `PressureAltitude::qnh_altitude`, used to set the QNH on a field of known elevation.
+pub fn qnh_from_elevation(static_pressure: Pressure, elevation: Length) -> Pressure {
+    static_pressure - pressure(elevation) + Pressure::AT_NN()
+}
+
 #[derive(Clone, Copy)]
 pub struct PressureAltitude {
     qnh_ref: Pressure,
@@ -121,3 +139,32 @@ impl PressureAltitude {
         self.static_pressure = pressure
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FloatToLength, FloatToPressure};
+
+    #[test]
+    fn test_density_altitude() {
+        for altitude in (0..=10_000).step_by(1000) {
+            let altitude = (altitude as f32).m();
+            let result = density_altitude(density(altitude));
+            assert!((result.to_m() - altitude.to_m()).abs() < 20.0);
+        }
+        assert!((temperature(0.0.m()).to_deg_c() - 15.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_qnh_from_elevation() {
+        let mut pressure_altitude = PressureAltitude::default();
+        for (qnh, elevation) in [(1013.25, 0.0), (1020.0, 450.0), (995.0, 1800.0)] {
+            let static_pressure = pressure(elevation.m()) + (qnh.hpa() - Pressure::AT_NN());
+            let result = qnh_from_elevation(static_pressure, elevation.m());
+            assert!((result.to_hpa() - qnh).abs() < 0.1);
+            pressure_altitude.set_qnh(result);
+            pressure_altitude.set_static_pressure(static_pressure);
+            assert!((pressure_altitude.qnh_altitude().to_m() - elevation).abs() < 5.0);
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use heapless::Vec;
+
+use crate::{
+    flight_physics::WindVector,
+    system_of_units::{Angle, Float, FloatToSpeed, Speed},
+};
+use core::f32::consts::PI;
+
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
+/// Samples of a circle, a circle taking longer is not used
+const MAX_SAMPLES: usize = 64;
+
+/// Samples of a circle at least, otherwise the GNSS track is not trustworthy
+const MIN_SAMPLES: usize = 8;
+
+/// Highest quality, it is reached after this number of consecutive circles
+pub const MAX_WIND_QUALITY: u8 = 5;
+
+/// Relative spread of the airspeed within a circle, which reduces the quality
+const SPREAD_REDUCED: Float = 0.1;
+
+/// Relative spread of the airspeed within a circle, above which the circle is not used
+const SPREAD_REJECTED: Float = 0.2;
+
+/// Minimum airspeed in m/s, slower the glider is not flying
+const MIN_AIRSPEED: Float = 10.0;
+
+/// Wind of a circle and its quality from 1 to MAX_WIND_QUALITY
+#[derive(Clone, Copy)]
+pub struct WindEstimate {
+    pub wind: WindVector,
+    pub quality: u8,
+}
+
+/// Wind estimation from the drift while circling
+///
+/// Flying a circle at constant airspeed, the air velocities of the glider add up to zero. So
+/// the mean of the ground velocities from GNSS track and ground speed over a full circle is the
+/// wind. The spread of the airspeeds, which are the differences between ground velocity and
+/// wind, shows how well the circle was flown.
+///
+/// Each completed circle results in an estimate, whose quality grows with the number of
+/// consecutive circles. The average wind is the mean of the estimates weighted by quality.
+#[derive(Default)]
+pub struct CirclingWind {
+    samples: Vec<[Float; 2], MAX_SAMPLES>, // ground velocity north and east in m/s
+    last_track: Option<Float>,             // rad
+    turned: Float,                         // rad since the start of circling
+    circle_start: Float,                   // rad turned at the start of the circle
+    circles: u8,                           // consecutive circles
+    average: Option<[Float; 2]>,           // wind velocity north and east in m/s
+}
+
+impl CirclingWind {
+    /// Adds a GNSS sample, a new estimate is returned after each full circle
+    pub fn update(
+        &mut self,
+        circling: bool,
+        track: Angle,
+        ground_speed: Speed,
+    ) -> Option<WindEstimate> {
+        if !circling {
+            self.reset();
+            return None;
+        }
+        let track = track.to_radians();
+        let sample = [
+            ground_speed.to_m_s() * track.cos(),
+            ground_speed.to_m_s() * track.sin(),
+        ];
+        let mut delta = 0.0;
+        if let Some(last_track) = self.last_track.replace(track) {
+            delta = track - last_track;
+            if delta > PI {
+                delta -= 2.0 * PI;
+            } else if delta < -PI {
+                delta += 2.0 * PI;
+            }
+            if self.turned * delta < 0.0 {
+                // the direction of the turn changed, so the circle is not complete
+                self.reset();
+                self.last_track = Some(track);
+            }
+            self.turned += delta;
+        }
+
+        // the circle is complete, if the current sample is nearer to the start of the next one
+        let mut estimate = None;
+        if (self.turned - self.circle_start).abs() + delta.abs() / 2.0 >= 2.0 * PI {
+            estimate = self.estimate();
+            self.samples.clear();
+            self.circle_start += 2.0 * PI * self.turned.signum();
+        }
+        if self.samples.push(sample).is_err() {
+            self.reset();
+        }
+        estimate
+    }
+
+    /// Average wind of all circles so far
+    pub fn average(&self) -> Option<WindVector> {
+        self.average.map(|wind| wind_vector(&wind))
+    }
+
+    // Circling ended or failed, the average wind is kept
+    fn reset(&mut self) {
+        self.samples.clear();
+        self.last_track = None;
+        self.turned = 0.0;
+        self.circle_start = 0.0;
+        self.circles = 0;
+    }
+
+    fn estimate(&mut self) -> Option<WindEstimate> {
+        let count = self.samples.len();
+        if count < MIN_SAMPLES {
+            self.circles = 0;
+            return None;
+        }
+        let mean = |f: &dyn Fn(&[Float; 2]) -> Float| {
+            self.samples.iter().map(f).sum::<Float>() / count as Float
+        };
+        let wind = [mean(&|s| s[0]), mean(&|s| s[1])];
+        let airspeed =
+            |s: &[Float; 2]| ((s[0] - wind[0]).powi(2) + (s[1] - wind[1]).powi(2)).sqrt();
+        let mean_airspeed = mean(&airspeed);
+        let spread = mean(&|s| (airspeed(s) - mean_airspeed).powi(2)).sqrt() / mean_airspeed;
+        if mean_airspeed < MIN_AIRSPEED || spread > SPREAD_REJECTED {
+            self.circles = 0;
+            return None;
+        }
+
+        self.circles = (self.circles + 1).min(MAX_WIND_QUALITY);
+        let quality = match spread > SPREAD_REDUCED {
+            true => (self.circles - 1).max(1),
+            false => self.circles,
+        };
+        let average = match self.average {
+            Some(average) => {
+                let weight = quality as Float / (2.0 * MAX_WIND_QUALITY as Float);
+                [
+                    average[0] + (wind[0] - average[0]) * weight,
+                    average[1] + (wind[1] - average[1]) * weight,
+                ]
+            }
+            None => wind,
+        };
+        self.average = Some(average);
+        Some(WindEstimate {
+            wind: wind_vector(&wind),
+            quality,
+        })
+    }
+}
+
+// The wind angle is the direction from which the wind blows
+fn wind_vector(velocity: &[Float; 2]) -> WindVector {
+    let speed = (velocity[0] * velocity[0] + velocity[1] * velocity[1]).sqrt();
+    let angle = velocity[1].atan2(velocity[0]) + PI;
+    WindVector::new(speed.m_s(), Angle::from_radians(angle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    // GNSS samples at 1 Hz of circles at an airspeed in m/s, turning with a period in s, with
+    // the wind from the direction in degrees
+    fn circling(
+        wind: &mut CirclingWind,
+        airspeed: impl Fn(usize) -> Float,
+        period: Float,
+        wind_speed: Float,
+        wind_from: Float,
+        seconds: usize,
+    ) -> std::vec::Vec<WindEstimate> {
+        let wind_to = (wind_from + 180.0).to_radians();
+        let drift = [wind_speed * wind_to.cos(), wind_speed * wind_to.sin()];
+        let mut estimates = std::vec::Vec::new();
+        for t in 0..seconds {
+            let heading = 2.0 * PI * t as Float / period;
+            let north = airspeed(t) * heading.cos() + drift[0];
+            let east = airspeed(t) * heading.sin() + drift[1];
+            let track = Angle::from_radians(east.atan2(north));
+            let ground_speed = (north * north + east * east).sqrt().m_s();
+            if let Some(estimate) = wind.update(true, track, ground_speed) {
+                estimates.push(estimate);
+            }
+        }
+        estimates
+    }
+
+    #[test]
+    fn test_constant_wind() {
+        let mut wind = CirclingWind::default();
+        let estimates = circling(&mut wind, |_| 25.0, 25.0, 5.0, 270.0, 200);
+        assert_eq!(estimates.len(), 7);
+        for estimate in estimates.iter() {
+            assert_float_eq!(estimate.wind.speed().to_m_s(), 5.0);
+            assert_float_eq!(estimate.wind.angle().to_degrees(), 270.0);
+        }
+        // the quality grows with the number of circles
+        assert_eq!(estimates[0].quality, 1);
+        assert_eq!(estimates[6].quality, MAX_WIND_QUALITY);
+        assert_float_eq!(wind.average().unwrap().speed().to_m_s(), 5.0);
+
+        // the drift does not depend on the direction of the turn
+        let mut wind = CirclingWind::default();
+        let estimates = circling(&mut wind, |_| 25.0, -20.0, 8.0, 45.0, 100);
+        assert_float_eq!(estimates[0].wind.speed().to_m_s(), 8.0);
+        assert_float_eq!(estimates[0].wind.angle().to_degrees(), 45.0);
+    }
+
+    #[test]
+    fn test_poorly_flown_circles() {
+        // a varying airspeed reduces the quality
+        let mut wind = CirclingWind::default();
+        let varying = |t: usize| 25.0 + 4.0 * (t as Float * 0.7).sin();
+        let estimates = circling(&mut wind, varying, 25.0, 5.0, 180.0, 200);
+        assert_eq!(estimates.last().unwrap().quality, MAX_WIND_QUALITY - 1);
+        assert!((estimates.last().unwrap().wind.speed().to_m_s() - 5.0).abs() < 1.0);
+
+        // far too irregular circles give no wind at all
+        let mut wind = CirclingWind::default();
+        let irregular = |t: usize| 25.0 + 10.0 * (t as Float * 0.7).sin();
+        assert!(circling(&mut wind, irregular, 25.0, 5.0, 180.0, 200).is_empty());
+        assert!(wind.average().is_none());
+    }
+
+    #[test]
+    fn test_interrupted_circling() {
+        let mut wind = CirclingWind::default();
+        // half a circle is not enough
+        assert!(circling(&mut wind, |_| 25.0, 25.0, 5.0, 90.0, 13).is_empty());
+        assert!(wind
+            .update(false, Angle::from_degrees(0.0), 20.0.m_s())
+            .is_none());
+        // after straight flight, the quality starts again
+        let estimates = circling(&mut wind, |_| 25.0, 25.0, 5.0, 90.0, 60);
+        assert_eq!(estimates.len(), 2);
+        assert_eq!(estimates[0].quality, 1);
+        assert_float_eq!(estimates[0].wind.angle().to_degrees(), 90.0);
+    }
+}
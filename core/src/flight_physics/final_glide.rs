@@ -0,0 +1,190 @@
+use crate::{
+    flight_physics::{AirSpeed, Polar, WindVector},
+    system_of_units::{into_range_0_360, Angle, Float, FloatToLength, Length, Speed},
+    Latitude, Longitude,
+};
+
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
+/// Mean earth radius in m
+const EARTH_RADIUS: Float = 6_371_000.0;
+
+/// Speed steps in m/s used to search the optimal speed to fly
+const SPEED_STEP: Float = 0.5;
+
+/// Result of the final glide calculation
+#[derive(Clone, Copy)]
+pub struct FinalGlide {
+    /// Distance to the destination
+    pub distance: Length,
+    /// True course to the destination
+    pub course: Angle,
+    /// Optimal speed to fly for the glide
+    pub speed: AirSpeed,
+    /// Altitude necessary to reach the destination with the safety altitude
+    pub required_altitude: Length,
+    /// Height above the safety altitude at the destination, negative if it is not reachable
+    pub arrival_height: Length,
+}
+
+/// Distance and true course between two positions
+///
+/// A flat earth approximation is used, which is precise enough for the distances of a glide.
+pub fn distance_and_course(
+    from_lat: Latitude,
+    from_lon: Longitude,
+    to_lat: Latitude,
+    to_lon: Longitude,
+) -> (Length, Angle) {
+    let d_lat = (to_lat.0.to_rad() - from_lat.0.to_rad()) as Float;
+    let mean_lat = ((to_lat.0.to_rad() + from_lat.0.to_rad()) / 2.0) as Float;
+    let d_lon = (to_lon.0.to_rad() - from_lon.0.to_rad()) as Float;
+    let north = d_lat * EARTH_RADIUS;
+    let east = d_lon * mean_lat.cos() * EARTH_RADIUS;
+    let distance = (north * north + east * east).sqrt();
+    let course = into_range_0_360(Angle::from_radians(east.atan2(north)));
+    (distance.m(), course)
+}
+
+impl FinalGlide {
+    /// Calculate the final glide to a destination
+    ///
+    /// The speed to fly is the one with the shortest overall time, if the expected climb is
+    /// mc_cready. At a McCready value of 0 this is the speed of the best glide over ground. The
+    /// wind angle is the direction from which the wind blows. min_altitude is the elevation of
+    /// the destination plus the safety altitude. Returns None, if the destination cannot be
+    /// reached at any speed against the wind.
+    pub fn calc(
+        polar: &Polar,
+        mc_cready: Speed,
+        wind: WindVector,
+        distance: Length,
+        course: Angle,
+        altitude: Length,
+        min_altitude: Length,
+    ) -> Option<Self> {
+        let wind_angle = (wind.angle() - course).to_radians();
+        let head_wind = wind.speed().to_m_s() * wind_angle.cos();
+        let cross_wind = wind.speed().to_m_s() * wind_angle.sin();
+        let mc_cready = mc_cready.to_m_s().max(0.0);
+
+        // search the speed with the minimal time
+        let mut best: Option<(Float, Float, Float)> = None; // (tas, sink, ground speed)
+        let mut tas = polar.v_min().tas().to_m_s();
+        let v_max = polar.v_max().tas().to_m_s();
+        while tas <= v_max {
+            let ground_speed = (tas * tas - cross_wind * cross_wind).max(0.0).sqrt() - head_wind;
+            if ground_speed > 0.0 {
+                let sink = -polar.sink_rate(AirSpeed::new(tas, tas)).to_m_s();
+                let cost = (sink + mc_cready) / ground_speed;
+                match best {
+                    Some((_, b_sink, b_gs)) if (b_sink + mc_cready) / b_gs <= cost => (),
+                    _ => best = Some((tas, sink, ground_speed)),
+                }
+            }
+            tas += SPEED_STEP;
+        }
+
+        let (tas, sink, ground_speed) = best?;
+        let height_loss = (distance.to_m() * sink / ground_speed).m();
+        Some(FinalGlide {
+            distance,
+            course,
+            speed: polar.airspeed_from_tas(tas),
+            required_altitude: min_altitude + height_loss,
+            arrival_height: altitude - min_altitude - height_loss,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        flight_physics::polar_store::BasicGliderData, AngleUnit, Density, F64ToCoord, FloatToSpeed,
+        GliderData,
+    };
+
+    const LS3_GLIDER_DATA: BasicGliderData = BasicGliderData {
+        name: "LS-3 WL",
+        wing_area: 10.5,
+        max_speed: 270.0,
+        empty_mass: 280.0,
+        max_ballast: 121.0,
+        reference_weight: 396.0,
+        handicap: 107,
+        polar_values: [[80.0, -0.604], [105.0, -0.700], [180.0, -1.939]],
+        flaps: None,
+        limits: None,
+        curve: None,
+    };
+
+    fn polar() -> Polar {
+        let glider_data = GliderData {
+            basic_glider_data: LS3_GLIDER_DATA,
+            ..Default::default()
+        };
+        let mut polar = Polar::default();
+        polar.recalc_glider(&glider_data);
+        polar.recalc(&glider_data, Density::AT_NN());
+        polar
+    }
+
+    #[test]
+    fn test_distance_and_course() {
+        let (distance, course) = distance_and_course(
+            Latitude(50.0_f64.deg()),
+            Longitude(8.0_f64.deg()),
+            Latitude(50.5_f64.deg()),
+            Longitude(8.0_f64.deg()),
+        );
+        assert!((distance.to_m() - 55_597.0).abs() < 10.0);
+        assert!(course.to_degrees().abs() < 0.1);
+
+        let (distance, course) = distance_and_course(
+            Latitude(50.0_f64.deg()),
+            Longitude(8.0_f64.deg()),
+            Latitude(50.0_f64.deg()),
+            Longitude(7.0_f64.deg()),
+        );
+        assert!((distance.to_m() - 71_474.0).abs() < 10.0);
+        assert!((course.to_degrees() - 270.0).abs() < 0.1);
+    }
+
+    fn glide(mc_cready: f32, wind_speed: f32, wind_angle: f32) -> Option<FinalGlide> {
+        let wind = WindVector::new(wind_speed.km_h(), wind_angle.deg());
+        FinalGlide::calc(
+            &polar(),
+            mc_cready.m_s(),
+            wind,
+            41_680.0.m(),
+            0.0_f32.deg(),
+            1500.0.m(),
+            300.0.m(),
+        )
+    }
+
+    #[test]
+    fn test_final_glide() {
+        // no wind, MC 0 => best glide ratio of about 1:41.7
+        let fg = glide(0.0, 0.0, 0.0).unwrap();
+        assert!((fg.arrival_height.to_m() - 200.0).abs() < 5.0);
+        assert!((fg.required_altitude.to_m() - 1300.0).abs() < 5.0);
+
+        // head wind costs height, tail wind saves height
+        let fg_head = glide(0.0, 20.0, 0.0).unwrap();
+        let fg_tail = glide(0.0, 20.0, 180.0).unwrap();
+        assert!(fg_head.arrival_height.to_m() < fg.arrival_height.to_m());
+        assert!(fg_tail.arrival_height.to_m() > fg.arrival_height.to_m());
+        assert!(fg_head.speed.tas().to_m_s() > fg.speed.tas().to_m_s());
+
+        // a higher McCready value means faster and with a higher loss of height
+        let fg_mc = glide(2.0, 0.0, 0.0).unwrap();
+        assert!(fg_mc.speed.tas().to_m_s() > fg.speed.tas().to_m_s());
+        assert!(fg_mc.arrival_height.to_m() < fg.arrival_height.to_m());
+
+        // a storm, that is faster than the glider
+        assert!(glide(0.0, 300.0, 0.0).is_none());
+    }
+}
@@ -0,0 +1,156 @@
+use crate::system_of_units::{FloatToSpeed, Speed};
+
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
+/// Maximum number of flap settings with an own polar
+pub const MAX_FLAP_POLARS: usize = 5;
+
+/// Speed bands and polars of the flap settings of a flapped glider
+///
+/// The settings are ordered from slow to fast. The upper limits of the speed bands (IAS) apply
+/// to the reference wing loading, for other wing loadings they are scaled with the square root
+/// of the ratio. The values are approximations, the placard in the cockpit is authoritative.
+///
+/// Each setting has a polar of three points (km/h, m/s) at the reference weight of the glider,
+/// like the polar of the polar store. The points are approximated from the polar of the glider,
+/// which is the envelope of the flap polars.
+#[derive(Clone, Copy)]
+pub struct FlapTable {
+    pub labels: &'static [&'static str],
+    pub wing_loading: f32,      // kg/m²
+    pub limits: &'static [f32], // km/h, upper limit of each setting except the fastest
+    pub polars: &'static [[[f32; 2]; 3]],
+}
+
+impl FlapTable {
+    pub fn count(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn label(&self, setting: u8) -> &'static str {
+        self.labels.get(setting as usize).copied().unwrap_or("?")
+    }
+
+    /// Flap setting at the position of the flap sensor in percent
+    ///
+    /// The sensor is expected to be calibrated so that 0% is the slowest and 100% is the
+    /// fastest setting.
+    pub fn setting_from_position(&self, position: f32) -> u8 {
+        let max = (self.count() - 1) as f32;
+        (position.clamp(0.0, 100.0) * max / 100.0).round() as u8
+    }
+
+    /// Optimal flap setting for the indicated airspeed at the given wing loading in kg/m²
+    pub fn optimal_setting(&self, ias: Speed, wing_loading: f32) -> u8 {
+        let ratio = (wing_loading / self.wing_loading).sqrt();
+        self.limits
+            .iter()
+            .position(|limit| ias < (limit * ratio).km_h())
+            .unwrap_or(self.limits.len()) as u8
+    }
+}
+
+const SETTINGS_5: &[&str] = &["+2", "+1", "0", "-1", "-2"];
+
+pub const ASG29_15M: FlapTable = FlapTable {
+    labels: SETTINGS_5,
+    wing_loading: 40.0,
+    limits: &[95.0, 110.0, 140.0, 175.0],
+    polars: &[
+        [[65.0, -0.665], [85.0, -0.583], [105.0, -0.629]],
+        [[82.5, -0.598], [102.5, -0.607], [122.5, -0.744]],
+        [[105.0, -0.629], [125.0, -0.755], [145.0, -1.009]],
+        [[137.5, -0.906], [157.5, -1.202], [177.5, -1.625]],
+        [[180.0, -1.684], [200.0, -2.201], [220.0, -2.847]],
+    ],
+};
+
+pub const ASG29_18M: FlapTable = FlapTable {
+    labels: SETTINGS_5,
+    wing_loading: 36.0,
+    limits: &[85.0, 100.0, 125.0, 160.0],
+    polars: &[
+        [[55.0, -0.569], [75.0, -0.471], [95.0, -0.509]],
+        [[72.5, -0.488], [92.5, -0.488], [112.5, -0.624]],
+        [[92.5, -0.500], [112.5, -0.612], [132.5, -0.860]],
+        [[122.5, -0.728], [142.5, -1.008], [162.5, -1.424]],
+        [[165.0, -1.482], [185.0, -2.000], [205.0, -2.654]],
+    ],
+};
+
+pub const DG800_15M: FlapTable = FlapTable {
+    labels: SETTINGS_5,
+    wing_loading: 35.0,
+    limits: &[90.0, 105.0, 135.0, 170.0],
+    polars: &[
+        [[60.0, -0.536], [80.0, -0.542], [100.0, -0.642]],
+        [[77.5, -0.548], [97.5, -0.615], [117.5, -0.775]],
+        [[100.0, -0.642], [120.0, -0.787], [140.0, -1.025]],
+        [[132.5, -0.932], [152.5, -1.189], [172.5, -1.539]],
+        [[175.0, -1.586], [195.0, -1.990], [215.0, -2.487]],
+    ],
+};
+
+pub const DG800_18M: FlapTable = FlapTable {
+    labels: SETTINGS_5,
+    wing_loading: 32.0,
+    limits: &[80.0, 95.0, 120.0, 155.0],
+    polars: &[
+        [[50.0, -0.711], [70.0, -0.576], [90.0, -0.562]],
+        [[67.5, -0.598], [87.5, -0.548], [107.5, -0.620]],
+        [[87.5, -0.560], [107.5, -0.608], [127.5, -0.777]],
+        [[117.5, -0.686], [137.5, -0.881], [157.5, -1.197]],
+        [[160.0, -1.242], [180.0, -1.644], [200.0, -2.168]],
+    ],
+};
+
+pub const VENTUS2_15M: FlapTable = FlapTable {
+    labels: SETTINGS_5,
+    wing_loading: 38.0,
+    limits: &[90.0, 105.0, 135.0, 170.0],
+    polars: &[
+        [[60.0, -0.676], [80.0, -0.581], [100.0, -0.612]],
+        [[77.5, -0.598], [97.5, -0.592], [117.5, -0.713]],
+        [[100.0, -0.612], [120.0, -0.722], [140.0, -0.957]],
+        [[132.5, -0.861], [152.5, -1.137], [172.5, -1.539]],
+        [[175.0, -1.595], [195.0, -2.088], [215.0, -2.707]],
+    ],
+};
+
+pub const VENTUS2_18M: FlapTable = FlapTable {
+    labels: SETTINGS_5,
+    wing_loading: 35.0,
+    limits: &[80.0, 95.0, 125.0, 160.0],
+    polars: &[
+        [[50.0, -0.663], [70.0, -0.520], [90.0, -0.523]],
+        [[67.5, -0.541], [87.5, -0.506], [107.5, -0.617]],
+        [[90.0, -0.523], [110.0, -0.626], [130.0, -0.877]],
+        [[122.5, -0.773], [142.5, -1.076], [162.5, -1.527]],
+        [[165.0, -1.590], [185.0, -2.156], [205.0, -2.869]],
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_setting_from_position() {
+        assert_eq!(ASG29_18M.setting_from_position(0.0), 0);
+        assert_eq!(ASG29_18M.setting_from_position(24.0), 1);
+        assert_eq!(ASG29_18M.setting_from_position(50.0), 2);
+        assert_eq!(ASG29_18M.setting_from_position(100.0), 4);
+        assert_eq!(ASG29_18M.setting_from_position(120.0), 4);
+        assert_eq!(ASG29_18M.label(2), "0");
+    }
+
+    #[test]
+    fn test_optimal_setting() {
+        assert_eq!(ASG29_18M.optimal_setting(80.0.km_h(), 36.0), 0);
+        assert_eq!(ASG29_18M.optimal_setting(110.0.km_h(), 36.0), 2);
+        assert_eq!(ASG29_18M.optimal_setting(200.0.km_h(), 36.0), 4);
+        // with water ballast the speed bands move to higher speeds
+        assert_eq!(ASG29_18M.optimal_setting(110.0.km_h(), 50.0), 1);
+    }
+}
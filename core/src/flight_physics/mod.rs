@@ -1,12 +1,22 @@
 mod airspeed;
 mod athmodphere;
+mod circling_wind;
+mod final_glide;
+mod flaps;
 mod polar;
+mod polar_curve;
 pub(crate) mod polar_store;
 #[rustfmt::skip]
 pub(crate) mod polar_store_idx;
+mod speed_limits;
 mod wind_vector;
 
 pub use airspeed::*;
 pub use athmodphere::*;
+pub use circling_wind::{CirclingWind, WindEstimate, MAX_WIND_QUALITY};
+pub use final_glide::*;
+pub use flaps::{FlapTable, MAX_FLAP_POLARS};
 pub use polar::{GliderData, Polar};
+pub use polar_curve::PolarCurve;
+pub use speed_limits::SpeedLimits;
 pub use wind_vector::*;
@@ -1,24 +1,42 @@
-use crate::flight_physics::{polar_store::BasicGliderData, AirSpeed};
+use heapless::Vec;
+
+use crate::flight_physics::{
+    flaps::MAX_FLAP_POLARS,
+    polar_curve::{fit, Koefs},
+    polar_store::BasicGliderData,
+    AirSpeed,
+};
 use crate::system_of_units::{Density, Float, FloatToMass, Mass, Speed};
 
 #[allow(unused_imports)]
 use micromath::F32Ext;
 
-#[derive(Clone, Debug)]
+/// Ratio of the stall speed to the minimum speed of the polar
+///
+/// The vertex of the polar is the speed for minimal sink. Gliders stall at about 85% of this
+/// speed.
+const STALL_SPEED_RATIO: Float = 0.85;
+
+/// Bisection steps to solve the polar for a speed, the result is exact to about 1e-5 m/s
+const SOLVE_STEPS: usize = 24;
+
+#[derive(Clone, Debug, Default)]
 pub struct PolarKoefs {
-    pub a: Float,
-    pub b: Float,
-    pub c: Float,
+    pub koefs: Koefs,
     pub v_min: Float,
     pub weight: Float,
 }
 
+/// Three points (km/h, m/s) of the polar of a flap setting
+pub type FlapPolar = [[f32; 2]; 3];
+
 #[derive(Clone, Copy)]
 pub struct GliderData {
     pub pilot_weight: Mass,
     pub water_ballast: Mass,
     pub bugs: f32,
     pub basic_glider_data: BasicGliderData,
+    pub flap_polars: [FlapPolar; MAX_FLAP_POLARS],
 }
 
 impl Default for GliderData {
@@ -28,11 +46,34 @@ impl Default for GliderData {
             water_ballast: 0.0.kg(),
             bugs: 1.0,
             basic_glider_data: BasicGliderData::default(),
+            flap_polars: Default::default(),
         }
     }
 }
 
 impl GliderData {
+    /// Selects a glider of the polar store, the flap polars are taken from its flap table
+    pub fn select_glider(&mut self, basic_glider_data: BasicGliderData) {
+        self.basic_glider_data = basic_glider_data;
+        self.flap_polars = Default::default();
+        if let Some(flaps) = basic_glider_data.flaps {
+            for (polar, table) in self.flap_polars.iter_mut().zip(flaps.polars) {
+                *polar = *table;
+            }
+        }
+    }
+
+    /// Number of flap settings with an own polar
+    ///
+    /// The flap polars are taken from the flap table or entered by the pilot for any glider. A
+    /// flap polar is used, when the speeds of all its points are set.
+    pub fn flap_polar_count(&self) -> usize {
+        self.flap_polars
+            .iter()
+            .take_while(|polar| polar.iter().all(|point| point[0] > 0.0))
+            .count()
+    }
+
     pub fn ballast_fraction(&self) -> f32 {
         self.water_ballast.to_kg() / self.basic_glider_data.max_ballast
     }
@@ -40,41 +81,79 @@ impl GliderData {
     pub fn set_ballast_fraction(&mut self, fraction: f32) {
         self.water_ballast = (fraction * self.basic_glider_data.max_ballast).kg();
     }
+
+    /// Ratio of the current mass to the mass without water ballast
+    pub fn overload(&self) -> f32 {
+        let dry_mass = self.basic_glider_data.empty_mass + self.pilot_weight.to_kg();
+        (dry_mass + self.water_ballast.to_kg()) / dry_mass
+    }
+}
+
+// Polar at the reference weight and adopted to the current weight and density
+#[derive(Clone, Default)]
+struct Curve {
+    refer: PolarKoefs, // reference coefs
+    curr: PolarKoefs,  // current koefs
+}
+
+impl Curve {
+    fn new(koefs: Koefs, weight: Float, max_speed: Float) -> Self {
+        // the speed for minimal sink is the maximum of the polar
+        let v_min = solve(5.0, max_speed, |v| -derivative(&koefs, v));
+        let refer = PolarKoefs {
+            koefs,
+            v_min,
+            weight,
+        };
+        Curve {
+            curr: refer.clone(),
+            refer,
+        }
+    }
+
+    // The polar w(v) is scaled to ratio * w(v / ratio), so the coefficient of v^i is scaled by
+    // ratio^(1 - i).
+    fn recalc(&mut self, ratio: Float, bugs: Float, weight: Float) {
+        self.curr.koefs = scaled(&self.refer.koefs, ratio);
+        for koef in self.curr.koefs.iter_mut() {
+            *koef *= bugs;
+        }
+        self.curr.v_min = self.refer.v_min * ratio;
+        self.curr.weight = weight;
+    }
 }
+
 pub struct Polar {
-    max_speed: Float,     // m/s
-    density_ratio: Float, // -
-    curr: PolarKoefs,     // current koefs
-    refer: PolarKoefs,    // reference coefs
+    max_speed: Float,                   // m/s
+    density_ratio: Float,               // -
+    glider: Curve,                      // polar of the glider
+    flaps: Vec<Curve, MAX_FLAP_POLARS>, // polars of the flap settings
+    flap: Option<usize>,                // active flap setting
 }
 
 impl Default for Polar {
     fn default() -> Self {
         Self {
-            curr: PolarKoefs {
-                a: 0.0,
-                b: 0.0,
-                c: 0.0,
-                v_min: 0.0,
-                weight: 0.0,
-            },
-            refer: PolarKoefs {
-                a: 0.0,
-                b: 0.0,
-                c: 0.0,
-                v_min: 0.0,
-                weight: 0.0,
-            },
             max_speed: 0.0,
             density_ratio: 1.0,
+            glider: Curve::default(),
+            flaps: Vec::new(),
+            flap: None,
         }
     }
 }
 
-/// Simple model of the polar curve for a glider
+/// Model of the polar curve for a glider
 ///
-/// The polar of the glider is mapped with a simple model, a quadratic approximation. The
-/// coefficients are calculated from 3 points of the polar curve.
+/// The polar of the glider is mapped with a polynomial. Usually this is a quadratic
+/// approximation, whose coefficients are calculated from 3 points of the polar curve. Gliders
+/// with a [PolarCurve](super::PolarCurve) use a polynomial of higher order.
+///
+/// Flapped gliders have an additional polar for each flap setting. The polar of the active flap
+/// setting is used for the sink rate, the minimum sink and the gliding ratio. The speed to fly is taken from the
+/// envelope of the flap polars, as the pilot changes the flaps with the speed. The minimum
+/// speed is a property of the glider and is always taken from the polar of the glider. The
+/// flap polars are kept, when the pilot enters the points of the glider polar.
 ///
 /// If the pilot weight, water ballast, empty weight, pollution of the glider with mosquitoes
 /// or air density changes, the polar curve is recalculated. Airspeeds are output as type
@@ -83,27 +162,22 @@ impl Polar {
     /// calc polar coefficients
     pub fn recalc_glider(&mut self, glider_data: &GliderData) {
         let bgd = &glider_data.basic_glider_data;
-        let (v1, w1) = (bgd.polar_values[0][0] / 3.6, bgd.polar_values[0][1]);
-        let (v2, w2) = (bgd.polar_values[1][0] / 3.6, bgd.polar_values[1][1]);
-        let (v3, w3) = (bgd.polar_values[2][0] / 3.6, bgd.polar_values[2][1]);
-
-        let a = ((v2 - v3) * (w1 - w3) + (v3 - v1) * (w2 - w3))
-            / (v1 * v1 * (v2 - v3) + v2 * v2 * (v3 - v1) + v3 * v3 * (v1 - v2));
-        let b = (w2 - w3 - a * (v2 * v2 - v3 * v3)) / (v2 - v3);
-        let c = w3 - a * v3 * v3 - b * v3;
-
-        let v_min = -b / a / 2.0;
-        let weight = bgd.reference_weight;
-
-        self.curr = PolarKoefs {
-            a,
-            b,
-            c,
-            v_min,
-            weight,
+        let koefs = match bgd.curve {
+            Some(curve) => curve.koefs(),
+            None => fit(&bgd.polar_values, 2),
         };
-        self.refer = self.curr.clone();
         self.max_speed = bgd.max_speed / 3.6;
+        self.glider = Curve::new(koefs, bgd.reference_weight, self.max_speed);
+        self.flaps.clear();
+        for polar in glider_data
+            .flap_polars
+            .iter()
+            .take(glider_data.flap_polar_count())
+        {
+            let curve = Curve::new(fit(polar, 2), bgd.reference_weight, self.max_speed);
+            let _ = self.flaps.push(curve);
+        }
+        self.flap = self.flap.filter(|flap| *flap < self.flaps.len());
         self.density_ratio = 1.0;
     }
 
@@ -113,16 +187,26 @@ impl Polar {
             + glider_data.pilot_weight
             + glider_data.water_ballast)
             .to_kg();
-        let ratio_weight = (weight / self.refer.weight).sqrt();
+        let ratio_weight = (weight / self.glider.refer.weight).sqrt();
         self.density_ratio = (Density::AT_NN().0 / density.to_kg_m3()).sqrt();
         let ratio = ratio_weight * self.density_ratio;
 
-        self.curr.a = glider_data.bugs * self.refer.a / ratio;
-        self.curr.b = glider_data.bugs * self.refer.b;
-        self.curr.c = glider_data.bugs * self.refer.c * ratio;
+        self.glider.recalc(ratio, glider_data.bugs, weight);
+        for curve in self.flaps.iter_mut() {
+            curve.recalc(ratio, glider_data.bugs, weight);
+        }
+    }
+
+    /// Activates the polar of a flap setting, None or an unknown setting use the glider polar
+    pub fn set_flap(&mut self, setting: Option<u8>) {
+        self.flap = setting
+            .map(|setting| setting as usize)
+            .filter(|flap| *flap < self.flaps.len());
+    }
 
-        self.curr.v_min = self.refer.v_min * ratio;
-        self.curr.weight = weight;
+    /// Returns the active flap setting, if its polar is used
+    pub fn flap(&self) -> Option<u8> {
+        self.flap.map(|flap| flap as u8)
     }
 
     /// Returns the sink rate of the glider
@@ -130,57 +214,158 @@ impl Polar {
     /// It is checked that the speed is within the permissible range.
     pub fn sink_rate(&self, speed: AirSpeed) -> Speed {
         let v = self.clamp_speed(speed.tas().to_m_s());
-        let sink_rate = v * v * self.curr.a + v * self.curr.b + self.curr.c;
-        Speed(sink_rate)
+        Speed(polynomial(&self.curr().koefs, v))
     }
 
-    /// Returns the speed for minimal sink
+    /// Returns the sink rate of the glider at a load factor n
+    ///
+    /// A load factor has the same effect as a higher weight, so the polar is scaled by sqrt(n)
+    /// in the same way as in recalc().
+    pub fn sink_rate_at_load(&self, speed: AirSpeed, load_factor: Float) -> Speed {
+        let ratio = load_factor.clamp(0.2, 5.0).sqrt();
+        let v = self.clamp_speed(speed.tas().to_m_s());
+        Speed(polynomial(&scaled(&self.curr().koefs, ratio), v))
+    }
+
+    /// Returns the minimum sink rate of the active polar
+    pub fn min_sink_rate(&self) -> Speed {
+        self.sink_rate(self.min_sink_speed())
+    }
+
+    /// Returns the speed for minimal sink of the active polar
     pub fn min_sink_speed(&self) -> AirSpeed {
-        let v = self.clamp_speed(-self.curr.b / self.curr.a / 2.0);
+        let v = self.clamp_speed(self.curr().v_min);
         self.airspeed_from_tas(v)
     }
 
     /// Returns the speed to fly, which is a function of the expected climb and the metereological
     /// sink.
+    ///
+    /// The speed to fly is the speed at which the tangent from (0, mc_cready - si_met) touches
+    /// the polar, so w(v) + met - mc_cready - v * w'(v) = 0. With flap polars, the tangent
+    /// touches the envelope, this is the flap polar with the flattest tangent.
     pub fn speed_to_fly(&self, si_met: Speed, st_mc_cready: Speed) -> AirSpeed {
-        let (met, mc_cready) = (si_met.to_m_s(), st_mc_cready.to_m_s());
-        let val = (self.curr.c + met - mc_cready) / self.curr.a;
-        let stf = if val > 0.0 { val.sqrt() } else { 0.0 };
-        let stf = self.clamp_speed(stf);
+        let offset = si_met.to_m_s() - st_mc_cready.to_m_s();
+        let stf = if self.flaps.is_empty() {
+            self.tangent_speed(&self.glider.curr.koefs, offset)
+        } else {
+            self.flaps
+                .iter()
+                .map(|curve| {
+                    let v = self.tangent_speed(&curve.curr.koefs, offset);
+                    (v, (polynomial(&curve.curr.koefs, v) + offset) / v)
+                })
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map_or(self.glider.curr.v_min, |(v, _)| v)
+        };
         self.airspeed_from_tas(stf)
     }
 
     /// Returns the current possible minimum speed
     pub fn v_min(&self) -> AirSpeed {
-        self.airspeed_from_tas(self.curr.v_min)
+        self.airspeed_from_tas(self.glider.curr.v_min)
+    }
+
+    /// Returns the stall speed at a load factor n
+    ///
+    /// The minimum speed is already scaled by the weight, a load factor scales it by sqrt(n).
+    pub fn stall_speed(&self, load_factor: Float) -> AirSpeed {
+        let ratio = load_factor.clamp(1.0, 5.0).sqrt();
+        self.airspeed_from_tas(self.glider.curr.v_min * STALL_SPEED_RATIO * ratio)
+    }
+
+    /// Returns the maximum permitted speed
+    pub fn v_max(&self) -> AirSpeed {
+        self.airspeed_from_tas(self.max_speed)
     }
 
     /// Returns the gliding ratio
     pub fn gliding_ratio(&self, speed: AirSpeed) -> Float {
         let v = self.clamp_speed(speed.tas().to_m_s());
-        let sink_rate = v * v * self.curr.a + v * self.curr.b + self.curr.c;
-        -v / sink_rate
+        -v / polynomial(&self.curr().koefs, v)
+    }
+
+    // Polar of the active flap setting or of the glider
+    fn curr(&self) -> &PolarKoefs {
+        match self.flap.and_then(|flap| self.flaps.get(flap)) {
+            Some(curve) => &curve.curr,
+            None => &self.glider.curr,
+        }
+    }
+
+    // Speed at which the tangent with the offset touches the polar
+    fn tangent_speed(&self, koefs: &Koefs, offset: Float) -> Float {
+        let v = solve(self.glider.curr.v_min, self.max_speed, |v| {
+            polynomial(koefs, v) + offset - v * derivative(koefs, v)
+        });
+        self.clamp_speed(v)
     }
 
     fn clamp_speed(&self, speed: Float) -> Float {
+        let v_min = self.glider.curr.v_min;
         match speed {
             v if v > self.max_speed => self.max_speed,
-            v if v < self.curr.v_min => self.curr.v_min,
-            v if v.is_nan() => self.curr.v_min,
+            v if v < v_min => v_min,
+            v if v.is_nan() => v_min,
             _ => speed,
         }
     }
 
-    fn airspeed_from_tas(&self, tas: Float) -> AirSpeed {
+    pub(crate) fn airspeed_from_tas(&self, tas: Float) -> AirSpeed {
         let ias = tas / self.density_ratio;
         AirSpeed::new(ias, tas)
     }
 }
 
+// Find the zero of a function rising from min to max, which is clamped to the range
+fn solve<F: Fn(Float) -> Float>(min: Float, max: Float, f: F) -> Float {
+    if min >= max || f(min) >= 0.0 {
+        return min;
+    }
+    if f(max) <= 0.0 {
+        return max;
+    }
+    let (mut low, mut high) = (min, max);
+    for _ in 0..SOLVE_STEPS {
+        let mid = (low + high) / 2.0;
+        if f(mid) < 0.0 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    (low + high) / 2.0
+}
+
+fn polynomial(koefs: &Koefs, v: Float) -> Float {
+    koefs.iter().rev().fold(0.0, |sum, koef| sum * v + koef)
+}
+
+fn derivative(koefs: &Koefs, v: Float) -> Float {
+    koefs
+        .iter()
+        .enumerate()
+        .skip(1)
+        .rev()
+        .fold(0.0, |sum, (i, koef)| sum * v + i as Float * koef)
+}
+
+// Coefficients of the polar ratio * w(v / ratio)
+fn scaled(koefs: &Koefs, ratio: Float) -> Koefs {
+    let mut scaled = *koefs;
+    let mut factor = ratio;
+    for koef in scaled.iter_mut() {
+        *koef *= factor;
+        factor /= ratio;
+    }
+    scaled
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::assert_float_eq;
+    use crate::flight_physics::{polar_store, PolarCurve};
     use crate::{FloatToDensity, FloatToMass, FloatToSpeed};
     use std::{fs::File, io::*, vec::Vec};
 
@@ -194,6 +379,9 @@ mod tests {
         reference_weight: 396.0,
         handicap: 107,
         polar_values: [[80.0, -0.604], [105.0, -0.700], [180.0, -1.939]],
+        flaps: None,
+        limits: None,
+        curve: None,
     };
 
     const AS33_GLIDER_DATA: BasicGliderData = BasicGliderData {
@@ -206,6 +394,9 @@ mod tests {
         reference_weight: 400.0,
         handicap: 122,
         polar_values: [[97.2, -0.511], [111.6, -0.556], [180.0, -1.369]],
+        flaps: None,
+        limits: None,
+        curve: None,
     };
 
     fn write_stf_to_csv(file_name: &str, polar: &mut Polar) {
@@ -393,4 +584,223 @@ mod tests {
             100.2
         );
     }
+
+    #[test]
+    fn test_load_factor() {
+        let mut glider_data = GliderData::default();
+        glider_data.basic_glider_data = LS3_GLIDER_DATA;
+        let mut polar = Polar::default();
+        polar.recalc_glider(&glider_data);
+        polar.recalc(&glider_data, Density::AT_NN());
+
+        let speed = AirSpeed::from_tas_at_nn(90.0.km_h());
+        assert_float_eq!(
+            polar.sink_rate_at_load(speed, 1.0).to_m_s(),
+            polar.sink_rate(speed).to_m_s()
+        );
+        assert_float_eq!(polar.sink_rate_at_load(speed, 2.0).to_m_s(), -0.847);
+        assert_float_eq!(polar.min_sink_rate().to_m_s(), -0.583);
+    }
+
+    #[test]
+    fn test_stall_speed() {
+        let mut glider_data = GliderData::default();
+        glider_data.basic_glider_data = LS3_GLIDER_DATA;
+        let mut polar = Polar::default();
+        polar.recalc_glider(&glider_data);
+        polar.recalc(&glider_data, Density::AT_NN());
+
+        assert_float_eq!(polar.stall_speed(1.0).ias().to_km_h(), 63.55);
+        assert_float_eq!(polar.stall_speed(0.5).ias().to_km_h(), 63.55);
+        assert_float_eq!(polar.stall_speed(2.0).ias().to_km_h(), 89.88);
+
+        glider_data.water_ballast = 121.0.kg();
+        polar.recalc(&glider_data, Density::AT_NN());
+        assert_float_eq!(polar.stall_speed(1.0).ias().to_km_h(), 73.21);
+
+        // the stall speed is constant in IAS
+        glider_data.water_ballast = 0.0.kg();
+        polar.recalc(&glider_data, 0.913.kg_m3());
+        assert_float_eq!(polar.stall_speed(1.0).ias().to_km_h(), 63.55);
+        assert_float_eq!(polar.stall_speed(1.0).tas().to_km_h(), 73.61);
+    }
+
+    const REFERENCE_CURVE: PolarCurve = PolarCurve::Points {
+        points: &[
+            [75.0, -0.52],
+            [85.0, -0.50],
+            [100.0, -0.55],
+            [120.0, -0.68],
+            [140.0, -0.88],
+            [160.0, -1.15],
+            [180.0, -1.48],
+            [200.0, -1.95],
+            [230.0, -2.80],
+            [260.0, -3.95],
+        ],
+        order: 3,
+    };
+
+    const REFERENCE_GLIDER_DATA: BasicGliderData = BasicGliderData {
+        name: "Reference 18m",
+        wing_area: 11.0,
+        max_speed: 270.0,
+        empty_mass: 310.0,
+        max_ballast: 200.0,
+        reference_weight: 400.0,
+        handicap: 0,
+        polar_values: [[85.0, -0.50], [120.0, -0.68], [180.0, -1.48]],
+        flaps: None,
+        limits: None,
+        curve: None,
+    };
+
+    #[test]
+    fn test_higher_order_polar() {
+        let mut glider_data = GliderData::default();
+        glider_data.basic_glider_data = REFERENCE_GLIDER_DATA;
+        let mut parabola = Polar::default();
+        parabola.recalc_glider(&glider_data);
+        parabola.recalc(&glider_data, Density::AT_NN());
+
+        glider_data.basic_glider_data.curve = Some(&REFERENCE_CURVE);
+        let mut curve = Polar::default();
+        curve.recalc_glider(&glider_data);
+        curve.recalc(&glider_data, Density::AT_NN());
+
+        // both models agree at low speed, only the curve follows the reference at high speed
+        let sink_rate = |polar: &Polar, speed: f32| {
+            polar
+                .sink_rate(AirSpeed::from_tas_at_nn(speed.km_h()))
+                .to_m_s()
+        };
+        assert!((sink_rate(&curve, 100.0) - sink_rate(&parabola, 100.0)).abs() < 0.02);
+        assert!((sink_rate(&curve, 260.0) - -3.95).abs() < 0.02);
+        assert!((sink_rate(&parabola, 260.0) - -3.95).abs() > 0.4);
+        let min_sink = |polar: &Polar| polar.min_sink_speed().tas().to_km_h();
+        assert!((min_sink(&curve) - min_sink(&parabola)).abs() < 5.0);
+
+        // the steeper polar at high speed results in a slower speed to fly
+        let stf = |polar: &Polar, mc: f32| polar.speed_to_fly(0.0.m_s(), mc.m_s()).tas().to_km_h();
+        assert!((stf(&curve, 0.0) - stf(&parabola, 0.0)).abs() < 5.0);
+        assert!(stf(&curve, 4.0) < stf(&parabola, 4.0) - 10.0);
+
+        // the curve is scaled by the weight like the parabola
+        glider_data.water_ballast = 100.0.kg();
+        curve.recalc(&glider_data, Density::AT_NN());
+        let ratio = (500.0f32 / 400.0).sqrt();
+        let sink_rate_160 = -1.15 * ratio;
+        assert!((sink_rate(&curve, 160.0 * ratio) - sink_rate_160).abs() < 0.03);
+    }
+
+    #[test]
+    fn test_flap_polars() {
+        let asg29 = polar_store::POLARS
+            .iter()
+            .find(|bgd| bgd.name == "ASG-29 (18m)")
+            .unwrap();
+        let mut glider_data = GliderData::default();
+        glider_data.select_glider(*asg29);
+        assert_eq!(glider_data.flap_polar_count(), 5);
+        let mut polar = Polar::default();
+        polar.recalc_glider(&glider_data);
+        polar.recalc(&glider_data, Density::AT_NN());
+
+        glider_data.flap_polars = Default::default();
+        let mut without_flaps = Polar::default();
+        without_flaps.recalc_glider(&glider_data);
+        without_flaps.recalc(&glider_data, Density::AT_NN());
+
+        let sink_rate = |polar: &Polar, speed: f32| {
+            polar
+                .sink_rate(AirSpeed::from_tas_at_nn(speed.km_h()))
+                .to_m_s()
+        };
+        let stf = |polar: &Polar, mc: f32| polar.speed_to_fly(0.0.m_s(), mc.m_s()).tas().to_km_h();
+
+        // the polar of the active flap setting is used, the slow setting is better at low speed
+        polar.set_flap(Some(0));
+        assert_eq!(polar.flap(), Some(0));
+        let (slow_80, slow_185) = (sink_rate(&polar, 80.0), sink_rate(&polar, 185.0));
+        polar.set_flap(Some(4));
+        assert!(sink_rate(&polar, 80.0) < slow_80 - 0.2);
+        assert!(sink_rate(&polar, 185.0) > slow_185 + 0.2);
+
+        // the minimum sink is taken from the same polar as the sink rate
+        let fast_min_sink = polar.min_sink_rate().to_m_s();
+        assert!((70..200).all(|speed| sink_rate(&polar, speed as f32) <= fast_min_sink));
+        polar.set_flap(Some(0));
+        assert!(polar.min_sink_rate().to_m_s() > fast_min_sink + 0.05);
+        polar.set_flap(Some(4));
+
+        // the speed to fly is taken from the envelope, independent of the active setting
+        for mc in [0.0, 2.0, 4.0] {
+            let envelope = stf(&polar, mc);
+            polar.set_flap(Some(0));
+            assert_float_eq!(stf(&polar, mc), envelope);
+            assert!((envelope - stf(&without_flaps, mc)).abs() < 3.0);
+            polar.set_flap(Some(4));
+        }
+
+        // without a valid setting the polar of the glider is used
+        polar.set_flap(Some(7));
+        assert_eq!(polar.flap(), None);
+        assert_float_eq!(sink_rate(&polar, 185.0), sink_rate(&without_flaps, 185.0));
+    }
+
+    #[test]
+    fn test_user_polar_with_flaps() {
+        let asg29 = polar_store::POLARS
+            .iter()
+            .find(|bgd| bgd.name == "ASG-29 (18m)")
+            .unwrap();
+        let mut glider_data = GliderData::default();
+        glider_data.select_glider(*asg29);
+        let mut polar = Polar::default();
+        polar.recalc_glider(&glider_data);
+        polar.recalc(&glider_data, Density::AT_NN());
+        let sink_rate = |polar: &Polar, speed: f32| {
+            polar
+                .sink_rate(AirSpeed::from_tas_at_nn(speed.km_h()))
+                .to_m_s()
+        };
+        polar.set_flap(Some(4));
+        let fast_185 = sink_rate(&polar, 185.0);
+
+        // the polar entered by the pilot keeps the flap polars
+        glider_data.basic_glider_data.polar_values = LS3_GLIDER_DATA.polar_values;
+        polar.recalc_glider(&glider_data);
+        polar.recalc(&glider_data, Density::AT_NN());
+        polar.set_flap(Some(4));
+        assert_eq!(polar.flap(), Some(4));
+        assert_float_eq!(sink_rate(&polar, 185.0), fast_185);
+        polar.set_flap(Some(0));
+        assert!(sink_rate(&polar, 185.0) < fast_185 - 0.2);
+
+        // without a flap setting the polar entered by the pilot is used
+        let mut user = glider_data;
+        user.flap_polars = Default::default();
+        let mut user_polar = Polar::default();
+        user_polar.recalc_glider(&user);
+        user_polar.recalc(&user, Density::AT_NN());
+        polar.set_flap(None);
+        assert_float_eq!(sink_rate(&polar, 185.0), sink_rate(&user_polar, 185.0));
+
+        // a glider without a flap table uses the flap polars entered by the pilot
+        let mut glider_data = GliderData::default();
+        glider_data.basic_glider_data = LS3_GLIDER_DATA;
+        glider_data.flap_polars[0] = asg29.flaps.unwrap().polars[0];
+        glider_data.flap_polars[1] = asg29.flaps.unwrap().polars[4];
+        glider_data.flap_polars[2][0] = [100.0, -0.6]; // incomplete, not used
+        assert_eq!(glider_data.flap_polar_count(), 2);
+        polar.recalc_glider(&glider_data);
+        polar.recalc(&glider_data, Density::AT_NN());
+        polar.set_flap(Some(2));
+        assert_eq!(polar.flap(), None);
+        polar.set_flap(Some(0));
+        let slow_185 = sink_rate(&polar, 185.0);
+        polar.set_flap(Some(1));
+        assert_eq!(polar.flap(), Some(1));
+        assert!(sink_rate(&polar, 185.0) > slow_185 + 0.2);
+    }
 }
@@ -0,0 +1,163 @@
+use crate::system_of_units::Float;
+
+/// Highest order of a polar polynomial
+pub const MAX_ORDER: usize = 5;
+
+/// Coefficients of a polar polynomial, lowest order first
+///
+/// The sink rate in m/s is the sum of `koefs[i] * v^i` with v in m/s.
+pub type Koefs = [Float; MAX_ORDER + 1];
+
+/// Polar of a glider with more than three points
+///
+/// The quadratic polar of the three XCSoar points is badly off at high speed for modern
+/// gliders. With a curve, the polar is a polynomial of higher order, either fitted to measured
+/// points or given by published coefficients.
+#[derive(Clone, Copy)]
+pub enum PolarCurve {
+    /// Points (km/h, m/s), fitted by least squares with a polynomial of the given order
+    Points {
+        points: &'static [[f32; 2]],
+        order: usize,
+    },
+    /// Published coefficients, the sink rate in m/s is the sum of `c[i] * v^i` with v in km/h
+    Coefficients(&'static [f32]),
+}
+
+impl PolarCurve {
+    pub fn koefs(&self) -> Koefs {
+        match self {
+            PolarCurve::Points { points, order } => fit(points, *order),
+            PolarCurve::Coefficients(coefficients) => {
+                let mut koefs = Koefs::default();
+                let mut factor = 1.0;
+                for (koef, coefficient) in koefs.iter_mut().zip(coefficients.iter()) {
+                    *koef = coefficient * factor;
+                    factor *= 3.6;
+                }
+                koefs
+            }
+        }
+    }
+}
+
+/// Least squares fit of a polynomial to points (km/h, m/s)
+///
+/// With as many points as coefficients, the polynomial runs through all points. This is how the
+/// parabola through the three XCSoar points is calculated. The fit is done in f64 with speeds
+/// scaled to the fastest point, because the normal equations of higher orders are badly
+/// conditioned.
+pub fn fit(points: &[[f32; 2]], order: usize) -> Koefs {
+    let n = order.clamp(1, MAX_ORDER) + 1;
+    let scale = points.iter().fold(1.0, |max, p| p[0].max(max)) as f64 / 3.6;
+
+    // normal equations, the last column is the right-hand side
+    let mut m = [[0.0f64; MAX_ORDER + 2]; MAX_ORDER + 1];
+    for point in points {
+        let x = point[0] as f64 / 3.6 / scale;
+        let w = point[1] as f64;
+        let mut powers = [1.0f64; 2 * MAX_ORDER + 1];
+        for i in 1..2 * n - 1 {
+            powers[i] = powers[i - 1] * x;
+        }
+        for row in 0..n {
+            for col in 0..n {
+                m[row][col] += powers[row + col];
+            }
+            m[row][n] += powers[row] * w;
+        }
+    }
+
+    // Gaussian elimination with partial pivoting
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|a, b| (m[*a][col] * m[*a][col]).total_cmp(&(m[*b][col] * m[*b][col])))
+            .unwrap_or(col);
+        m.swap(col, pivot);
+        if m[col][col] == 0.0 {
+            return Koefs::default(); // less points than coefficients
+        }
+        let pivot_row = m[col];
+        for row in m.iter_mut().take(n).skip(col + 1) {
+            let factor = row[col] / pivot_row[col];
+            for (value, pivot) in row.iter_mut().zip(pivot_row).take(n + 1).skip(col) {
+                *value -= factor * pivot;
+            }
+        }
+    }
+    let mut d = [0.0f64; MAX_ORDER + 1];
+    for row in (0..n).rev() {
+        let sum = (row + 1..n).fold(m[row][n], |sum, k| sum - m[row][k] * d[k]);
+        d[row] = sum / m[row][row];
+    }
+
+    // undo the scaling of the speed
+    let mut koefs = Koefs::default();
+    let mut factor = 1.0;
+    for i in 0..n {
+        koefs[i] = (d[i] / factor) as Float;
+        factor *= scale;
+    }
+    koefs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Reference polar of a modern 18 m glider at 400 kg, steeper at high speed than a parabola
+    const REFERENCE: &[[f32; 2]] = &[
+        [75.0, -0.52],
+        [85.0, -0.50],
+        [100.0, -0.55],
+        [120.0, -0.68],
+        [140.0, -0.88],
+        [160.0, -1.15],
+        [180.0, -1.48],
+        [200.0, -1.95],
+        [230.0, -2.80],
+        [260.0, -3.95],
+    ];
+
+    fn sink(koefs: &Koefs, v: f32) -> f32 {
+        let v = v / 3.6;
+        koefs.iter().rev().fold(0.0, |sum, koef| sum * v + koef)
+    }
+
+    #[test]
+    fn test_three_points() {
+        // the parabola runs through the three XCSoar points of the LS-3
+        let points = [[80.0, -0.604], [105.0, -0.700], [180.0, -1.939]];
+        let koefs = fit(&points, 2);
+        for point in points {
+            assert!((sink(&koefs, point[0]) - point[1]).abs() < 0.001);
+        }
+        assert_eq!(koefs[3], 0.0);
+    }
+
+    #[test]
+    fn test_higher_order() {
+        let cubic = fit(REFERENCE, 3);
+        for point in REFERENCE {
+            assert!((sink(&cubic, point[0]) - point[1]).abs() < 0.02);
+        }
+
+        // a parabola through three points is badly off at high speed
+        let parabola = fit(&[REFERENCE[1], REFERENCE[3], REFERENCE[6]], 2);
+        assert!((sink(&parabola, 260.0) - -3.95).abs() > 0.4);
+    }
+
+    #[test]
+    fn test_coefficients() {
+        // published coefficients refer to km/h
+        const COEFFICIENTS: &[f32] = &[-1.2, 0.0155, -8.0e-5, -1.0e-7];
+        let koefs = PolarCurve::Coefficients(COEFFICIENTS).koefs();
+        for speed in [80.0f32, 150.0, 250.0] {
+            let published = COEFFICIENTS
+                .iter()
+                .rev()
+                .fold(0.0, |sum, coefficient| sum * speed + coefficient);
+            assert!((sink(&koefs, speed) - published).abs() < 0.001);
+        }
+    }
+}
@@ -6,6 +6,13 @@
 //! “assets/crate_polar_idx.py”. This script creates an index that enables alphabetical
 //! selection in the menu.
 
+use super::{
+    flaps::{self, FlapTable},
+    polar_curve::PolarCurve,
+    polar_store_idx::{TO_RAW, TO_SORTED},
+    speed_limits::{self, SpeedLimits},
+};
+
 /// Structure with the basic data of a sailplane
 ///
 /// The contents are kept as natural Rust data types and not in the physical sizes. This data
@@ -20,7 +27,10 @@ pub struct BasicGliderData {
     pub max_ballast: f32,      // kg
     pub reference_weight: f32, // kg
     pub handicap: u16,
-    pub polar_values: [[f32; 2]; 3], // (km/h, m/s) * 3
+    pub polar_values: [[f32; 2]; 3],          // (km/h, m/s) * 3
+    pub flaps: Option<&'static FlapTable>,    // speed bands of flapped gliders
+    pub limits: Option<&'static SpeedLimits>, // speed limits of the flight manual
+    pub curve: Option<&'static PolarCurve>,   // polar of higher order than polar_values
 }
 
 impl Default for BasicGliderData {
@@ -34,12 +44,13 @@ impl Default for BasicGliderData {
             reference_weight: 0.0,
             handicap: 0,
             polar_values: [[0.0, 0.0], [0.0, 0.0], [0.0, 0.0]],
+            flaps: None,
+            limits: None,
+            curve: None,
         }
     }
 }
 
-use super::polar_store_idx::{TO_RAW, TO_SORTED};
-
 pub fn to_sorted_idx(raw_idx: usize) -> usize {
     TO_SORTED[raw_idx] as usize
 }
@@ -69,6 +80,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 318.0,
         handicap: 100,
         polar_values: [[80.0, -0.606], [120.0, -0.990], [160.0, -1.918]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 1,  imported from XCSoar
@@ -80,6 +94,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 450.0,
         handicap: 107,
         polar_values: [[100.0, -0.680], [120.0, -0.920], [150.0, -1.450]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 2,  imported from XCSoar
@@ -91,6 +108,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 310.0,
         handicap: 110,
         polar_values: [[115.0, -0.860], [174.0, -1.760], [212.7, -3.400]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 3,  imported from XCSoar
@@ -102,6 +122,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 367.0,
         handicap: 110,
         polar_values: [[95.0, -0.620], [110.0, -0.760], [175.0, -2.010]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 4,  imported from XCSoar
@@ -113,6 +136,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 570.0,
         handicap: 114,
         polar_values: [[113.0, -0.720], [150.6, -1.420], [207.1, -4.100]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 5,  imported from XCSoar
@@ -124,6 +150,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 362.0,
         handicap: 107,
         polar_values: [[84.1, -0.652], [130.0, -0.947], [170.0, -1.838]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 6,  imported from XCSoar
@@ -135,6 +164,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 362.0,
         handicap: 114,
         polar_values: [[108.8, -0.635], [156.4, -1.182], [211.1, -2.540]],
+        flaps: Some(&flaps::ASG29_15M),
+        limits: Some(&speed_limits::ASG29),
+        curve: None,
     },
     BasicGliderData {
         // No 7,  imported from XCSoar
@@ -146,6 +178,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 355.0,
         handicap: 121,
         polar_values: [[85.0, -0.470], [90.0, -0.480], [185.0, -2.000]],
+        flaps: Some(&flaps::ASG29_18M),
+        limits: Some(&speed_limits::ASG29),
+        curve: None,
     },
     BasicGliderData {
         // No 8,  imported from XCSoar
@@ -157,6 +192,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 350.0,
         handicap: 114,
         polar_values: [[100.0, -0.640], [120.0, -0.750], [150.0, -1.130]],
+        flaps: Some(&flaps::ASG29_15M),
+        limits: Some(&speed_limits::ASG29),
+        curve: None,
     },
     BasicGliderData {
         // No 9,  imported from XCSoar
@@ -168,6 +206,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 400.0,
         handicap: 121,
         polar_values: [[90.0, -0.499], [95.5, -0.510], [196.4, -2.120]],
+        flaps: Some(&flaps::ASG29_18M),
+        limits: Some(&speed_limits::ASG29),
+        curve: None,
     },
     BasicGliderData {
         // No 10,  self added
@@ -179,6 +220,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 807.0,
         handicap: 120,
         polar_values: [[100.0, -0.582], [126.0, -0.648], [185.0, -1.450]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 11,  imported from XCSoar
@@ -190,6 +234,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 750.0,
         handicap: 122,
         polar_values: [[130.0, -0.780], [170.0, -1.400], [219.9, -2.600]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 12,  imported from XCSoar
@@ -201,6 +248,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 340.0,
         handicap: 119,
         polar_values: [[100.0, -0.560], [120.0, -0.740], [150.0, -1.160]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 13,  imported from XCSoar
@@ -212,6 +262,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 435.0,
         handicap: 119,
         polar_values: [[90.0, -0.510], [96.0, -0.530], [185.0, -2.000]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 14,  imported from XCSoar
@@ -223,6 +276,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 456.0,
         handicap: 79,
         polar_values: [[85.0, -0.840], [120.0, -1.500], [150.0, -2.800]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 15,  imported from XCSoar
@@ -234,6 +290,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 310.0,
         handicap: 88,
         polar_values: [[75.0, -0.613], [138.0, -1.773], [200.0, -4.234]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 16,  imported from XCSoar
@@ -245,6 +304,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 468.0,
         handicap: 92,
         polar_values: [[74.1, -0.670], [101.9, -0.900], [166.7, -2.680]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 17,  imported from XCSoar
@@ -256,6 +318,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 330.0,
         handicap: 92,
         polar_values: [[100.0, -0.850], [120.0, -1.190], [150.0, -2.020]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 18,  imported from XCSoar
@@ -267,6 +332,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 394.0,
         handicap: 110,
         polar_values: [[95.0, -0.570], [148.0, -1.480], [183.1, -2.600]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 19,  imported from XCSoar
@@ -278,6 +346,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 349.0,
         handicap: 97,
         polar_values: [[97.6, -0.770], [156.1, -1.900], [195.2, -3.400]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 20,  imported from XCSoar
@@ -289,6 +360,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 522.0,
         handicap: 115,
         polar_values: [[114.5, -0.700], [169.1, -1.680], [206.5, -2.900]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 21,  imported from XCSoar
@@ -300,6 +374,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 363.0,
         handicap: 100,
         polar_values: [[97.5, -0.740], [156.0, -1.640], [195.0, -3.100]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 22,  imported from XCSoar
@@ -311,6 +388,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 377.0,
         handicap: 108,
         polar_values: [[116.2, -0.770], [174.3, -1.890], [213.0, -3.300]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 23,  imported from XCSoar
@@ -322,6 +402,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 400.0,
         handicap: 112,
         polar_values: [[95.0, -0.628], [148.0, -1.338], [200.0, -2.774]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 24,  imported from XCSoar
@@ -333,6 +416,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 597.0,
         handicap: 123,
         polar_values: [[80.0, -0.402], [120.0, -0.660], [160.0, -1.354]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 25,  imported from XCSoar
@@ -344,6 +430,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 465.0,
         handicap: 124,
         polar_values: [[100.0, -0.470], [120.0, -0.630], [150.0, -1.040]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 26,  imported from XCSoar
@@ -355,6 +444,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 350.0,
         handicap: 107,
         polar_values: [[108.8, -0.730], [142.2, -1.210], [167.4, -1.800]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 27,  imported from XCSoar
@@ -366,6 +458,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 365.0,
         handicap: 114,
         polar_values: [[88.8, -0.594], [130.0, -0.851], [170.0, -1.610]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 28,  imported from XCSoar
@@ -377,6 +472,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 310.0,
         handicap: 108,
         polar_values: [[92.6, -0.571], [120.4, -0.875], [148.2, -1.394]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 29,  imported from XCSoar
@@ -388,6 +486,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 345.0,
         handicap: 114,
         polar_values: [[65.0, -0.470], [107.0, -0.670], [165.0, -2.000]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 30,  imported from XCSoar
@@ -399,6 +500,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 350.0,
         handicap: 120,
         polar_values: [[100.0, -0.540], [120.0, -0.630], [150.0, -1.070]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 31,  imported from XCSoar
@@ -410,6 +514,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 395.0,
         handicap: 120,
         polar_values: [[100.0, -0.540], [120.0, -0.690], [150.0, -1.110]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 32,  imported from XCSoar
@@ -421,6 +528,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 530.0,
         handicap: 123,
         polar_values: [[100.0, -0.520], [120.0, -0.610], [150.0, -0.910]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 33,  imported from XCSoar
@@ -432,6 +542,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 200.0,
         handicap: 93,
         polar_values: [[100.0, -0.740], [120.0, -1.010], [150.0, -1.660]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 34,  imported from XCSoar
@@ -443,6 +556,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 310.0,
         handicap: 98,
         polar_values: [[80.0, -0.600], [100.0, -0.750], [140.0, -1.450]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 35,  imported from XCSoar
@@ -454,6 +570,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 700.0,
         handicap: 120,
         polar_values: [[110.0, -0.640], [140.0, -0.880], [180.0, -1.470]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 36,  imported from XCSoar
@@ -465,6 +584,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 472.0,
         handicap: 78,
         polar_values: [[85.0, -0.840], [143.0, -3.320], [200.0, -9.610]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 37,  imported from XCSoar
@@ -476,6 +598,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 500.0,
         handicap: 78,
         polar_values: [[70.0, -0.850], [110.0, -1.250], [160.0, -3.200]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 38,  imported from XCSoar
@@ -487,6 +612,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 510.0,
         handicap: 80,
         polar_values: [[95.0, -0.940], [148.0, -2.600], [200.0, -6.370]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 39,  imported from XCSoar
@@ -498,6 +626,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 470.0,
         handicap: 93,
         polar_values: [[100.0, -0.830], [120.0, -1.040], [150.0, -1.690]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 40,  imported from XCSoar
@@ -509,6 +640,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 330.0,
         handicap: 102,
         polar_values: [[100.0, -0.740], [120.0, -1.060], [150.0, -1.880]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 41,  imported from XCSoar
@@ -520,6 +654,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 300.0,
         handicap: 100,
         polar_values: [[100.0, -0.730], [120.0, -1.000], [150.0, -1.700]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 42,  imported from XCSoar
@@ -531,6 +668,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 613.0,
         handicap: 111,
         polar_values: [[106.0, -0.620], [153.0, -1.530], [200.0, -3.200]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 43,  imported from XCSoar
@@ -542,6 +682,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 300.0,
         handicap: 107,
         polar_values: [[100.0, -0.680], [120.0, -0.860], [150.0, -1.300]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 44,  imported from XCSoar
@@ -553,6 +696,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 310.0,
         handicap: 104,
         polar_values: [[95.0, -0.660], [140.0, -1.280], [160.0, -1.700]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 45,  imported from XCSoar
@@ -564,6 +710,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 440.0,
         handicap: 107,
         polar_values: [[115.0, -0.760], [160.5, -1.220], [210.2, -2.300]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 46,  imported from XCSoar
@@ -575,6 +724,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 444.0,
         handicap: 109,
         polar_values: [[118.3, -0.680], [163.8, -1.150], [198.3, -1.800]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 47,  imported from XCSoar
@@ -586,6 +738,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 659.0,
         handicap: 104,
         polar_values: [[115.4, -0.710], [152.0, -1.280], [190.0, -2.300]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 48,  imported from XCSoar
@@ -597,6 +752,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 327.0,
         handicap: 110,
         polar_values: [[100.0, -0.600], [120.0, -0.760], [150.0, -1.190]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 49,  imported from XCSoar
@@ -608,6 +766,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 468.0,
         handicap: 113,
         polar_values: [[103.6, -0.653], [130.0, -0.891], [170.0, -1.481]],
+        flaps: Some(&flaps::DG800_15M),
+        limits: Some(&speed_limits::DG800),
+        curve: None,
     },
     BasicGliderData {
         // No 50,  imported from XCSoar
@@ -619,6 +780,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 472.0,
         handicap: 119,
         polar_values: [[90.0, -0.550], [130.0, -0.792], [170.0, -1.425]],
+        flaps: Some(&flaps::DG800_18M),
+        limits: Some(&speed_limits::DG800),
+        curve: None,
     },
     BasicGliderData {
         // No 51,  imported from XCSoar
@@ -630,6 +794,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 370.0,
         handicap: 113,
         polar_values: [[92.1, -0.581], [130.0, -0.975], [170.0, -1.693]],
+        flaps: Some(&flaps::DG800_15M),
+        limits: Some(&speed_limits::DG800),
+        curve: None,
     },
     BasicGliderData {
         // No 52,  imported from XCSoar
@@ -641,6 +808,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 350.0,
         handicap: 119,
         polar_values: [[77.5, -0.473], [130.0, -0.926], [170.0, -1.795]],
+        flaps: Some(&flaps::DG800_18M),
+        limits: Some(&speed_limits::DG800),
+        curve: None,
     },
     BasicGliderData {
         // No 53,  imported from XCSoar
@@ -652,6 +822,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 100.0,
         handicap: 0,
         polar_values: [[30.0, -1.100], [44.3, -1.520], [58.0, -3.600]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 54,  imported from XCSoar
@@ -663,6 +836,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 100.0,
         handicap: 0,
         polar_values: [[37.0, -0.950], [48.1, -1.150], [73.0, -3.600]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 55,  imported from XCSoar
@@ -674,6 +850,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 100.0,
         handicap: 0,
         polar_values: [[37.0, -0.890], [48.3, -1.020], [76.5, -3.300]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 56,  imported from XCSoar
@@ -685,6 +864,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 670.0,
         handicap: 68,
         polar_values: [[100.0, -1.290], [120.0, -1.610], [150.0, -2.450]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 57,  imported from XCSoar
@@ -696,6 +878,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 350.0,
         handicap: 107,
         polar_values: [[95.0, -0.630], [140.0, -1.230], [180.0, -2.290]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 58,  imported from XCSoar
@@ -707,6 +892,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 312.0,
         handicap: 108,
         polar_values: [[105.0, -0.660], [150.0, -1.050], [200.0, -2.000]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 59,  imported from XCSoar
@@ -718,6 +906,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 377.0,
         handicap: 114,
         polar_values: [[100.0, -0.570], [120.0, -0.760], [150.0, -1.330]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 60,  imported from XCSoar
@@ -729,6 +920,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 615.0,
         handicap: 112,
         polar_values: [[103.0, -0.640], [152.0, -1.250], [200.0, -2.510]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 61,  imported from XCSoar
@@ -740,6 +934,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 615.0,
         handicap: 112,
         polar_values: [[103.0, -0.640], [152.0, -1.250], [200.0, -2.510]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 62,  imported from XCSoar
@@ -751,6 +948,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 700.0,
         handicap: 113,
         polar_values: [[110.0, -0.664], [155.0, -1.206], [200.0, -2.287]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 63,  imported from XCSoar
@@ -762,6 +962,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 670.0,
         handicap: 125,
         polar_values: [[100.0, -0.460], [120.0, -0.610], [150.0, -0.960]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 64,  imported from XCSoar
@@ -773,6 +976,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 670.0,
         handicap: 125,
         polar_values: [[100.0, -0.470], [120.0, -0.630], [150.0, -0.970]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 65,  imported from XCSoar
@@ -784,6 +990,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 330.0,
         handicap: 96,
         polar_values: [[75.0, -0.700], [93.0, -0.740], [185.0, -3.100]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 66,  imported from XCSoar
@@ -795,6 +1004,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 580.0,
         handicap: 92,
         polar_values: [[99.0, -0.800], [175.0, -1.950], [225.0, -3.800]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 67,  imported from XCSoar
@@ -806,6 +1018,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 380.0,
         handicap: 91,
         polar_values: [[75.0, -0.600], [100.0, -0.700], [180.0, -3.100]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 68,  imported from XCSoar
@@ -817,6 +1032,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 380.0,
         handicap: 100,
         polar_values: [[75.0, -0.600], [100.0, -0.700], [180.0, -2.800]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 69,  imported from XCSoar
@@ -828,6 +1046,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 351.0,
         handicap: 105,
         polar_values: [[90.0, -0.630], [105.0, -0.720], [157.0, -2.000]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 70,  imported from XCSoar
@@ -839,6 +1060,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 374.0,
         handicap: 107,
         polar_values: [[94.0, -0.610], [141.1, -1.180], [172.4, -2.000]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 71,  imported from XCSoar
@@ -850,6 +1074,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 305.0,
         handicap: 110,
         polar_values: [[100.0, -0.780], [120.0, -0.970], [150.0, -1.430]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 72,  imported from XCSoar
@@ -861,6 +1088,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 300.0,
         handicap: 100,
         polar_values: [[94.0, -0.680], [147.7, -2.030], [184.6, -4.100]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 73,  imported from XCSoar
@@ -872,6 +1102,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 304.0,
         handicap: 98,
         polar_values: [[97.0, -0.790], [152.4, -1.910], [190.5, -3.300]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 74,  imported from XCSoar
@@ -883,6 +1116,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 295.0,
         handicap: 96,
         polar_values: [[100.0, -0.850], [120.0, -1.210], [150.0, -2.010]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 75,  imported from XCSoar
@@ -894,6 +1130,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 590.0,
         handicap: 84,
         polar_values: [[100.0, -0.820], [160.0, -2.280], [200.0, -4.270]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 76,  imported from XCSoar
@@ -905,6 +1144,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 360.0,
         handicap: 96,
         polar_values: [[100.0, -0.820], [135.7, -1.550], [184.1, -3.300]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 77,  imported from XCSoar
@@ -916,6 +1158,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 405.0,
         handicap: 121,
         polar_values: [[108.0, -0.570], [152.0, -1.060], [180.0, -1.650]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 78,  imported from XCSoar
@@ -927,6 +1172,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 441.0,
         handicap: 126,
         polar_values: [[108.0, -0.520], [156.0, -1.100], [180.0, -1.620]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 79,  imported from XCSoar
@@ -938,6 +1186,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 350.0,
         handicap: 116,
         polar_values: [[100.0, -0.600], [130.0, -0.800], [160.0, -1.200]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 80,  imported from XCSoar
@@ -949,6 +1200,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 398.0,
         handicap: 122,
         polar_values: [[100.0, -0.550], [130.0, -0.720], [160.0, -1.120]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 81,  imported from XCSoar
@@ -960,6 +1214,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 498.0,
         handicap: 102,
         polar_values: [[100.0, -0.710], [120.0, -0.920], [150.0, -1.460]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 82,  imported from XCSoar
@@ -971,6 +1228,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 603.0,
         handicap: 106,
         polar_values: [[115.5, -0.760], [171.8, -1.980], [210.0, -4.000]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 83,  imported from XCSoar
@@ -982,6 +1242,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 519.0,
         handicap: 108,
         polar_values: [[90.0, -0.600], [120.0, -0.880], [160.0, -1.640]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 84,  imported from XCSoar
@@ -993,6 +1256,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 418.0,
         handicap: 78,
         polar_values: [[87.0, -0.900], [120.0, -1.500], [150.0, -2.600]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 85,  imported from XCSoar
@@ -1004,6 +1270,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 360.0,
         handicap: 54,
         polar_values: [[65.0, -0.950], [120.0, -2.500], [140.0, -3.500]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 86,  imported from XCSoar
@@ -1015,6 +1284,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 310.0,
         handicap: 82,
         polar_values: [[64.8, -0.670], [130.0, -2.260], [170.0, -4.690]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 87,  imported from XCSoar
@@ -1026,6 +1298,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 310.0,
         handicap: 85,
         polar_values: [[87.3, -0.810], [141.9, -2.030], [174.7, -3.500]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 88,  imported from XCSoar
@@ -1037,6 +1312,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 445.0,
         handicap: 78,
         polar_values: [[87.0, -0.920], [120.0, -1.550], [150.0, -2.700]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 89,  imported from XCSoar
@@ -1048,6 +1326,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 290.0,
         handicap: 76,
         polar_values: [[74.1, -0.760], [101.9, -1.270], [166.7, -4.640]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 90,  imported from XCSoar
@@ -1059,6 +1340,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 330.0,
         handicap: 86,
         polar_values: [[87.2, -0.800], [135.6, -1.730], [174.4, -3.400]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 91,  imported from XCSoar
@@ -1070,6 +1354,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 430.0,
         handicap: 114,
         polar_values: [[75.0, -0.480], [125.0, -0.880], [175.0, -1.970]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 92,  imported from XCSoar
@@ -1081,6 +1368,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 285.0,
         handicap: 113,
         polar_values: [[100.0, -0.600], [120.0, -0.720], [150.0, -1.090]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 93,  imported from XCSoar
@@ -1092,6 +1382,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 295.0,
         handicap: 119,
         polar_values: [[100.0, -0.560], [120.0, -0.740], [150.0, -1.160]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 94,  imported from XCSoar
@@ -1103,6 +1396,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 285.0,
         handicap: 108,
         polar_values: [[100.0, -0.640], [120.0, -0.850], [150.0, -1.410]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 95,  imported from XCSoar
@@ -1114,6 +1410,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 295.0,
         handicap: 114,
         polar_values: [[100.0, -0.600], [120.0, -0.820], [150.0, -1.340]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 96,  imported from XCSoar
@@ -1125,6 +1424,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 285.0,
         handicap: 113,
         polar_values: [[95.0, -0.574], [148.0, -1.310], [200.0, -2.885]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 97,  imported from XCSoar
@@ -1136,6 +1438,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 298.0,
         handicap: 119,
         polar_values: [[115.0, -0.680], [158.0, -1.379], [200.0, -2.975]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 98,  imported from XCSoar
@@ -1147,6 +1452,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 370.0,
         handicap: 113,
         polar_values: [[100.0, -0.640], [120.0, -0.800], [150.0, -1.260]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 99,  imported from XCSoar
@@ -1158,6 +1466,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 380.0,
         handicap: 119,
         polar_values: [[100.0, -0.580], [120.0, -0.750], [150.0, -1.210]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 100,  imported from XCSoar
@@ -1169,6 +1480,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 350.0,
         handicap: 98,
         polar_values: [[115.9, -1.020], [154.5, -1.840], [193.1, -3.300]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 101,  imported from XCSoar
@@ -1180,6 +1494,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 345.0,
         handicap: 100,
         polar_values: [[100.0, -0.750], [120.0, -0.980], [150.0, -1.600]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 102,  imported from XCSoar
@@ -1191,6 +1508,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 383.0,
         handicap: 107,
         polar_values: [[93.0, -0.640], [127.0, -0.930], [148.2, -1.280]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 103,  imported from XCSoar
@@ -1202,6 +1522,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 325.0,
         handicap: 109,
         polar_values: [[100.0, -0.610], [120.0, -0.840], [150.0, -1.530]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 104,  self added
@@ -1213,6 +1536,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 396.0,
         handicap: 108,
         polar_values: [[80.0, -0.604], [105.0, -0.700], [180.0, -1.939]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 105,  imported from XCSoar
@@ -1224,6 +1550,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 361.0,
         handicap: 104,
         polar_values: [[100.0, -0.690], [120.0, -0.870], [150.0, -1.440]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 106,  imported from XCSoar
@@ -1235,6 +1564,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 461.0,
         handicap: 118,
         polar_values: [[75.0, -0.450], [135.0, -1.000], [172.5, -1.900]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 107,  imported from XCSoar
@@ -1246,6 +1578,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 327.0,
         handicap: 111,
         polar_values: [[90.0, -0.600], [100.0, -0.658], [183.0, -1.965]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 108,  imported from XCSoar
@@ -1257,6 +1592,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 330.0,
         handicap: 117,
         polar_values: [[90.0, -0.510], [100.0, -0.570], [183.0, -2.000]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 109,  imported from XCSoar
@@ -1268,6 +1606,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 350.0,
         handicap: 107,
         polar_values: [[103.8, -0.730], [155.7, -1.470], [180.0, -2.660]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 110,  imported from XCSoar
@@ -1279,6 +1620,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 325.0,
         handicap: 108,
         polar_values: [[70.0, -0.510], [115.0, -0.850], [173.0, -2.000]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 111,  imported from XCSoar
@@ -1290,6 +1634,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 325.0,
         handicap: 114,
         polar_values: [[80.0, -0.510], [94.0, -0.560], [173.0, -2.000]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 112,  imported from XCSoar
@@ -1301,6 +1648,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 345.0,
         handicap: 107,
         polar_values: [[100.0, -0.690], [120.0, -0.920], [150.0, -1.450]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 113,  imported from XCSoar
@@ -1312,6 +1662,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 493.0,
         handicap: 114,
         polar_values: [[119.8, -0.750], [179.8, -2.140], [219.7, -3.800]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 114,  imported from XCSoar
@@ -1323,6 +1676,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 527.0,
         handicap: 122,
         polar_values: [[116.2, -0.670], [174.3, -1.810], [232.4, -3.800]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 115,  imported from XCSoar
@@ -1334,6 +1690,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 712.0,
         handicap: 121,
         polar_values: [[93.6, -0.460], [175.4, -1.480], [218.7, -2.500]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 116,  imported from XCSoar
@@ -1345,6 +1704,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 820.0,
         handicap: 121,
         polar_values: [[115.0, -0.570], [157.4, -0.980], [222.2, -2.300]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 117,  imported from XCSoar
@@ -1356,6 +1718,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 577.0,
         handicap: 121,
         polar_values: [[141.7, -0.990], [182.3, -1.890], [243.1, -4.000]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 118,  imported from XCSoar
@@ -1367,6 +1732,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 597.0,
         handicap: 124,
         polar_values: [[85.1, -0.410], [128.0, -0.750], [162.7, -1.400]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 119,  imported from XCSoar
@@ -1378,6 +1746,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 743.0,
         handicap: 123,
         polar_values: [[107.5, -0.500], [142.7, -0.830], [181.5, -1.600]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 120,  imported from XCSoar
@@ -1389,6 +1760,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 820.0,
         handicap: 123,
         polar_values: [[100.0, -0.480], [150.0, -0.870], [190.8, -1.600]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 121,  imported from XCSoar
@@ -1400,6 +1774,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 354.0,
         handicap: 102,
         polar_values: [[102.5, -0.690], [157.8, -1.590], [216.9, -3.600]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 122,  imported from XCSoar
@@ -1411,6 +1788,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 348.0,
         handicap: 104,
         polar_values: [[100.0, -0.690], [156.5, -1.780], [215.2, -4.200]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 123,  imported from XCSoar
@@ -1422,6 +1802,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 437.0,
         handicap: 104,
         polar_values: [[109.6, -0.830], [166.7, -2.000], [241.2, -4.700]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 124,  imported from XCSoar
@@ -1433,6 +1816,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 460.0,
         handicap: 0,
         polar_values: [[123.6, -0.780], [152.0, -1.120], [200.2, -2.200]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 125,  imported from XCSoar
@@ -1444,6 +1830,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 300.0,
         handicap: 85,
         polar_values: [[99.5, -0.950], [158.5, -2.850], [198.1, -5.100]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 126,  imported from XCSoar
@@ -1455,6 +1844,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 546.0,
         handicap: 86,
         polar_values: [[104.0, -0.847], [152.0, -1.994], [200.0, -4.648]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 127,  imported from XCSoar
@@ -1466,6 +1858,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 344.0,
         handicap: 102,
         polar_values: [[85.0, -0.620], [105.0, -0.750], [175.0, -2.540]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 128,  imported from XCSoar
@@ -1477,6 +1872,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 310.0,
         handicap: 100,
         polar_values: [[100.0, -0.700], [120.0, -0.980], [150.0, -1.580]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 129,  imported from XCSoar
@@ -1488,6 +1886,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 306.0,
         handicap: 86,
         polar_values: [[90.0, -0.847], [126.0, -1.644], [198.0, -5.098]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 130,  imported from XCSoar
@@ -1499,6 +1900,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 420.0,
         handicap: 0,
         polar_values: [[60.0, -1.020], [80.0, -0.960], [120.0, -2.110]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 131,  imported from XCSoar
@@ -1510,6 +1914,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 250.0,
         handicap: 84,
         polar_values: [[99.3, -0.920], [140.0, -1.800], [170.0, -2.900]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 132,  imported from XCSoar
@@ -1521,6 +1928,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 300.0,
         handicap: 88,
         polar_values: [[100.0, -0.810], [120.0, -1.270], [150.0, -2.500]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 133,  imported from XCSoar
@@ -1532,6 +1942,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 315.0,
         handicap: 63,
         polar_values: [[82.3, -1.040], [117.7, -1.880], [156.9, -3.800]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 134,  imported from XCSoar
@@ -1543,6 +1956,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 354.0,
         handicap: 85,
         polar_values: [[89.8, -0.800], [143.7, -2.100], [179.6, -3.800]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 135,  imported from XCSoar
@@ -1554,6 +1970,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 381.0,
         handicap: 0,
         polar_values: [[98.7, -0.740], [151.8, -1.800], [202.9, -3.900]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 136,  imported from XCSoar
@@ -1565,6 +1984,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 322.0,
         handicap: 76,
         polar_values: [[76.0, -0.680], [133.0, -2.000], [170.9, -4.100]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 137,  imported from XCSoar
@@ -1576,6 +1998,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 472.0,
         handicap: 54,
         polar_values: [[71.5, -0.960], [113.0, -1.740], [147.7, -3.440]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 138,  imported from XCSoar
@@ -1587,6 +2012,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 370.0,
         handicap: 86,
         polar_values: [[80.0, -0.720], [100.0, -0.980], [150.0, -2.460]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 139,  imported from XCSoar
@@ -1598,6 +2026,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 350.0,
         handicap: 98,
         polar_values: [[70.8, -0.600], [94.5, -0.690], [148.1, -1.830]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 140,  imported from XCSoar
@@ -1609,6 +2040,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 482.0,
         handicap: 113,
         polar_values: [[109.5, -0.660], [157.1, -1.470], [196.4, -2.700]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 141,  imported from XCSoar
@@ -1620,6 +2054,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 375.0,
         handicap: 100,
         polar_values: [[100.0, -0.730], [120.0, -0.950], [150.0, -1.600]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 142,  imported from XCSoar
@@ -1631,6 +2068,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 326.0,
         handicap: 100,
         polar_values: [[95.0, -0.660], [180.0, -2.240], [220.0, -3.850]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 143,  imported from XCSoar
@@ -1642,6 +2082,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 435.0,
         handicap: 84,
         polar_values: [[100.0, -1.000], [120.0, -1.420], [150.0, -2.350]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 144,  imported from XCSoar
@@ -1653,6 +2096,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 333.0,
         handicap: 90,
         polar_values: [[70.0, -0.580], [130.0, -1.600], [180.0, -3.600]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 145,  imported from XCSoar
@@ -1664,6 +2110,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 442.0,
         handicap: 98,
         polar_values: [[98.0, -0.920], [174.0, -4.350], [250.0, -13.220]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 146,  imported from XCSoar
@@ -1675,6 +2124,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 442.0,
         handicap: 98,
         polar_values: [[99.0, -0.860], [175.0, -4.220], [250.0, -13.010]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 147,  imported from XCSoar
@@ -1686,6 +2138,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 442.0,
         handicap: 102,
         polar_values: [[91.0, -0.690], [170.0, -3.980], [250.0, -12.660]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 148,  imported from XCSoar
@@ -1697,6 +2152,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 350.0,
         handicap: 106,
         polar_values: [[100.0, -0.660], [120.0, -0.860], [150.0, -1.400]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 149,  imported from XCSoar
@@ -1708,6 +2166,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 540.0,
         handicap: 76,
         polar_values: [[70.0, -0.830], [90.0, -1.000], [140.0, -2.530]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 150,  imported from XCSoar
@@ -1719,6 +2180,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 450.0,
         handicap: 0,
         polar_values: [[75.0, -0.548], [125.0, -1.267], [160.0, -2.439]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 151,  imported from XCSoar
@@ -1730,6 +2194,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 395.0,
         handicap: 84,
         polar_values: [[78.0, -0.637], [139.0, -2.000], [200.0, -5.092]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 152,  imported from XCSoar
@@ -1741,6 +2208,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 337.0,
         handicap: 99,
         polar_values: [[93.2, -0.740], [149.2, -1.710], [205.1, -4.200]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 153,  imported from XCSoar
@@ -1752,6 +2222,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 850.0,
         handicap: 110,
         polar_values: [[133.5, -0.830], [167.8, -1.410], [205.0, -2.300]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 154,  imported from XCSoar
@@ -1763,6 +2236,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 472.0,
         handicap: 99,
         polar_values: [[100.0, -0.710], [120.0, -0.830], [150.0, -1.350]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 155,  imported from XCSoar
@@ -1774,6 +2250,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 347.0,
         handicap: 96,
         polar_values: [[90.0, -0.780], [130.0, -1.410], [160.0, -2.440]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 156,  imported from XCSoar
@@ -1785,6 +2264,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 335.0,
         handicap: 86,
         polar_values: [[80.0, -0.700], [100.0, -1.050], [120.0, -1.650]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 157,  self added
@@ -1796,6 +2278,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 339.0,
         handicap: 115,
         polar_values: [[85.0, -0.576], [110.0, -0.648], [200.0, -2.230]],
+        flaps: Some(&flaps::VENTUS2_15M),
+        limits: Some(&speed_limits::VENTUS2_15M),
+        curve: None,
     },
     BasicGliderData {
         // No 158,  imported from XCSoar
@@ -1807,6 +2292,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 385.0,
         handicap: 120,
         polar_values: [[80.0, -0.500], [120.0, -0.730], [180.0, -2.000]],
+        flaps: Some(&flaps::VENTUS2_18M),
+        limits: Some(&speed_limits::VENTUS2_18M),
+        curve: None,
     },
     BasicGliderData {
         // No 159,  imported from XCSoar
@@ -1818,6 +2306,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 410.0,
         handicap: 120,
         polar_values: [[100.0, -0.620], [150.0, -1.200], [200.0, -2.300]],
+        flaps: Some(&flaps::VENTUS2_18M),
+        limits: Some(&speed_limits::VENTUS2_18M),
+        curve: None,
     },
     BasicGliderData {
         // No 160,  imported from XCSoar
@@ -1829,6 +2320,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 385.0,
         handicap: 120,
         polar_values: [[80.0, -0.500], [120.0, -0.730], [180.0, -2.000]],
+        flaps: Some(&flaps::VENTUS2_18M),
+        limits: Some(&speed_limits::VENTUS2_18M),
+        curve: None,
     },
     BasicGliderData {
         // No 161,  imported from XCSoar
@@ -1840,6 +2334,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 470.0,
         handicap: 120,
         polar_values: [[100.0, -0.560], [150.0, -1.130], [200.0, -2.280]],
+        flaps: Some(&flaps::VENTUS2_18M),
+        limits: Some(&speed_limits::VENTUS2_18M),
+        curve: None,
     },
     BasicGliderData {
         // No 162,  imported from XCSoar
@@ -1851,6 +2348,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 358.0,
         handicap: 113,
         polar_values: [[100.2, -0.640], [159.7, -1.470], [239.5, -4.300]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 163,  imported from XCSoar
@@ -1862,6 +2362,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 341.0,
         handicap: 110,
         polar_values: [[97.7, -0.680], [156.3, -1.460], [234.4, -3.900]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 164,  imported from XCSoar
@@ -1873,6 +2376,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 430.0,
         handicap: 115,
         polar_values: [[100.2, -0.600], [159.7, -1.320], [210.5, -2.500]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 165,  imported from XCSoar
@@ -1884,6 +2390,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 330.0,
         handicap: 86,
         polar_values: [[80.0, -0.610], [152.0, -2.000], [174.0, -3.000]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 166,  imported from XCSoar
@@ -1895,6 +2404,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 358.0,
         handicap: 0,
         polar_values: [[110.0, -0.880], [167.0, -2.210], [203.7, -3.600]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 167,  Manufacturer's data interpreted by Andreas Westkamp
@@ -1906,6 +2418,9 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 400.0,
         handicap: 122,
         polar_values: [[97.2, -0.511], [111.6, -0.556], [180.0, -1.369]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
     BasicGliderData {
         // No 168,  Manufacturer's data interpreted by Andreas Westkamp
@@ -1917,5 +2432,8 @@ pub const POLARS: &[BasicGliderData] = &[
         reference_weight: 352.0,
         handicap: 116,
         polar_values: [[86.4, -0.583], [115.2, -0.642], [180.0, -1.473]],
+        flaps: None,
+        limits: None,
+        curve: None,
     },
 ];
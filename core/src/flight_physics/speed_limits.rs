@@ -0,0 +1,105 @@
+use crate::{
+    flight_physics::density,
+    system_of_units::{FloatToLength, FloatToSpeed, Length, Speed},
+};
+
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
+/// Speed limits of a glider from the flight manual
+///
+/// All limits are IAS and apply up to the altitude `tas_altitude`. Above it, the manufacturers
+/// limit the TAS to protect the glider against flutter, so the IAS limits decrease with the
+/// square root of the air density. The values are approximations, the placard in the cockpit is
+/// authoritative.
+#[derive(Clone, Copy)]
+pub struct SpeedLimits {
+    pub vne: f32,          // km/h, never exceed speed
+    pub rough_air: f32,    // km/h, VRA, maximum speed in rough air
+    pub airbrakes: f32,    // km/h, maximum speed with extended airbrakes
+    pub ballast: f32,      // km/h, never exceed speed with water ballast
+    pub tas_altitude: f32, // m, pressure altitude above which the TAS is limited
+}
+
+impl SpeedLimits {
+    /// Never exceed speed at the pressure altitude, with extended airbrakes or water ballast
+    pub fn vne(&self, altitude: Length, airbrakes: bool, ballast: bool) -> Speed {
+        let mut vne = self.vne;
+        if airbrakes {
+            vne = vne.min(self.airbrakes);
+        }
+        if ballast {
+            vne = vne.min(self.ballast);
+        }
+        (vne * self.altitude_ratio(altitude)).km_h()
+    }
+
+    /// Maximum speed in rough air at the pressure altitude
+    pub fn rough_air(&self, altitude: Length) -> Speed {
+        (self.rough_air * self.altitude_ratio(altitude)).km_h()
+    }
+
+    // IAS ratio of a constant TAS at the altitude and at tas_altitude
+    fn altitude_ratio(&self, altitude: Length) -> f32 {
+        let tas_altitude = self.tas_altitude.m();
+        if altitude <= tas_altitude {
+            return 1.0;
+        }
+        (density(altitude).to_kg_m3() / density(tas_altitude).to_kg_m3()).sqrt()
+    }
+}
+
+pub const ASG29: SpeedLimits = SpeedLimits {
+    vne: 285.0,
+    rough_air: 200.0,
+    airbrakes: 285.0,
+    ballast: 285.0,
+    tas_altitude: 2000.0,
+};
+
+pub const DG800: SpeedLimits = SpeedLimits {
+    vne: 270.0,
+    rough_air: 200.0,
+    airbrakes: 270.0,
+    ballast: 270.0,
+    tas_altitude: 2000.0,
+};
+
+pub const VENTUS2_15M: SpeedLimits = SpeedLimits {
+    vne: 285.0,
+    rough_air: 200.0,
+    airbrakes: 285.0,
+    ballast: 285.0,
+    tas_altitude: 2000.0,
+};
+
+pub const VENTUS2_18M: SpeedLimits = SpeedLimits {
+    vne: 285.0,
+    rough_air: 200.0,
+    airbrakes: 285.0,
+    ballast: 250.0,
+    tas_altitude: 2000.0,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    #[test]
+    fn test_altitude_reduction() {
+        // the TAS is constant above 2000 m
+        let vne = |altitude: f32| ASG29.vne(altitude.m(), false, false).to_km_h();
+        assert_float_eq!(vne(0.0), 285.0);
+        assert_float_eq!(vne(2000.0), 285.0);
+        assert_float_eq!(vne(3000.0), 270.9);
+        assert_float_eq!(vne(6000.0), 230.8);
+        assert_float_eq!(ASG29.rough_air(6000.0.m()).to_km_h(), 162.0);
+    }
+
+    #[test]
+    fn test_airbrakes_and_ballast() {
+        assert_float_eq!(VENTUS2_18M.vne(0.0.m(), false, true).to_km_h(), 250.0);
+        assert_float_eq!(VENTUS2_18M.vne(0.0.m(), true, false).to_km_h(), 285.0);
+    }
+}
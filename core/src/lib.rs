@@ -62,4 +62,20 @@ pub mod basic_config {
     pub const MENU_TIMEOUT: u16 = 30;
     /// Timeout in milliseconds before data is written to the EEPROM or the CAN bus
     pub const PERSISTENCE_TIMEOUT: u16 = 500;
+    /// Number of recent thermals used for the automatic MacCready value
+    pub const AUTO_MC_THERMALS: usize = 3;
+    /// Time in seconds the icing warning is shown
+    pub const ICING_INFO_TIMEOUT: u8 = 10;
+    /// Time in seconds without outside air temperature or humidity, before they are unknown
+    pub const OAT_HUMIDITY_TIMEOUT: u16 = 10;
+    /// Time in seconds an engine warning is shown
+    pub const ENGINE_INFO_TIMEOUT: u8 = 10;
+    /// Time in seconds without airspeed from the sensorbox, before the speed warnings are off
+    pub const SENSOR_AIRSPEED_TIMEOUT: u16 = 3;
+    /// Time in seconds without wind from the sensorbox, before the circling wind is used
+    pub const SENSOR_WIND_TIMEOUT: u16 = 10;
+    /// Time in seconds without turn rate from the sensorbox, before the GNSS track is used
+    pub const SENSOR_TURN_RATE_TIMEOUT: u16 = 3;
+    /// Time in seconds without static pressure from the sensorbox, before it is not averaged
+    pub const SENSOR_PRESSURE_TIMEOUT: u16 = 3;
 }
@@ -1,5 +1,5 @@
 use crate::{
-    system_of_units::{FloatToSpeed, Speed},
+    system_of_units::{FloatToLength, FloatToSpeed, Length, Pressure, Speed},
     AirSpeed,
 };
 
@@ -13,8 +13,19 @@ pub struct Calculated {
     pub speed_to_fly_1s: Speed, // ref. IAS
     pub thermal_climb_rate: Speed,
     pub av2_climb_rate: Speed, // calculated by frontend
+    pub vario_climb_rate: Speed, // according to the vario source
+    pub mc_suggestion: Option<Speed>, // derived from the recent thermals
+    pub icing_risk: bool, // OAT near 0 °C with high humidity
+    pub flap_setting: Option<u8>, // index into the FlapTable of the glider
+    pub optimal_flap: Option<u8>, // for the current airspeed and wing loading
+    pub vne: Option<Speed>, // ref. IAS, at the altitude, with airbrakes and ballast
+    pub rough_air_speed: Option<Speed>, // ref. IAS, at the altitude
+    pub wind_quality: u8, // of the circling wind, 0 without an estimate
+    pub qnh_proposal: Option<Pressure>, // automatic QNH waiting for the pilot
+    pub qnh_elevation: Length, // field elevation of the proposed QNH
     pub frequency: u16,
     pub continuous: bool,
+    pub duty_cycle: u16,
     pub gain: i8,
     pub av_supply_voltage: f32,
 }
@@ -30,8 +41,19 @@ impl Default for Calculated {
             speed_to_fly_1s: 0.0.km_h(),
             thermal_climb_rate: 0.0.m_s(),
             av2_climb_rate: 0.0.m_s(),
+            vario_climb_rate: 0.0.m_s(),
+            mc_suggestion: None,
+            icing_risk: false,
+            flap_setting: None,
+            optimal_flap: None,
+            vne: None,
+            rough_air_speed: None,
+            wind_quality: 0,
+            qnh_proposal: None,
+            qnh_elevation: 0.0.m(),
             frequency: 500,
             continuous: false,
+            duty_cycle: 200,
             gain: 2,
             av_supply_voltage: 12.0,
         }
@@ -0,0 +1,173 @@
+use crate::{
+    controller::object_id,
+    utils::{HwVersion, SwVersion},
+    CanParticipant,
+};
+
+/// Number of bus participants kept in the table
+pub const MAX_BUS_DEVICES: usize = 16;
+
+/// One participant of the CAN bus
+#[derive(Clone, Copy)]
+pub struct BusDevice {
+    pub vda: u16,
+    pub object_id: u16,
+    pub age: u8,         // seconds since the last heartbeat
+    pub frame_rate: u16, // frames per second
+    pub hw_version: Option<HwVersion>,
+    pub sw_version: Option<SwVersion>,
+}
+
+impl BusDevice {
+    /// Kind of device derived from the object id of the heartbeat
+    pub fn kind(&self) -> &'static str {
+        match self.object_id {
+            object_id::CONFIG => "Config",
+            object_id::SENSOR => "Sensor",
+            object_id::GPS => "GPS",
+            object_id::FRONTEND => "Display",
+            object_id::REMOTE => "Remote",
+            object_id::ENGINE => "Engine",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Participants of the CAN bus
+///
+/// The table is a copy of the participant list of the CAN dispatcher, which identifies all
+/// devices by their heartbeats. It is refreshed by the device driver. The versions are
+/// completed by the application when a device sends its hardware and firmware version.
+#[derive(Clone, Copy)]
+pub struct CanBus {
+    vda: u16,
+    devices: [Option<BusDevice>; MAX_BUS_DEVICES],
+}
+
+impl Default for CanBus {
+    fn default() -> Self {
+        CanBus {
+            vda: 0,
+            devices: [None; MAX_BUS_DEVICES],
+        }
+    }
+}
+
+impl CanBus {
+    /// Replace the table by the participants of the CAN dispatcher
+    ///
+    /// Known versions are kept as long as the device stays on the same address. vda is the own
+    /// address of this device.
+    pub fn update(&mut self, vda: u16, participants: impl Iterator<Item = CanParticipant>) {
+        self.vda = vda;
+        let mut devices = [None; MAX_BUS_DEVICES];
+        for (entry, participant) in devices.iter_mut().zip(participants) {
+            let known = self
+                .devices()
+                .find(|d| d.vda == participant.vda && d.object_id == participant.object_id);
+            *entry = Some(BusDevice {
+                vda: participant.vda,
+                object_id: participant.object_id,
+                age: participant.age,
+                frame_rate: participant.frame_rate,
+                hw_version: known.and_then(|d| d.hw_version),
+                sw_version: known.and_then(|d| d.sw_version),
+            });
+        }
+        self.devices = devices;
+    }
+
+    /// Store the versions reported by the device with the given address
+    pub fn set_versions(&mut self, vda: u16, hw_version: HwVersion, sw_version: SwVersion) {
+        for device in self.devices.iter_mut().flatten() {
+            if device.vda == vda {
+                device.hw_version = Some(hw_version);
+                device.sw_version = Some(sw_version);
+            }
+        }
+    }
+
+    /// Address of the other frontend of a two-seater
+    pub fn sync_partner(&self) -> Option<u16> {
+        self.devices()
+            .find(|d| d.object_id == object_id::FRONTEND && d.vda != self.vda)
+            .map(|d| d.vda)
+    }
+
+    /// The frontend with the lowest address is the master of a two-seater
+    pub fn is_sync_master(&self) -> bool {
+        !self
+            .devices()
+            .any(|d| d.object_id == object_id::FRONTEND && d.vda < self.vda)
+    }
+
+    /// Iterate over all participants
+    pub fn devices(&self) -> impl Iterator<Item = &BusDevice> {
+        self.devices.iter().flatten()
+    }
+
+    pub fn device(&self, idx: usize) -> Option<&BusDevice> {
+        self.devices().nth(idx)
+    }
+
+    pub fn len(&self) -> usize {
+        self.devices().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn participant(vda: u16, object_id: u16) -> CanParticipant {
+        CanParticipant {
+            vda,
+            object_id,
+            age: 1,
+            frame_rate: 42,
+        }
+    }
+
+    #[test]
+    fn test_can_bus() {
+        let mut can_bus = CanBus::default();
+        assert!(can_bus.is_empty());
+
+        can_bus.update(40, [participant(2, 2), participant(5, 4)].into_iter());
+        assert_eq!(can_bus.len(), 2);
+        assert_eq!(can_bus.device(1).unwrap().kind(), "Display");
+
+        // the versions survive a refresh, but not a change of the device
+        let sw_version = SwVersion {
+            version: [0, 3, 8, 1],
+        };
+        can_bus.set_versions(2, HwVersion::from_bytes([1, 3, 1, 0]), sw_version);
+        can_bus.update(40, [participant(2, 2), participant(5, 2)].into_iter());
+        assert!(can_bus.device(0).unwrap().sw_version == Some(sw_version));
+        assert!(can_bus.device(1).unwrap().sw_version.is_none());
+
+        can_bus.update(40, [participant(5, 2)].into_iter());
+        assert_eq!(can_bus.len(), 1);
+        assert_eq!(can_bus.device(0).unwrap().kind(), "Sensor");
+    }
+
+    #[test]
+    fn test_sync_partner() {
+        let mut can_bus = CanBus::default();
+        can_bus.update(40, [participant(2, 2), participant(40, 4)].into_iter());
+        assert!(can_bus.sync_partner().is_none());
+        assert!(can_bus.is_sync_master());
+
+        can_bus.update(40, [participant(40, 4), participant(41, 4)].into_iter());
+        assert_eq!(can_bus.sync_partner(), Some(41));
+        assert!(can_bus.is_sync_master());
+
+        can_bus.update(41, [participant(40, 4), participant(41, 4)].into_iter());
+        assert_eq!(can_bus.sync_partner(), Some(40));
+        assert!(!can_bus.is_sync_master());
+    }
+}
@@ -1,11 +1,13 @@
 use num_enum::FromPrimitive;
 use core::convert::From;
 use crate::{
-    system_of_units::{FloatToSpeed, Speed},
+    system_of_units::{FloatToLength, FloatToSpeed, Length, Speed},
     view::viewable::{centerview::CenterView, lineview::LineView},
     Palette,
 };
 
+use super::{CustomTone, TonePreset, Units};
+
 /// Possible displays
 #[derive(Clone, Copy, PartialEq, FromPrimitive)]
 #[repr(u8)]
@@ -15,6 +17,8 @@ pub enum DisplayActive {
     Horizon,
     Menu,
     FirmwareUpdate,
+    Engine,
+    QnhDialog,
 }
 
 pub const HORIZON: &str = "Horizon";
@@ -22,6 +26,7 @@ pub const HORIZON: &str = "Horizon";
 pub const VARIO: &str = "Vario";
 pub const FIRMWARE_UPDATE: &str = "Firmware Update";
 pub const MENU: &str = "Menu";
+pub const ENGINE: &str = "Engine";
 
 impl From<&str> for DisplayActive {
     fn from(value: &str) -> Self {
@@ -29,6 +34,7 @@ impl From<&str> for DisplayActive {
             HORIZON => DisplayActive::Horizon,
             FIRMWARE_UPDATE => DisplayActive::FirmwareUpdate,
             MENU => DisplayActive::Menu,
+            ENGINE => DisplayActive::Engine,
             _ => DisplayActive::Vario,
         }
     }
@@ -47,6 +53,11 @@ pub enum TypeOfInfo {
     None,
     WaterBallast,
     GearAlarm,
+    Icing,
+    Engine,
+    LowBattery,
+    Stall,
+    Overspeed,
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -71,7 +82,9 @@ pub struct Config {
     pub snd_min_freq: f32,
     pub snd_center_freq: f32,
     pub snd_max_freq: f32,
-    pub snd_exp_mul: f32,
+    // replaces snd_exp_mul, which was never stored, its tone is the default preset LarusClassic
+    pub tone_preset: TonePreset,
+    pub custom_tone: CustomTone,
     pub snd_duty_cycle: u16, // Oscillations, symetric on/off
     pub av2_climb_rate_tc: f32,
     pub av_speed_to_fly_tc: f32,
@@ -88,6 +101,9 @@ pub struct Config {
     pub battery_low: f32,
     pub stf_upper_limit: Speed,
     pub stf_lower_limit: Speed,
+    pub stall_margin: f32, // % above the stall speed
+    pub field_elevation: Length, // entered by the pilot for the automatic QNH
+    pub units: Units,
 }
 
 impl Config {
@@ -105,7 +121,8 @@ impl Config {
             snd_min_freq: 233.0,    // -7,5
             snd_center_freq: 659.0, // e2
             snd_max_freq: 1864.0,   // +7,5
-            snd_exp_mul: 0.138629,  // -5 .. 5 two octaves
+            tone_preset: TonePreset::LarusClassic,
+            custom_tone: CustomTone::default(),
             snd_duty_cycle: 200,
             av2_climb_rate_tc: 30.0,
             av_speed_to_fly_tc: 5.0,
@@ -122,6 +139,9 @@ impl Config {
             battery_low: 10.0,
             stf_upper_limit: 10.0.km_h(),
             stf_lower_limit: -10.0.km_h(),
+            stall_margin: 10.0,
+            field_elevation: 0.0.m(),
+            units: Units::default(),
         }
     }
 }
@@ -1,7 +1,7 @@
 use crate::{
     controller::{CanActive, Editor},
     system_of_units::{FloatToLength, FloatToSpeed, Length, Speed},
-    utils::DeviceEvent,
+    utils::{DeviceEvent, SwVersion},
     MenuControl, Rotation,
 };
 
@@ -23,7 +23,7 @@ pub enum FlyMode {
 /// optimize climbing in thermals is displayed. SppedToFly, on the other hand, is intended
 /// for optimal pre-flight.
 #[repr(u8)]
-#[derive(PartialEq, Clone, Copy)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum VarioMode {
     Vario,
     SpeedToFly,
@@ -39,6 +39,16 @@ impl From<u8> for VarioMode {
     }
 }
 
+impl From<u8> for FlyMode {
+    fn from(value: u8) -> Self {
+        if value == 0 {
+            FlyMode::Circling
+        } else {
+            FlyMode::StraightFlight
+        }
+    }
+}
+
 impl core::ops::Not for VarioMode {
     type Output = Self;
 
@@ -136,6 +146,192 @@ impl DataSource {
     }
 }
 
+/// Source of the climb rate for needle, audio and NMEA output
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum VarioSource {
+    TotalEnergy,
+    Netto,
+    Relative,
+}
+
+pub const VARIO_SOURCE_TOTAL_ENERGY: &str = "Total Energy";
+pub const VARIO_SOURCE_NETTO: &str = "Netto";
+pub const VARIO_SOURCE_RELATIVE: &str = "Relative";
+
+impl From<u8> for VarioSource {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => VarioSource::Netto,
+            2 => VarioSource::Relative,
+            _ => VarioSource::TotalEnergy,
+        }
+    }
+}
+
+impl From<&str> for VarioSource {
+    fn from(value: &str) -> Self {
+        match value {
+            VARIO_SOURCE_NETTO => VarioSource::Netto,
+            VARIO_SOURCE_RELATIVE => VarioSource::Relative,
+            _ => VarioSource::TotalEnergy,
+        }
+    }
+}
+
+impl VarioSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VarioSource::TotalEnergy => VARIO_SOURCE_TOTAL_ENERGY,
+            VarioSource::Netto => VARIO_SOURCE_NETTO,
+            VarioSource::Relative => VARIO_SOURCE_RELATIVE,
+        }
+    }
+
+    /// Short label to be shown next to the vario scale
+    pub fn label(&self) -> &'static str {
+        match self {
+            VarioSource::TotalEnergy => "TE",
+            VarioSource::Netto => "NET",
+            VarioSource::Relative => "REL",
+        }
+    }
+}
+
+/// Automatic MacCready value derived from the recent thermals
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum AutoMcMode {
+    Off,
+    Suggest,
+    Auto,
+}
+
+pub const AUTO_MC_OFF: &str = "Off";
+pub const AUTO_MC_SUGGEST: &str = "Suggest";
+pub const AUTO_MC_AUTO: &str = "Auto";
+
+impl From<u8> for AutoMcMode {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => AutoMcMode::Suggest,
+            2 => AutoMcMode::Auto,
+            _ => AutoMcMode::Off,
+        }
+    }
+}
+
+impl From<&str> for AutoMcMode {
+    fn from(value: &str) -> Self {
+        match value {
+            AUTO_MC_SUGGEST => AutoMcMode::Suggest,
+            AUTO_MC_AUTO => AutoMcMode::Auto,
+            _ => AutoMcMode::Off,
+        }
+    }
+}
+
+impl AutoMcMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutoMcMode::Off => AUTO_MC_OFF,
+            AutoMcMode::Suggest => AUTO_MC_SUGGEST,
+            AutoMcMode::Auto => AUTO_MC_AUTO,
+        }
+    }
+}
+
+/// Source of the field elevation for the automatic QNH at startup
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum AutoQnhMode {
+    Off,
+    Gnss,
+    Elevation,
+}
+
+pub const AUTO_QNH_OFF: &str = "Off";
+pub const AUTO_QNH_GNSS: &str = "GNSS";
+pub const AUTO_QNH_ELEVATION: &str = "Elevation";
+
+impl From<u8> for AutoQnhMode {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => AutoQnhMode::Gnss,
+            2 => AutoQnhMode::Elevation,
+            _ => AutoQnhMode::Off,
+        }
+    }
+}
+
+impl From<&str> for AutoQnhMode {
+    fn from(value: &str) -> Self {
+        match value {
+            AUTO_QNH_GNSS => AutoQnhMode::Gnss,
+            AUTO_QNH_ELEVATION => AutoQnhMode::Elevation,
+            _ => AutoQnhMode::Off,
+        }
+    }
+}
+
+impl AutoQnhMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutoQnhMode::Off => AUTO_QNH_OFF,
+            AutoQnhMode::Gnss => AUTO_QNH_GNSS,
+            AutoQnhMode::Elevation => AUTO_QNH_ELEVATION,
+        }
+    }
+}
+
+/// Set of NMEA sentences sent to the flight computer
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum NmeaProfile {
+    Larus,
+    Lxnav,
+    OpenVario,
+    Borgelt,
+}
+
+pub const NMEA_PROFILE_LARUS: &str = "Larus";
+pub const NMEA_PROFILE_LXNAV: &str = "LXNAV";
+pub const NMEA_PROFILE_OPEN_VARIO: &str = "OpenVario";
+pub const NMEA_PROFILE_BORGELT: &str = "Borgelt B50";
+
+impl From<u8> for NmeaProfile {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => NmeaProfile::Lxnav,
+            2 => NmeaProfile::OpenVario,
+            3 => NmeaProfile::Borgelt,
+            _ => NmeaProfile::Larus,
+        }
+    }
+}
+
+impl From<&str> for NmeaProfile {
+    fn from(value: &str) -> Self {
+        match value {
+            NMEA_PROFILE_LXNAV => NmeaProfile::Lxnav,
+            NMEA_PROFILE_OPEN_VARIO => NmeaProfile::OpenVario,
+            NMEA_PROFILE_BORGELT => NmeaProfile::Borgelt,
+            _ => NmeaProfile::Larus,
+        }
+    }
+}
+
+impl NmeaProfile {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NmeaProfile::Larus => NMEA_PROFILE_LARUS,
+            NmeaProfile::Lxnav => NMEA_PROFILE_LXNAV,
+            NmeaProfile::OpenVario => NMEA_PROFILE_OPEN_VARIO,
+            NmeaProfile::Borgelt => NMEA_PROFILE_BORGELT,
+        }
+    }
+}
+
 /// Enum mode controls whether the background should be visible or not when editing a data
 /// point.
 #[repr(u8)]
@@ -163,6 +359,17 @@ pub enum SystemState {
     CanAndGpsOk,
 }
 
+/// State of the sensorbox update shown on the firmware update page
+#[derive(Clone, Copy, PartialEq)]
+pub enum SensorboxUpdate {
+    /// A newer image was found, the pilot has to confirm the update
+    Offered(SwVersion),
+    /// Progress of the transfer in percent
+    Running(u8),
+    /// The transfer failed, shown until the pilot acknowledges it
+    Failed,
+}
+
 /// Metastructure for different control variables
 #[derive(Clone, Copy)]
 pub struct Control {
@@ -170,6 +377,8 @@ pub struct Control {
     pub alive_ticks: u32,
     /// Count ticks average climb rates slave mode is active (1s)
     pub avg_climb_slave_ticks: u16,
+    /// Count ticks the flight state is mirrored from the master frontend (1s)
+    pub sync_slave_ticks: u16,
     /// State of the Larus system
     pub system_state: SystemState,
     /// Bit pattern of all can bus devices
@@ -188,6 +397,8 @@ pub struct Control {
     pub pers_ticks: u32,
     /// DeviceEvent::FwAvailable, PrepareFwUpload, ...
     pub firmware_update_state: DeviceEvent,
+    /// Sensorbox update, None if no update is offered or running
+    pub sensorbox_update: Option<SensorboxUpdate>,
     /// TcrMode::StraightFlight, TcrMode::Transition, TcrMode::Climbing
     pub tcr_mode: TcrMode,
     /// Measurement of time climbing
@@ -208,8 +419,32 @@ pub struct Control {
     pub alarm_volume: i8,
     /// Source average climb rate
     pub avg_climb_rate_src: DataSource,
+    /// VarioSource::TotalEnergy, VarioSource::Netto, VarioSource::Relative
+    pub vario_source: VarioSource,
     /// Energy arrow multiplying factor
     pub energy_arrow_mult: f32,
+    /// AutoMcMode::Off, AutoMcMode::Suggest, AutoMcMode::Auto
+    pub auto_mc_mode: AutoMcMode,
+    /// Count ticks the outside air temperature is valid (1s)
+    pub oat_ticks: u16,
+    /// Count ticks the humidity is valid (1s)
+    pub humidity_ticks: u16,
+    /// Sentences sent to the flight computer
+    pub nmea_profile: NmeaProfile,
+    /// Count ticks the airspeed of the sensorbox is valid (1s)
+    pub airspeed_ticks: u16,
+    /// Flap setting selected by the pilot, None if the flap sensor is used
+    pub flap_select: Option<u8>,
+    /// Source of the wind, the circling wind replaces a missing sensorbox wind
+    pub wind_src: DataSource,
+    /// Count ticks the wind of the sensorbox is valid (1s)
+    pub sensor_wind_ticks: u16,
+    /// Count ticks the turn rate of the sensorbox is valid (1s)
+    pub turn_rate_ticks: u16,
+    /// Count ticks the static pressure of the sensorbox is valid (1s)
+    pub pressure_ticks: u16,
+    /// AutoQnhMode::Off, AutoQnhMode::Gnss, AutoQnhMode::Elevation
+    pub auto_qnh_mode: AutoQnhMode,
 }
 
 impl Default for Control {
@@ -217,6 +452,7 @@ impl Default for Control {
         Self {
             alive_ticks: 0,
             avg_climb_slave_ticks: 0,
+            sync_slave_ticks: 0,
             system_state: SystemState::NoCom,
             can_devices: CanActive::None as u32,
             fly_mode: FlyMode::StraightFlight,
@@ -226,6 +462,7 @@ impl Default for Control {
             speed_to_fly_limit: 105.0.km_h(),
             pers_ticks: 0,
             firmware_update_state: DeviceEvent::UploadFinished,
+            sensorbox_update: None,
             tcr_mode: TcrMode::StraightFlight,
             tcr_1s_climb_ticks: 0,
             tcr_1s_transient_ticks: 0,
@@ -236,7 +473,19 @@ impl Default for Control {
             reset_config: 0,
             alarm_volume: 15,
             avg_climb_rate_src: DataSource::Frontend,
+            vario_source: VarioSource::TotalEnergy,
             energy_arrow_mult: 0.0,
+            auto_mc_mode: AutoMcMode::Off,
+            oat_ticks: 0,
+            humidity_ticks: 0,
+            nmea_profile: NmeaProfile::Larus,
+            airspeed_ticks: 0,
+            flap_select: None,
+            wind_src: DataSource::Sensorbox,
+            sensor_wind_ticks: 0,
+            turn_rate_ticks: 0,
+            pressure_ticks: 0,
+            auto_qnh_mode: AutoQnhMode::Off,
         }
     }
 }
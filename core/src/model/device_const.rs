@@ -76,6 +76,8 @@ pub struct VarioSizes {
     pub wind_len: i32,
     pub wind_len_min: i32,
     pub angle_m_s: f32,
+    pub scale_label_radius: u32, // distance of the scale numbers from the center
+    pub scale_label_diameter: u32,
 }
 
 pub struct Images {
@@ -0,0 +1,88 @@
+use super::{Content, EditableFuncs, ListParams, Params};
+use crate::{model::BusDevice, tformat, CoreController, CoreModel};
+use tfmt::Convert;
+
+const NO_DEVICES: &str = "No devices";
+
+fn list_params(cm: &CoreModel) -> Params {
+    Params::List(ListParams {
+        max: cm.can_bus.len().saturating_sub(1) as i32,
+    })
+}
+
+// The address is shown in each line, so both lists can be assigned to each other
+fn write_device(
+    cm: &CoreModel,
+    convert: &mut Convert<20>,
+    idx: i32,
+    details: fn(&BusDevice) -> Option<heapless::String<20>>,
+) {
+    match cm.can_bus.device(idx as usize) {
+        Some(device) => {
+            let s = details(device)
+                .or_else(|| tformat!(20, "{:02} {}", device.vda, device.kind()).ok())
+                .unwrap();
+            convert.write_str(s.as_str()).unwrap()
+        }
+        None => convert.write_str(NO_DEVICES).unwrap(),
+    }
+}
+
+pub struct BusDevices;
+impl EditableFuncs for BusDevices {
+    fn name() -> &'static str {
+        "Devices"
+    }
+
+    fn content(_cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::List(0)
+    }
+
+    fn content_as_str(cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
+        write_device(cm, convert, idx, |device| match device.sw_version {
+            Some(v) => tformat!(
+                20,
+                "{:02} {} {}.{}.{}",
+                device.vda,
+                device.kind(),
+                v.major(),
+                v.minor(),
+                v.patch()
+            )
+            .ok(),
+            None => None,
+        })
+    }
+
+    fn params(cm: &CoreModel) -> Params {
+        list_params(cm)
+    }
+}
+
+pub struct BusTraffic;
+impl EditableFuncs for BusTraffic {
+    fn name() -> &'static str {
+        "Traffic"
+    }
+
+    fn content(_cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::List(0)
+    }
+
+    fn content_as_str(cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
+        write_device(cm, convert, idx, |device| {
+            tformat!(
+                20,
+                "{:02} {}s {}/s",
+                device.vda,
+                device.age,
+                device.frame_rate
+            )
+            .ok()
+        })
+    }
+
+    fn params(cm: &CoreModel) -> Params {
+        list_params(cm)
+    }
+}
@@ -1,16 +1,20 @@
-use super::{Content, EditableFuncs, EnumParams, F32Params, Params};
+use super::{Content, EditableFuncs, EnumParams, F32Params, ListParams, Params};
 use crate::{
-    controller::{GearPins, InPinFunction, InTogglePinFunction, OutPinFunction},
+    controller::{GearPins, InPinFunction, InTogglePinFunction, OutPinFunction, RemoteAction},
     controller::{
         ONE_PIN_MODE, PIN_IN_CLOSE, PIN_IN_OPEN, PIN_IN_TOGGLE, PIN_NONE, PIN_OUT_CLOSE,
         PIN_OUT_OPEN, TWO_PIN_MODE,
     },
     model::control::{VARIO_MODE_CONTROL_AUTO, VARIO_MODE_CONTROL_CAN, VARIO_MODE_CONTROL_NMEA, VARIO_MODE_CONTROL_PIN},
-    model::VarioModeControl,
+    model::control::{
+        NMEA_PROFILE_BORGELT, NMEA_PROFILE_LARUS, NMEA_PROFILE_LXNAV, NMEA_PROFILE_OPEN_VARIO,
+    },
+    model::{NmeaProfile, VarioModeControl},
     persist,
     utils::{TString, Variant},
     CoreController, CoreModel, Echo, PersistenceId, Rotation,
 };
+use tfmt::Convert;
 
 pub struct DrainPinConfig;
 const PIN_PARAMS: Params = Params::Enum(EnumParams {
@@ -28,7 +32,7 @@ impl EditableFuncs for DrainPinConfig {
         ))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         PIN_PARAMS
     }
 
@@ -63,7 +67,7 @@ impl EditableFuncs for FactoryReset {
         Content::Enum(TString::<16>::from_str(s))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
             variants: [DO_NOT_CHANGE, FACTORY_RESET, DO_NOT_CHANGE_2, "", ""],
         })
@@ -96,7 +100,7 @@ impl EditableFuncs for EnergyArrowMult {
         Content::F32(Some(cm.control.energy_arrow_mult))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::F32(F32Params {
             min: 0.0,
             max: 10.0,
@@ -132,7 +136,7 @@ impl EditableFuncs for FlashControl {
         ))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
             variants: [PIN_NONE, PIN_OUT_CLOSE, PIN_OUT_OPEN, "", ""],
         })
@@ -161,7 +165,7 @@ impl EditableFuncs for FlowEmpty {
         Content::F32(Some(cc.drain_control.flow_rate_offset))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::F32(F32Params {
             min: 1.0,
             max: 200.0,
@@ -195,7 +199,7 @@ impl EditableFuncs for FlowSlope {
         Content::F32(Some(cc.drain_control.flow_rate_slope))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::F32(F32Params {
             min: -1.0,
             max: 1.0,
@@ -231,7 +235,7 @@ impl EditableFuncs for GearPinConfig {
         ))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         PIN_PARAMS
     }
 
@@ -260,7 +264,7 @@ impl EditableFuncs for AirbrakesPinConfig {
         ))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         PIN_PARAMS
     }
 
@@ -289,7 +293,7 @@ impl EditableFuncs for GearAlarmModeConfig {
         ))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
             variants: [ONE_PIN_MODE, TWO_PIN_MODE, "", "", ""],
         })
@@ -308,6 +312,42 @@ impl EditableFuncs for GearAlarmModeConfig {
     }
 }
 
+pub struct NmeaProfile_;
+impl EditableFuncs for NmeaProfile_ {
+    fn name() -> &'static str {
+        "NMEA Output"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.control.nmea_profile.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [
+                NMEA_PROFILE_LARUS,
+                NMEA_PROFILE_LXNAV,
+                NMEA_PROFILE_OPEN_VARIO,
+                NMEA_PROFILE_BORGELT,
+                "",
+            ],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let profile = NmeaProfile::from(val.as_str());
+            persist::persist_set(
+                cc,
+                cm,
+                Variant::U8(profile as u8),
+                PersistenceId::NmeaProfile,
+                Echo::None,
+            );
+        }
+    }
+}
+
 pub struct ResetConfig;
 const DEFAULT_CONFIG: &str = "Default Config";
 
@@ -324,7 +364,7 @@ impl EditableFuncs for ResetConfig {
         Content::Enum(TString::<16>::from_str(s))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
             variants: [DEFAULT_CONFIG, DO_NOT_CHANGE, "", "", ""],
         })
@@ -357,7 +397,7 @@ impl EditableFuncs for Rotation_ {
         Content::Enum(TString::<16>::from_str(cm.control.rotation.name()))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
             variants: [
                 Rotation::Rotate0.name(),
@@ -394,7 +434,7 @@ impl EditableFuncs for SpeedToFlyPinConfig {
         ))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
             variants: [PIN_NONE, PIN_IN_CLOSE, PIN_IN_OPEN, PIN_IN_TOGGLE, ""],
         })
@@ -413,6 +453,123 @@ impl EditableFuncs for SpeedToFlyPinConfig {
     }
 }
 
+fn remote_button_content(cc: &CoreController, button: u8) -> Content {
+    Content::List(cc.remote_control.action(button) as i32)
+}
+
+fn remote_button_as_str(convert: &mut Convert<20>, idx: i32) {
+    convert
+        .write_str(RemoteAction::from(idx as u8).as_str())
+        .unwrap()
+}
+
+const REMOTE_BUTTON_PARAMS: Params = Params::List(ListParams {
+    max: RemoteAction::COUNT as i32 - 1,
+});
+
+fn remote_button_set_content(
+    cm: &mut CoreModel,
+    cc: &mut CoreController,
+    content: Content,
+    id: PersistenceId,
+) {
+    if let Content::List(idx) = content {
+        persist::persist_set(cc, cm, Variant::U8(idx as u8), id, Echo::None)
+    }
+}
+
+pub struct RemoteButton1;
+impl EditableFuncs for RemoteButton1 {
+    fn name() -> &'static str {
+        "Remote Button 1"
+    }
+
+    fn content(_cm: &mut CoreModel, cc: &mut CoreController) -> Content {
+        remote_button_content(cc, 0)
+    }
+
+    fn content_as_str(_cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
+        remote_button_as_str(convert, idx)
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        REMOTE_BUTTON_PARAMS
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        remote_button_set_content(cm, cc, content, PersistenceId::RemoteButton1)
+    }
+}
+
+pub struct RemoteButton2;
+impl EditableFuncs for RemoteButton2 {
+    fn name() -> &'static str {
+        "Remote Button 2"
+    }
+
+    fn content(_cm: &mut CoreModel, cc: &mut CoreController) -> Content {
+        remote_button_content(cc, 1)
+    }
+
+    fn content_as_str(_cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
+        remote_button_as_str(convert, idx)
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        REMOTE_BUTTON_PARAMS
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        remote_button_set_content(cm, cc, content, PersistenceId::RemoteButton2)
+    }
+}
+
+pub struct RemoteButton3;
+impl EditableFuncs for RemoteButton3 {
+    fn name() -> &'static str {
+        "Remote Button 3"
+    }
+
+    fn content(_cm: &mut CoreModel, cc: &mut CoreController) -> Content {
+        remote_button_content(cc, 2)
+    }
+
+    fn content_as_str(_cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
+        remote_button_as_str(convert, idx)
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        REMOTE_BUTTON_PARAMS
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        remote_button_set_content(cm, cc, content, PersistenceId::RemoteButton3)
+    }
+}
+
+pub struct RemoteButton4;
+impl EditableFuncs for RemoteButton4 {
+    fn name() -> &'static str {
+        "Remote Button 4"
+    }
+
+    fn content(_cm: &mut CoreModel, cc: &mut CoreController) -> Content {
+        remote_button_content(cc, 3)
+    }
+
+    fn content_as_str(_cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
+        remote_button_as_str(convert, idx)
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        REMOTE_BUTTON_PARAMS
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        remote_button_set_content(cm, cc, content, PersistenceId::RemoteButton4)
+    }
+}
+
 pub struct UserProfile;
 const USER_1: &str = "User 1";
 const USER_2: &str = "User 2";
@@ -435,7 +592,7 @@ impl EditableFuncs for UserProfile {
         Content::Enum(TString::<16>::from_str(s))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
             variants: [USER_1, USER_2, USER_3, USER_4, DO_NOT_CHANGE],
         })
@@ -469,7 +626,7 @@ impl EditableFuncs for VarioModeControl_ {
         ))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
             variants: [
                 VARIO_MODE_CONTROL_AUTO,
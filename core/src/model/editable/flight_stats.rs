@@ -0,0 +1,180 @@
+use super::{Content, EditableFuncs, ListParams, Params};
+use crate::{tformat, CoreController, CoreModel};
+use tfmt::Convert;
+
+// The values are shown as a list with only one entry, so they cannot be changed
+const READ_ONLY_PARAMS: Params = Params::List(ListParams { max: 0 });
+const NO_DATA: &str = "--";
+
+pub struct Thermals;
+impl EditableFuncs for Thermals {
+    fn name() -> &'static str {
+        "Thermals"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        // start with the last thermal
+        Content::List(cm.flight_stats.len().saturating_sub(1) as i32)
+    }
+
+    fn content_as_str(cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
+        match cm.flight_stats.thermal(idx as usize) {
+            Some(thermal) => {
+                let altitude = cm.config.units.altitude;
+                let vertical_speed = cm.config.units.vertical_speed;
+                let s = tformat!(
+                    20,
+                    "{:02}:{:02} {:.0}{} {:.1}{}",
+                    thermal.entry.hour(),
+                    thermal.entry.min(),
+                    altitude.value(thermal.altitude_gain),
+                    altitude.as_str(),
+                    vertical_speed.value(thermal.avg_climb_rate()),
+                    vertical_speed.as_str()
+                )
+                .unwrap();
+                convert.write_str(s.as_str()).unwrap()
+            }
+            None => convert.write_str("No thermals").unwrap(),
+        }
+    }
+
+    fn params(cm: &CoreModel) -> Params {
+        Params::List(ListParams {
+            max: cm.flight_stats.len().saturating_sub(1) as i32,
+        })
+    }
+}
+
+pub struct AvgThermal;
+impl EditableFuncs for AvgThermal {
+    fn name() -> &'static str {
+        "Avg Thermal"
+    }
+
+    fn content(_cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::List(0)
+    }
+
+    fn content_as_str(cm: &CoreModel, convert: &mut Convert<20>, _idx: i32) {
+        match cm.flight_stats.avg_climb_rate() {
+            Some(climb_rate) => {
+                let unit = cm.config.units.vertical_speed;
+                let s = tformat!(20, "{:.1} {}", unit.value(climb_rate), unit.as_str()).unwrap();
+                convert.write_str(s.as_str()).unwrap()
+            }
+            None => convert.write_str(NO_DATA).unwrap(),
+        }
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        READ_ONLY_PARAMS
+    }
+}
+
+pub struct CirclingPercent;
+impl EditableFuncs for CirclingPercent {
+    fn name() -> &'static str {
+        "Circling"
+    }
+
+    fn content(_cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::List(0)
+    }
+
+    fn content_as_str(cm: &CoreModel, convert: &mut Convert<20>, _idx: i32) {
+        match cm.flight_stats.circling_percent() {
+            Some(percent) => {
+                let s = tformat!(20, "{:.0} %", percent).unwrap();
+                convert.write_str(s.as_str()).unwrap()
+            }
+            None => convert.write_str(NO_DATA).unwrap(),
+        }
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        READ_ONLY_PARAMS
+    }
+}
+
+pub struct CruiseSpeed;
+impl EditableFuncs for CruiseSpeed {
+    fn name() -> &'static str {
+        "Cruise Speed"
+    }
+
+    fn content(_cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::List(0)
+    }
+
+    fn content_as_str(cm: &CoreModel, convert: &mut Convert<20>, _idx: i32) {
+        match cm.flight_stats.cruise_speed() {
+            Some(speed) => {
+                let unit = cm.config.units.speed;
+                let s = tformat!(20, "{:.0} {}", unit.value(speed), unit.as_str()).unwrap();
+                convert.write_str(s.as_str()).unwrap()
+            }
+            None => convert.write_str(NO_DATA).unwrap(),
+        }
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        READ_ONLY_PARAMS
+    }
+}
+
+pub struct GlideRatio;
+impl EditableFuncs for GlideRatio {
+    fn name() -> &'static str {
+        "Glide Ratio"
+    }
+
+    fn content(_cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::List(0)
+    }
+
+    fn content_as_str(cm: &CoreModel, convert: &mut Convert<20>, _idx: i32) {
+        match cm.flight_stats.glide_ratio() {
+            Some(ratio) => {
+                let s = tformat!(20, "1:{:.0}", ratio).unwrap();
+                convert.write_str(s.as_str()).unwrap()
+            }
+            None => convert.write_str(NO_DATA).unwrap(),
+        }
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        READ_ONLY_PARAMS
+    }
+}
+
+pub struct EngineTime;
+impl EditableFuncs for EngineTime {
+    fn name() -> &'static str {
+        "Engine Time"
+    }
+
+    fn content(_cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::List(0)
+    }
+
+    fn content_as_str(cm: &CoreModel, convert: &mut Convert<20>, _idx: i32) {
+        // run time since switching on and total run time in h:mm
+        let secs = cm.flight_stats.engine_secs();
+        let total = cm.flight_stats.engine_total_secs();
+        let s = tformat!(
+            20,
+            "{}:{:02} / {}:{:02} h",
+            secs / 3600,
+            secs / 60 % 60,
+            total / 3600,
+            total / 60 % 60
+        )
+        .unwrap();
+        convert.write_str(s.as_str()).unwrap()
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        READ_ONLY_PARAMS
+    }
+}
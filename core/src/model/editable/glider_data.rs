@@ -1,5 +1,10 @@
-use super::{Content, EditableFuncs, F32Params, Params};
-use crate::{persist, utils::Variant, CoreController, CoreModel, Echo, FloatToMass, PersistenceId};
+use super::{Content, EditableFuncs, F32Params, ListParams, Params};
+use tfmt::Convert;
+
+use crate::{
+    flight_physics::MAX_FLAP_POLARS, model::VerticalSpeedUnit, persist, utils::Variant,
+    CoreController, CoreModel, Echo, FloatToMass, FloatToSpeed, PersistenceId,
+};
 
 pub struct Bugs;
 impl EditableFuncs for Bugs {
@@ -11,7 +16,7 @@ impl EditableFuncs for Bugs {
         Content::F32(Some((cm.glider_data.bugs - 1.0) * 100.0))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::F32(F32Params {
             min: 0.0,
             max: 100.0,
@@ -42,17 +47,18 @@ impl EditableFuncs for PilotWeight {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.glider_data.pilot_weight.to_kg()))
+        Content::F32(Some(cm.config.units.mass.value(cm.glider_data.pilot_weight)))
     }
 
-    fn params() -> Params {
+    fn params(cm: &CoreModel) -> Params {
+        let unit = cm.config.units.mass;
         Params::F32(F32Params {
             min: 0.0,
-            max: 250.0,
+            max: unit.value(250.0.kg()),
             small_inc: 1.0,
             big_inc: 10.0,
             dec_places: 0,
-            unit: "kg",
+            unit: unit.as_str(),
         })
     }
 
@@ -61,7 +67,7 @@ impl EditableFuncs for PilotWeight {
             persist::persist_set(
                 cc,
                 cm,
-                Variant::Mass(val.kg()),
+                Variant::Mass(cm.config.units.mass.mass(val)),
                 PersistenceId::PilotWeight,
                 Echo::NmeaAndCan,
             )
@@ -76,17 +82,18 @@ impl EditableFuncs for WaterBallast {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.glider_data.water_ballast.to_kg()))
+        Content::F32(Some(cm.config.units.mass.value(cm.glider_data.water_ballast)))
     }
 
-    fn params() -> Params {
+    fn params(cm: &CoreModel) -> Params {
+        let unit = cm.config.units.mass;
         Params::F32(F32Params {
             min: 0.0,
-            max: 250.0,
+            max: unit.value(250.0.kg()),
             small_inc: 1.0,
             big_inc: 10.0,
             dec_places: 0,
-            unit: "kg",
+            unit: unit.as_str(),
         })
     }
 
@@ -95,7 +102,7 @@ impl EditableFuncs for WaterBallast {
             persist::persist_set(
                 cc,
                 cm,
-                Variant::Mass(val.kg()),
+                Variant::Mass(cm.config.units.mass.mass(val)),
                 PersistenceId::WaterBallast,
                 Echo::NmeaAndCan,
             )
@@ -110,17 +117,20 @@ impl EditableFuncs for EmptyMass {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.glider_data.basic_glider_data.empty_mass))
+        Content::F32(Some(
+            cm.config.units.mass.value(cm.glider_data.basic_glider_data.empty_mass.kg()),
+        ))
     }
 
-    fn params() -> Params {
+    fn params(cm: &CoreModel) -> Params {
+        let unit = cm.config.units.mass;
         Params::F32(F32Params {
-            min: 100.0,
-            max: 850.0,
+            min: unit.value(100.0.kg()),
+            max: unit.value(850.0.kg()),
             small_inc: 1.0,
             big_inc: 10.0,
             dec_places: 0,
-            unit: "kg",
+            unit: unit.as_str(),
         })
     }
 
@@ -129,7 +139,7 @@ impl EditableFuncs for EmptyMass {
             persist::persist_set(
                 cc,
                 cm,
-                Variant::F32(val),
+                Variant::F32(cm.config.units.mass.mass(val).to_kg()),
                 PersistenceId::EmptyMass,
                 Echo::Can,
             )
@@ -144,17 +154,20 @@ impl EditableFuncs for MaxBallast {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.glider_data.basic_glider_data.max_ballast))
+        Content::F32(Some(
+            cm.config.units.mass.value(cm.glider_data.basic_glider_data.max_ballast.kg()),
+        ))
     }
 
-    fn params() -> Params {
+    fn params(cm: &CoreModel) -> Params {
+        let unit = cm.config.units.mass;
         Params::F32(F32Params {
             min: 0.0,
-            max: 300.0,
+            max: unit.value(300.0.kg()),
             small_inc: 1.0,
             big_inc: 10.0,
             dec_places: 0,
-            unit: "kg",
+            unit: unit.as_str(),
         })
     }
 
@@ -163,7 +176,7 @@ impl EditableFuncs for MaxBallast {
             persist::persist_set(
                 cc,
                 cm,
-                Variant::F32(val),
+                Variant::F32(cm.config.units.mass.mass(val).to_kg()),
                 PersistenceId::MaxBallast,
                 Echo::Can,
             )
@@ -178,17 +191,20 @@ impl EditableFuncs for ReferenceWeight {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.glider_data.basic_glider_data.reference_weight))
+        Content::F32(Some(
+            cm.config.units.mass.value(cm.glider_data.basic_glider_data.reference_weight.kg()),
+        ))
     }
 
-    fn params() -> Params {
+    fn params(cm: &CoreModel) -> Params {
+        let unit = cm.config.units.mass;
         Params::F32(F32Params {
-            min: 100.0,
-            max: 1000.0,
+            min: unit.value(100.0.kg()),
+            max: unit.value(1000.0.kg()),
             small_inc: 1.0,
             big_inc: 10.0,
             dec_places: 0,
-            unit: "kg",
+            unit: unit.as_str(),
         })
     }
 
@@ -197,7 +213,7 @@ impl EditableFuncs for ReferenceWeight {
             persist::persist_set(
                 cc,
                 cm,
-                Variant::F32(val),
+                Variant::F32(cm.config.units.mass.mass(val).to_kg()),
                 PersistenceId::ReferenceWeight,
                 Echo::Can,
             )
@@ -205,15 +221,97 @@ impl EditableFuncs for ReferenceWeight {
     }
 }
 
+pub struct FlapSetting;
+const FLAP_SENSOR: &str = "Sensor";
+// settings of a glider without a flap table, for which the pilot enters the flap polars
+const FLAP_NUMBERS: [&str; MAX_FLAP_POLARS] = ["1", "2", "3", "4", "5"];
+
+impl EditableFuncs for FlapSetting {
+    fn name() -> &'static str {
+        "Flap Setting"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::List(cm.control.flap_select.map_or(0, |flap| flap as i32 + 1))
+    }
+
+    fn content_as_str(cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
+        let label = match cm.glider_data.basic_glider_data.flaps {
+            _ if idx == 0 => FLAP_SENSOR,
+            Some(flaps) => flaps.label(idx as u8 - 1),
+            None => FLAP_NUMBERS[idx as usize - 1],
+        };
+        convert.write_str(label).unwrap()
+    }
+
+    fn params(cm: &CoreModel) -> Params {
+        let count = cm
+            .glider_data
+            .basic_glider_data
+            .flaps
+            .map_or(MAX_FLAP_POLARS, |flaps| flaps.count());
+        Params::List(ListParams { max: count as i32 })
+    }
+
+    // The selection is not stored, after a restart the flap sensor is used again
+    fn set_content(cm: &mut CoreModel, _cc: &mut CoreController, content: Content) {
+        if let Content::List(idx) = content {
+            cm.control.flap_select = (idx > 0).then(|| idx as u8 - 1);
+        }
+    }
+}
+
+// The pilot edits the polar of the selected flap setting, this works for every glider
+fn selected_flap(cm: &CoreModel) -> Option<usize> {
+    cm.control
+        .flap_select
+        .map(|flap| flap as usize)
+        .filter(|flap| *flap < MAX_FLAP_POLARS)
+}
+
+fn polar_value(cm: &CoreModel, point: usize, value: usize) -> f32 {
+    match selected_flap(cm) {
+        Some(flap) => cm.glider_data.flap_polars[flap][point][value],
+        None => cm.glider_data.basic_glider_data.polar_values[point][value],
+    }
+}
+
+fn set_polar_value(
+    cm: &mut CoreModel,
+    cc: &mut CoreController,
+    point: usize,
+    value: usize,
+    content: f32,
+    id: PersistenceId,
+) {
+    match selected_flap(cm) {
+        Some(flap) => {
+            let mut polar_point = cm.glider_data.flap_polars[flap][point];
+            polar_point[value] = content;
+            persist::persist_set(
+                cc,
+                cm,
+                Variant::U32(persist::pack_polar_point(polar_point)),
+                persist::flap_polar_id(flap, point),
+                Echo::None,
+            )
+        }
+        None => persist::persist_set(cc, cm, Variant::F32(content), id, Echo::Can),
+    }
+}
+
 pub struct PolarValueV1;
-const V_PARAMS: Params = Params::F32(F32Params {
-    min: 50.0,
-    max: 250.0,
-    small_inc: 1.0,
-    big_inc: 10.0,
-    dec_places: 0,
-    unit: "km/h",
-});
+fn v_params(cm: &CoreModel) -> Params {
+    let unit = cm.config.units.speed;
+    Params::F32(F32Params {
+        min: unit.value(50.0.km_h()),
+        max: unit.value(250.0.km_h()),
+        small_inc: 1.0,
+        big_inc: 10.0,
+        dec_places: 0,
+        unit: unit.as_str(),
+    })
+}
 
 impl EditableFuncs for PolarValueV1 {
     fn name() -> &'static str {
@@ -221,22 +319,18 @@ impl EditableFuncs for PolarValueV1 {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.glider_data.basic_glider_data.polar_values[0][0]))
+        let v = polar_value(cm, 0, 0).km_h();
+        Content::F32(Some(cm.config.units.speed.value(v)))
     }
 
-    fn params() -> Params {
-        V_PARAMS
+    fn params(cm: &CoreModel) -> Params {
+        v_params(cm)
     }
 
     fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
         if let Content::F32(Some(val)) = content {
-            persist::persist_set(
-                cc,
-                cm,
-                Variant::F32(val),
-                PersistenceId::PolarValueV1,
-                Echo::Can,
-            )
+            let value = cm.config.units.speed.speed(val).to_km_h();
+            set_polar_value(cm, cc, 0, 0, value, PersistenceId::PolarValueV1)
         }
     }
 }
@@ -248,22 +342,18 @@ impl EditableFuncs for PolarValueV2 {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.glider_data.basic_glider_data.polar_values[1][0]))
+        let v = polar_value(cm, 1, 0).km_h();
+        Content::F32(Some(cm.config.units.speed.value(v)))
     }
 
-    fn params() -> Params {
-        V_PARAMS
+    fn params(cm: &CoreModel) -> Params {
+        v_params(cm)
     }
 
     fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
         if let Content::F32(Some(val)) = content {
-            persist::persist_set(
-                cc,
-                cm,
-                Variant::F32(val),
-                PersistenceId::PolarValueV2,
-                Echo::Can,
-            )
+            let value = cm.config.units.speed.speed(val).to_km_h();
+            set_polar_value(cm, cc, 1, 0, value, PersistenceId::PolarValueV2)
         }
     }
 }
@@ -275,35 +365,38 @@ impl EditableFuncs for PolarValueV3 {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.glider_data.basic_glider_data.polar_values[2][0]))
+        let v = polar_value(cm, 2, 0).km_h();
+        Content::F32(Some(cm.config.units.speed.value(v)))
     }
 
-    fn params() -> Params {
-        V_PARAMS
+    fn params(cm: &CoreModel) -> Params {
+        v_params(cm)
     }
 
     fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
         if let Content::F32(Some(val)) = content {
-            persist::persist_set(
-                cc,
-                cm,
-                Variant::F32(val),
-                PersistenceId::PolarValueV3,
-                Echo::Can,
-            )
+            let value = cm.config.units.speed.speed(val).to_km_h();
+            set_polar_value(cm, cc, 2, 0, value, PersistenceId::PolarValueV3)
         }
     }
 }
 
 pub struct PolarValueSi1;
-const SI_PARAMS: Params = Params::F32(F32Params {
-    min: -5.0,
-    max: 0.0,
-    small_inc: 0.01,
-    big_inc: 0.1,
-    dec_places: 2,
-    unit: "m/s",
-});
+fn si_params(cm: &CoreModel) -> Params {
+    let unit = cm.config.units.vertical_speed;
+    Params::F32(F32Params {
+        min: unit.value(-5.0.m_s()),
+        max: 0.0,
+        small_inc: unit.increment() / 10.0,
+        big_inc: unit.increment(),
+        dec_places: if unit == VerticalSpeedUnit::FtMin {
+            0
+        } else {
+            2
+        },
+        unit: unit.as_str(),
+    })
+}
 
 impl EditableFuncs for PolarValueSi1 {
     fn name() -> &'static str {
@@ -311,22 +404,18 @@ impl EditableFuncs for PolarValueSi1 {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.glider_data.basic_glider_data.polar_values[0][1]))
+        let si = polar_value(cm, 0, 1).m_s();
+        Content::F32(Some(cm.config.units.vertical_speed.value(si)))
     }
 
-    fn params() -> Params {
-        SI_PARAMS
+    fn params(cm: &CoreModel) -> Params {
+        si_params(cm)
     }
 
     fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
         if let Content::F32(Some(val)) = content {
-            persist::persist_set(
-                cc,
-                cm,
-                Variant::F32(val),
-                PersistenceId::PolarValueSi1,
-                Echo::Can,
-            )
+            let value = cm.config.units.vertical_speed.speed(val).to_m_s();
+            set_polar_value(cm, cc, 0, 1, value, PersistenceId::PolarValueSi1)
         }
     }
 }
@@ -338,22 +427,18 @@ impl EditableFuncs for PolarValueSi2 {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.glider_data.basic_glider_data.polar_values[1][1]))
+        let si = polar_value(cm, 1, 1).m_s();
+        Content::F32(Some(cm.config.units.vertical_speed.value(si)))
     }
 
-    fn params() -> Params {
-        SI_PARAMS
+    fn params(cm: &CoreModel) -> Params {
+        si_params(cm)
     }
 
     fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
         if let Content::F32(Some(val)) = content {
-            persist::persist_set(
-                cc,
-                cm,
-                Variant::F32(val),
-                PersistenceId::PolarValueSi2,
-                Echo::Can,
-            )
+            let value = cm.config.units.vertical_speed.speed(val).to_m_s();
+            set_polar_value(cm, cc, 1, 1, value, PersistenceId::PolarValueSi2)
         }
     }
 }
@@ -365,22 +450,18 @@ impl EditableFuncs for PolarValueSi3 {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.glider_data.basic_glider_data.polar_values[2][1]))
+        let si = polar_value(cm, 2, 1).m_s();
+        Content::F32(Some(cm.config.units.vertical_speed.value(si)))
     }
 
-    fn params() -> Params {
-        SI_PARAMS
+    fn params(cm: &CoreModel) -> Params {
+        si_params(cm)
     }
 
     fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
         if let Content::F32(Some(val)) = content {
-            persist::persist_set(
-                cc,
-                cm,
-                Variant::F32(val),
-                PersistenceId::PolarValueSi3,
-                Echo::Can,
-            )
+            let value = cm.config.units.vertical_speed.speed(val).to_m_s();
+            set_polar_value(cm, cc, 2, 1, value, PersistenceId::PolarValueSi3)
         }
     }
 }
@@ -14,16 +14,20 @@
 ///   - Add reference to fn Editable::this()
 ///   - Add the new editable to the menu structure (src/model/menu)
 mod model;
+mod can_bus;
 mod controller;
+mod flight_stats;
 mod glider_data;
 mod sensorbox;
 
 use model::*;
+use can_bus::*;
 use controller::*;
+use flight_stats::*;
 use glider_data::*;
 use sensorbox::*;
 
-use crate::{utils::TString, CoreController, CoreModel};
+use crate::{utils::TString, CoreController, CoreError, CoreModel};
 use tfmt::Convert;
 
 #[repr(u8)]
@@ -31,6 +35,8 @@ use tfmt::Convert;
 pub enum Editable {
     // model
     AlarmVolume,
+    AutoMc,
+    AutoQnh,
     AvgClimbRateSrc,
     BatteryGood,
     BatteryLow,
@@ -38,19 +44,45 @@ pub enum Editable {
     CenterViewCircling,
     CenterViewStraight,
     Display,
+    FieldElevation,
     Glider,
     GliderSymbol,
+    GlideTarget,
+    HomeElevation,
     Info1,
     Info2,
     Info3,
     McCready,
+    SafetyAltitude,
+    SetHome,
+    StallMargin,
     StfUpperLimit,
     StfLowerLimit,
     TcCircleHysteresis,
     TcClimbRate,
     TcSpeedToFly,
     Theme,
+    ToneProfile,
+    ToneSinkLimit,
+    ToneLiftLimit,
+    ToneSinkPitch,
+    ToneLiftPitch,
+    ToneSlowBeeps,
+    ToneFastBeeps,
+    SpeedUnit,
+    VerticalSpeedUnit,
+    AltitudeUnit,
+    DistanceUnit,
+    MassUnit,
+    PressureUnit,
+    TemperatureUnit,
+    VarioSource,
     Volume,
+    WindSource,
+
+    // can_bus
+    BusDevices,
+    BusTraffic,
 
     // controller
     DrainPinConfig,
@@ -62,12 +94,25 @@ pub enum Editable {
     GearPinConfig,
     AirbrakesPinConfig,
     GearAlarmModeConfig,
+    NmeaProfile,
+    RemoteButton1,
+    RemoteButton2,
+    RemoteButton3,
+    RemoteButton4,
     ResetConfig,
     Rotation,
     SpeedToFlyPinConfig,
     UserProfile,
     VarioModeControl,
 
+    // flight_stats
+    Thermals,
+    AvgThermal,
+    CirclingPercent,
+    CruiseSpeed,
+    GlideRatio,
+    EngineTime,
+
     // glider_data
     Bugs,
     PilotWeight,
@@ -75,6 +120,7 @@ pub enum Editable {
     EmptyMass,
     MaxBallast,
     ReferenceWeight,
+    FlapSetting,
     PolarValueV1,
     PolarValueV2,
     PolarValueV3,
@@ -164,8 +210,8 @@ pub enum Params {
 struct EditableFptrs {
     name: fn() -> &'static str,
     content: fn(&mut CoreModel, &mut CoreController) -> Content,
-    content_as_str: fn(&mut Convert<20>, i32),
-    params: fn() -> Params,
+    content_as_str: fn(&CoreModel, &mut Convert<20>, i32),
+    params: fn(&CoreModel) -> Params,
     set_content: fn(&mut CoreModel, &mut CoreController, Content),
 }
 
@@ -178,9 +224,9 @@ trait EditableFuncs {
         Content::String(TString::<12>::from_str(""))
     }
 
-    fn content_as_str(_convert: &mut Convert<20>, _idx: i32) {}
+    fn content_as_str(_cm: &CoreModel, _convert: &mut Convert<20>, _idx: i32) {}
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::String(StringParams {
             content: TString::<16>::from_str(""),
         })
@@ -218,6 +264,8 @@ impl Editable {
         match self {
             // model
             Editable::AlarmVolume => AlarmVolume::this(),
+            Editable::AutoMc => AutoMc::this(),
+            Editable::AutoQnh => AutoQnh::this(),
             Editable::AvgClimbRateSrc => AvgClimbRateSrc::this(),
             Editable::BatteryGood => BatteryGood::this(),
             Editable::BatteryLow => BatteryLow::this(),
@@ -225,19 +273,45 @@ impl Editable {
             Editable::CenterViewCircling => CenterViewCircling::this(),
             Editable::CenterViewStraight => CenterViewStraight::this(),
             Editable::Display => Display::this(),
+            Editable::FieldElevation => FieldElevation::this(),
             Editable::Glider => Glider::this(),
             Editable::GliderSymbol => GliderSymbol::this(),
+            Editable::GlideTarget => GlideTarget_::this(),
+            Editable::HomeElevation => HomeElevation::this(),
             Editable::Info1 => Info1::this(),
             Editable::Info2 => Info2::this(),
             Editable::McCready => McCready::this(),
             Editable::Info3 => Info3::this(),
+            Editable::SafetyAltitude => SafetyAltitude::this(),
+            Editable::SetHome => SetHome::this(),
+            Editable::StallMargin => StallMargin::this(),
             Editable::StfUpperLimit => StfUpperLimit::this(),
             Editable::StfLowerLimit => StfLowerLimit::this(),
             Editable::TcCircleHysteresis => TcCircleHysteresis::this(),
             Editable::TcClimbRate => TcClimbRate::this(),
             Editable::TcSpeedToFly => TcSpeedToFly::this(),
             Editable::Theme => Theme::this(),
+            Editable::ToneProfile => ToneProfile_::this(),
+            Editable::ToneSinkLimit => ToneSinkLimit::this(),
+            Editable::ToneLiftLimit => ToneLiftLimit::this(),
+            Editable::ToneSinkPitch => ToneSinkPitch::this(),
+            Editable::ToneLiftPitch => ToneLiftPitch::this(),
+            Editable::ToneSlowBeeps => ToneSlowBeeps::this(),
+            Editable::ToneFastBeeps => ToneFastBeeps::this(),
+            Editable::SpeedUnit => SpeedUnit_::this(),
+            Editable::VerticalSpeedUnit => VerticalSpeedUnit_::this(),
+            Editable::AltitudeUnit => AltitudeUnit_::this(),
+            Editable::DistanceUnit => DistanceUnit_::this(),
+            Editable::MassUnit => MassUnit_::this(),
+            Editable::PressureUnit => PressureUnit_::this(),
+            Editable::TemperatureUnit => TemperatureUnit_::this(),
+            Editable::VarioSource => VarioSource_::this(),
             Editable::Volume => Volume::this(),
+            Editable::WindSource => WindSource::this(),
+
+            // can_bus
+            Editable::BusDevices => BusDevices::this(),
+            Editable::BusTraffic => BusTraffic::this(),
 
             // controller
             Editable::DrainPinConfig => DrainPinConfig::this(),
@@ -249,12 +323,25 @@ impl Editable {
             Editable::GearPinConfig => GearPinConfig::this(),
             Editable::AirbrakesPinConfig => AirbrakesPinConfig::this(),
             Editable::GearAlarmModeConfig => GearAlarmModeConfig::this(),
+            Editable::NmeaProfile => NmeaProfile_::this(),
+            Editable::RemoteButton1 => RemoteButton1::this(),
+            Editable::RemoteButton2 => RemoteButton2::this(),
+            Editable::RemoteButton3 => RemoteButton3::this(),
+            Editable::RemoteButton4 => RemoteButton4::this(),
             Editable::ResetConfig => ResetConfig::this(),
             Editable::Rotation => Rotation_::this(),
             Editable::SpeedToFlyPinConfig => SpeedToFlyPinConfig::this(),
             Editable::UserProfile => UserProfile::this(),
             Editable::VarioModeControl => VarioModeControl_::this(),
 
+            // flight_stats
+            Editable::Thermals => Thermals::this(),
+            Editable::AvgThermal => AvgThermal::this(),
+            Editable::CirclingPercent => CirclingPercent::this(),
+            Editable::CruiseSpeed => CruiseSpeed::this(),
+            Editable::GlideRatio => GlideRatio::this(),
+            Editable::EngineTime => EngineTime::this(),
+
             // glider_data
             Editable::Bugs => Bugs::this(),
             Editable::PilotWeight => PilotWeight::this(),
@@ -262,6 +349,7 @@ impl Editable {
             Editable::EmptyMass => EmptyMass::this(),
             Editable::MaxBallast => MaxBallast::this(),
             Editable::ReferenceWeight => ReferenceWeight::this(),
+            Editable::FlapSetting => FlapSetting::this(),
             Editable::PolarValueV1 => PolarValueV1::this(),
             Editable::PolarValueV2 => PolarValueV2::this(),
             Editable::PolarValueV3 => PolarValueV3::this(),
@@ -299,9 +387,13 @@ impl Editable {
         }
     }
 
-    pub fn content_as_str(&self, content: Content) -> TString<20> {
+    pub fn content_as_str(
+        &self,
+        cm: &CoreModel,
+        content: Content,
+        params: &Params,
+    ) -> TString<20> {
         let mut conv = Convert::<20>::new(b' ');
-        let params = self.params();
 
         match params {
             Params::Enum(_params) => {
@@ -320,7 +412,7 @@ impl Editable {
             }
             Params::List(_params) => {
                 if let Content::List(val) = content {
-                    ((self.this()).content_as_str)(&mut conv, val);
+                    ((self.this()).content_as_str)(cm, &mut conv, val);
                 }
             }
             Params::String(_params) => {
@@ -345,8 +437,17 @@ impl Editable {
         ((self.this()).name)()
     }
 
-    pub fn params(&self) -> Params {
-        ((self.this()).params)()
+    pub fn params(&self, cm: &CoreModel) -> Params {
+        ((self.this()).params)(cm)
+    }
+
+    /// Values from a flight computer or configuration tool must be within the limits of the
+    /// editor, the value is expected in the unit the editor uses
+    pub fn in_params(&self, cm: &CoreModel, val: f32) -> Result<f32, CoreError> {
+        match self.params(cm) {
+            Params::F32(p) if val >= p.min && val <= p.max => Ok(val),
+            _ => Err(CoreError::ParseError),
+        }
     }
 
     pub fn set_content(&self, cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
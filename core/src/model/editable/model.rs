@@ -1,16 +1,23 @@
 use super::{Content, EditableFuncs, EnumParams, F32Params, ListParams, Params};
 use crate::{
     model::{
-        control::{DATA_SOURCE_FRONTEND, DATA_SOURCE_SENSORBOX},
-        DataSource, DisplayActive, DisplayTheme,
-        config::{VARIO, HORIZON}},
+        control::{
+            AUTO_MC_AUTO, AUTO_MC_OFF, AUTO_MC_SUGGEST, AUTO_QNH_ELEVATION, AUTO_QNH_GNSS,
+            AUTO_QNH_OFF, DATA_SOURCE_FRONTEND, DATA_SOURCE_SENSORBOX, VARIO_SOURCE_NETTO,
+            VARIO_SOURCE_RELATIVE, VARIO_SOURCE_TOTAL_ENERGY,
+        },
+        navigation::{GLIDE_TARGET_HOME, GLIDE_TARGET_WAYPOINT},
+        units::*,
+        AutoMcMode, AutoQnhMode, DataSource, DisplayActive, DisplayTheme, GlideTarget, GpsState,
+        TonePreset, VarioSource,
+        config::{VARIO, HORIZON, ENGINE}},
     persist, polar_store,
     utils::{TString, Variant},
     view::viewable::{
         centerview::{CenterType, CenterView},
         lineview::{LineView, Placement},
     },
-    CoreController, CoreModel, Echo, FloatToSpeed, PersistenceId,
+    CoreController, CoreModel, Echo, FloatToLength, FloatToSpeed, PersistenceId,
 };
 use tfmt::Convert;
 
@@ -33,7 +40,7 @@ impl EditableFuncs for AlarmVolume {
         Content::F32(Some(cm.control.alarm_volume as f32))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         VOLUME_PARAMS
     }
 
@@ -50,6 +57,66 @@ impl EditableFuncs for AlarmVolume {
     }
 }
 
+pub struct AutoMc;
+impl EditableFuncs for AutoMc {
+    fn name() -> &'static str {
+        "Auto MC"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.control.auto_mc_mode.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [AUTO_MC_OFF, AUTO_MC_SUGGEST, AUTO_MC_AUTO, "", ""],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let mode = AutoMcMode::from(val.as_str());
+            persist::persist_set(
+                cc,
+                cm,
+                Variant::U8(mode as u8),
+                PersistenceId::AutoMcMode,
+                Echo::None,
+            );
+        }
+    }
+}
+
+pub struct AutoQnh;
+impl EditableFuncs for AutoQnh {
+    fn name() -> &'static str {
+        "Auto QNH"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.control.auto_qnh_mode.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [AUTO_QNH_OFF, AUTO_QNH_GNSS, AUTO_QNH_ELEVATION, "", ""],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let mode = AutoQnhMode::from(val.as_str());
+            persist::persist_set(
+                cc,
+                cm,
+                Variant::U8(mode as u8),
+                PersistenceId::AutoQnhMode,
+                Echo::None,
+            );
+        }
+    }
+}
+
 pub struct AvgClimbRateSrc;
 impl EditableFuncs for AvgClimbRateSrc {
     fn name() -> &'static str {
@@ -62,7 +129,7 @@ impl EditableFuncs for AvgClimbRateSrc {
         ))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
             variants: [DATA_SOURCE_FRONTEND, DATA_SOURCE_SENSORBOX, "", "", ""],
         })
@@ -101,7 +168,7 @@ impl EditableFuncs for BatteryGood {
         Content::F32(Some(cm.config.battery_good))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         BAT_PARAMS
     }
 
@@ -128,7 +195,7 @@ impl EditableFuncs for BatteryLow {
         Content::F32(Some(cm.config.battery_low))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         BAT_PARAMS
     }
 
@@ -155,7 +222,7 @@ impl EditableFuncs for CenterFrequency {
         Content::F32(Some(cm.config.snd_center_freq))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::F32(F32Params {
             min: 500.0,
             max: 1000.0,
@@ -179,6 +246,235 @@ impl EditableFuncs for CenterFrequency {
     }
 }
 
+pub struct ToneProfile_;
+impl EditableFuncs for ToneProfile_ {
+    fn name() -> &'static str {
+        "Tone Profile"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::List(cm.config.tone_preset as i32)
+    }
+
+    fn content_as_str(cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
+        let profile = TonePreset::from(idx as u8).profile(&cm.config.custom_tone);
+        convert.write_str(profile.name).unwrap()
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::List(ListParams {
+            max: TonePreset::COUNT as i32 - 1,
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        // the profile is applied at once, so it can be heard while editing
+        if let Content::List(idx) = content {
+            persist::persist_set(
+                cc,
+                cm,
+                Variant::U8(idx as u8),
+                PersistenceId::ToneProfile,
+                Echo::None,
+            );
+        }
+    }
+}
+
+// Designing a tone selects the custom profile, so the changes are heard at once
+fn set_custom_tone(
+    cm: &mut CoreModel,
+    cc: &mut CoreController,
+    id: PersistenceId,
+    pair: [f32; 2],
+) {
+    let packed = persist::pack_tone_pair(pair);
+    persist::persist_set(cc, cm, Variant::U32(packed), id, Echo::None);
+    if cm.config.tone_preset != TonePreset::Custom {
+        persist::persist_set(
+            cc,
+            cm,
+            Variant::U8(TonePreset::Custom as u8),
+            PersistenceId::ToneProfile,
+            Echo::None,
+        );
+    }
+}
+
+pub struct ToneSinkLimit;
+impl EditableFuncs for ToneSinkLimit {
+    fn name() -> &'static str {
+        "Sink Limit"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::F32(Some(cm.config.custom_tone.sink_limit()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::F32(F32Params {
+            min: -10.0,
+            max: 0.0,
+            small_inc: 0.1,
+            big_inc: 0.5,
+            dec_places: 1,
+            unit: "m/s",
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::F32(Some(val)) = content {
+            let tone = cm.config.custom_tone;
+            set_custom_tone(cm, cc, PersistenceId::ToneDeadBand, [val, tone.lift_limit()]);
+        }
+    }
+}
+
+pub struct ToneLiftLimit;
+impl EditableFuncs for ToneLiftLimit {
+    fn name() -> &'static str {
+        "Lift Limit"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::F32(Some(cm.config.custom_tone.lift_limit()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::F32(F32Params {
+            min: 0.0,
+            max: 3.0,
+            small_inc: 0.1,
+            big_inc: 0.5,
+            dec_places: 1,
+            unit: "m/s",
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::F32(Some(val)) = content {
+            let tone = cm.config.custom_tone;
+            set_custom_tone(cm, cc, PersistenceId::ToneDeadBand, [tone.sink_limit(), val]);
+        }
+    }
+}
+
+pub struct ToneSinkPitch;
+impl EditableFuncs for ToneSinkPitch {
+    fn name() -> &'static str {
+        "Sink Pitch"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::F32(Some(cm.config.custom_tone.sink_pitch()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::F32(F32Params {
+            min: 0.3,
+            max: 1.0,
+            small_inc: 0.01,
+            big_inc: 0.1,
+            dec_places: 2,
+            unit: "",
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::F32(Some(val)) = content {
+            let tone = cm.config.custom_tone;
+            set_custom_tone(cm, cc, PersistenceId::TonePitch, [val, tone.lift_pitch()]);
+        }
+    }
+}
+
+pub struct ToneLiftPitch;
+impl EditableFuncs for ToneLiftPitch {
+    fn name() -> &'static str {
+        "Lift Pitch"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::F32(Some(cm.config.custom_tone.lift_pitch()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::F32(F32Params {
+            min: 1.0,
+            max: 3.0,
+            small_inc: 0.01,
+            big_inc: 0.1,
+            dec_places: 2,
+            unit: "",
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::F32(Some(val)) = content {
+            let tone = cm.config.custom_tone;
+            set_custom_tone(cm, cc, PersistenceId::TonePitch, [tone.sink_pitch(), val]);
+        }
+    }
+}
+
+pub struct ToneSlowBeeps;
+impl EditableFuncs for ToneSlowBeeps {
+    fn name() -> &'static str {
+        "Slow Beeps"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::F32(Some(cm.config.custom_tone.slow_pulse_rate()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::F32(F32Params {
+            min: 0.5,
+            max: 10.0,
+            small_inc: 0.1,
+            big_inc: 1.0,
+            dec_places: 1,
+            unit: "/s",
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::F32(Some(val)) = content {
+            let tone = cm.config.custom_tone;
+            set_custom_tone(cm, cc, PersistenceId::TonePulseRate, [val, tone.fast_pulse_rate()]);
+        }
+    }
+}
+
+pub struct ToneFastBeeps;
+impl EditableFuncs for ToneFastBeeps {
+    fn name() -> &'static str {
+        "Fast Beeps"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::F32(Some(cm.config.custom_tone.fast_pulse_rate()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::F32(F32Params {
+            min: 0.5,
+            max: 10.0,
+            small_inc: 0.1,
+            big_inc: 1.0,
+            dec_places: 1,
+            unit: "/s",
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::F32(Some(val)) = content {
+            let tone = cm.config.custom_tone;
+            set_custom_tone(cm, cc, PersistenceId::TonePulseRate, [tone.slow_pulse_rate(), val]);
+        }
+    }
+}
+
 pub struct CenterViewCircling;
 impl EditableFuncs for CenterViewCircling {
     fn name() -> &'static str {
@@ -193,13 +489,13 @@ impl EditableFuncs for CenterViewCircling {
         )
     }
 
-    fn content_as_str(convert: &mut Convert<20>, idx: i32) {
+    fn content_as_str(_cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
         convert
             .write_str(CenterView::from_sorted(idx as usize, CenterType::Circling).name())
             .unwrap()
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::List(ListParams {
             max: CenterView::max(CenterType::Circling) as i32,
         })
@@ -233,13 +529,13 @@ impl EditableFuncs for CenterViewStraight {
         )
     }
 
-    fn content_as_str(convert: &mut Convert<20>, idx: i32) {
+    fn content_as_str(_cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
         convert
             .write_str(CenterView::from_sorted(idx as usize, CenterType::Straight).name())
             .unwrap()
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::List(ListParams {
             max: CenterView::max(CenterType::Straight) as i32,
         })
@@ -269,13 +565,14 @@ impl EditableFuncs for Display {
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
         match cm.config.last_display_active {
             DisplayActive::Horizon => Content::Enum(TString::<16>::from_str(HORIZON)),
+            DisplayActive::Engine => Content::Enum(TString::<16>::from_str(ENGINE)),
             _ => Content::Enum(TString::<16>::from_str(VARIO)),
         }
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
-            variants: [VARIO, HORIZON, "", "", ""],
+            variants: [VARIO, HORIZON, ENGINE, "", ""],
         })
     }
 
@@ -293,6 +590,42 @@ impl EditableFuncs for Display {
     }
 }
 
+pub struct FieldElevation;
+impl EditableFuncs for FieldElevation {
+    fn name() -> &'static str {
+        "Field Elevation"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::F32(Some(cm.config.units.altitude.value(cm.config.field_elevation)))
+    }
+
+    fn params(cm: &CoreModel) -> Params {
+        let unit = cm.config.units.altitude;
+        Params::F32(F32Params {
+            min: unit.value((-500.0).m()),
+            max: unit.value(5000.0.m()),
+            small_inc: 1.0,
+            big_inc: 10.0,
+            dec_places: 0,
+            unit: unit.as_str(),
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::F32(Some(val)) = content {
+            let elevation = cm.config.units.altitude.length(val);
+            persist::persist_set(
+                cc,
+                cm,
+                Variant::F32(elevation.to_m()),
+                PersistenceId::FieldElevation,
+                Echo::None,
+            );
+        }
+    }
+}
+
 pub struct Glider;
 impl EditableFuncs for Glider {
     fn name() -> &'static str {
@@ -304,13 +637,13 @@ impl EditableFuncs for Glider {
         Content::List(sorted_idx as i32)
     }
 
-    fn content_as_str(convert: &mut Convert<20>, idx: i32) {
+    fn content_as_str(_cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
         let raw_idx = polar_store::to_raw_idx(idx as usize);
         let name = polar_store::from_raw_idx(raw_idx).name;
         convert.write_str(name).unwrap()
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::List(ListParams {
             max: polar_store::size() as i32 - 1,
         })
@@ -347,7 +680,7 @@ impl EditableFuncs for GliderSymbol {
         }
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
             variants: [ON, OFF, "", "", ""],
         })
@@ -366,6 +699,73 @@ impl EditableFuncs for GliderSymbol {
     }
 }
 
+pub struct GlideTarget_;
+impl EditableFuncs for GlideTarget_ {
+    fn name() -> &'static str {
+        "Glide Target"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.navigation.target.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [GLIDE_TARGET_WAYPOINT, GLIDE_TARGET_HOME, "", "", ""],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let target = GlideTarget::from(val.as_str());
+            persist::persist_set(
+                cc,
+                cm,
+                Variant::U8(target as u8),
+                PersistenceId::GlideTarget,
+                Echo::None,
+            );
+        }
+    }
+}
+
+pub struct HomeElevation;
+impl EditableFuncs for HomeElevation {
+    fn name() -> &'static str {
+        "Home Elevation"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        let elevation = cm.navigation.home.and_then(|home| home.elevation);
+        Content::F32(elevation.map(|e| cm.config.units.altitude.value(e)))
+    }
+
+    fn params(cm: &CoreModel) -> Params {
+        let unit = cm.config.units.altitude;
+        Params::F32(F32Params {
+            min: unit.value((-500.0).m()),
+            max: unit.value(5000.0.m()),
+            small_inc: 1.0,
+            big_inc: 10.0,
+            dec_places: 0,
+            unit: unit.as_str(),
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let (Content::F32(Some(val)), Some(_)) = (content, cm.navigation.home) {
+            let elevation = cm.config.units.altitude.length(val);
+            persist::persist_set(
+                cc,
+                cm,
+                Variant::F32(elevation.to_m()),
+                PersistenceId::HomeElevation,
+                Echo::None,
+            );
+        }
+    }
+}
+
 pub struct Info1;
 impl EditableFuncs for Info1 {
     fn name() -> &'static str {
@@ -376,13 +776,13 @@ impl EditableFuncs for Info1 {
         Content::List(cm.config.info1.sorted_as_i32(Placement::Top))
     }
 
-    fn content_as_str(convert: &mut Convert<20>, idx: i32) {
+    fn content_as_str(_cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
         convert
             .write_str(LineView::from_sorted(idx as usize, Placement::Top).name())
             .unwrap()
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::List(ListParams {
             max: LineView::max(Placement::Top) as i32,
         })
@@ -412,13 +812,13 @@ impl EditableFuncs for Info2 {
         Content::List(cm.config.info2.sorted_as_i32(Placement::Bottom))
     }
 
-    fn content_as_str(convert: &mut Convert<20>, idx: i32) {
+    fn content_as_str(_cm: &CoreModel, convert: &mut Convert<20>, idx: i32) {
         convert
             .write_str(LineView::from_sorted(idx as usize, Placement::Bottom).name())
             .unwrap()
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::List(ListParams {
             max: LineView::max(Placement::Bottom) as i32,
         })
@@ -457,7 +857,7 @@ impl EditableFuncs for Info3 {
         ))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
             variants: [ALTERNATING, CLIMB_RATE, "", "", ""],
         })
@@ -488,17 +888,18 @@ impl EditableFuncs for McCready {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.config.mc_cready.to_m_s()))
+        Content::F32(Some(cm.config.units.vertical_speed.value(cm.config.mc_cready)))
     }
 
-    fn params() -> Params {
+    fn params(cm: &CoreModel) -> Params {
+        let unit = cm.config.units.vertical_speed;
         Params::F32(F32Params {
             min: 0.0,
-            max: 5.0,
-            small_inc: 0.1,
-            big_inc: 0.1,
-            dec_places: 1,
-            unit: "m/s",
+            max: unit.value(5.0.m_s()),
+            small_inc: unit.increment(),
+            big_inc: unit.increment(),
+            dec_places: unit.dec_places(),
+            unit: unit.as_str(),
         })
     }
 
@@ -507,7 +908,7 @@ impl EditableFuncs for McCready {
             persist::persist_set(
                 cc,
                 cm,
-                Variant::Speed(val.m_s()),
+                Variant::Speed(cm.config.units.vertical_speed.speed(val)),
                 PersistenceId::McCready,
                 Echo::NmeaAndCan,
             );
@@ -515,6 +916,40 @@ impl EditableFuncs for McCready {
     }
 }
 
+pub struct StallMargin;
+impl EditableFuncs for StallMargin {
+    fn name() -> &'static str {
+        "Stall Margin"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::F32(Some(cm.config.stall_margin))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::F32(F32Params {
+            min: 0.0,
+            max: 30.0,
+            small_inc: 1.0,
+            big_inc: 5.0,
+            dec_places: 0,
+            unit: "%",
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::F32(Some(val)) = content {
+            persist::persist_set(
+                cc,
+                cm,
+                Variant::F32(val),
+                PersistenceId::StallMargin,
+                Echo::None,
+            )
+        }
+    }
+}
+
 pub struct StfUpperLimit;
 impl EditableFuncs for StfUpperLimit {
     fn name() -> &'static str {
@@ -522,17 +957,18 @@ impl EditableFuncs for StfUpperLimit {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.config.stf_upper_limit.to_km_h()))
+        Content::F32(Some(cm.config.units.speed.value(cm.config.stf_upper_limit)))
     }
 
-    fn params() -> Params {
+    fn params(cm: &CoreModel) -> Params {
+        let unit = cm.config.units.speed;
         Params::F32(F32Params {
             min: 0.0,
-            max: 50.0,
+            max: unit.value(50.0.km_h()),
             small_inc: 1.0,
             big_inc: 10.0,
             dec_places: 0,
-            unit: "km/h",
+            unit: unit.as_str(),
         })
     }
 
@@ -541,7 +977,7 @@ impl EditableFuncs for StfUpperLimit {
             persist::persist_set(
                 cc,
                 cm,
-                Variant::Speed(val.km_h()),
+                Variant::Speed(cm.config.units.speed.speed(val)),
                 PersistenceId::StfUpperLimit,
                 Echo::None,
             )
@@ -556,17 +992,18 @@ impl EditableFuncs for StfLowerLimit {
     }
 
     fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
-        Content::F32(Some(cm.config.stf_lower_limit.to_km_h()))
+        Content::F32(Some(cm.config.units.speed.value(cm.config.stf_lower_limit)))
     }
 
-    fn params() -> Params {
+    fn params(cm: &CoreModel) -> Params {
+        let unit = cm.config.units.speed;
         Params::F32(F32Params {
-            min: -50.0,
+            min: unit.value(-50.0.km_h()),
             max: 0.0,
             small_inc: 1.0,
             big_inc: 10.0,
             dec_places: 0,
-            unit: "km/h",
+            unit: unit.as_str(),
         })
     }
 
@@ -575,7 +1012,7 @@ impl EditableFuncs for StfLowerLimit {
             persist::persist_set(
                 cc,
                 cm,
-                Variant::Speed(val.km_h()),
+                Variant::Speed(cm.config.units.speed.speed(val)),
                 PersistenceId::StfLowerLimit,
                 Echo::None,
             )
@@ -593,7 +1030,7 @@ impl EditableFuncs for TcCircleHysteresis {
         Content::F32(Some(cm.config.circle_hysteresis_tc as f32))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::F32(F32Params {
             min: 3.0,
             max: 30.0,
@@ -627,7 +1064,7 @@ impl EditableFuncs for TcClimbRate {
         Content::F32(Some(cm.config.av2_climb_rate_tc))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::F32(F32Params {
             min: 15.0,
             max: 120.0,
@@ -661,7 +1098,7 @@ impl EditableFuncs for TcSpeedToFly {
         Content::F32(Some(cm.config.av_speed_to_fly_tc))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::F32(F32Params {
             min: 1.0,
             max: 60.0,
@@ -702,7 +1139,7 @@ impl EditableFuncs for Theme {
         }
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         Params::Enum(EnumParams {
             variants: [DARK, BRIGHT, "", "", ""],
         })
@@ -725,6 +1162,346 @@ impl EditableFuncs for Theme {
     }
 }
 
+fn persist_units(cm: &mut CoreModel, cc: &mut CoreController, units: Units) {
+    persist::persist_set(
+        cc,
+        cm,
+        Variant::U32(units.as_u32()),
+        PersistenceId::Units,
+        Echo::None,
+    );
+}
+
+pub struct SafetyAltitude;
+impl EditableFuncs for SafetyAltitude {
+    fn name() -> &'static str {
+        "Safety Altitude"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        let altitude = cm.navigation.safety_altitude;
+        Content::F32(Some(cm.config.units.altitude.value(altitude)))
+    }
+
+    fn params(cm: &CoreModel) -> Params {
+        let unit = cm.config.units.altitude;
+        Params::F32(F32Params {
+            min: 0.0,
+            max: unit.value(1000.0.m()),
+            small_inc: 10.0,
+            big_inc: 50.0,
+            dec_places: 0,
+            unit: unit.as_str(),
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::F32(Some(val)) = content {
+            let altitude = cm.config.units.altitude.length(val);
+            persist::persist_set(
+                cc,
+                cm,
+                Variant::F32(altitude.to_m()),
+                PersistenceId::SafetyAltitude,
+                Echo::None,
+            );
+        }
+    }
+}
+
+pub struct SetHome;
+const HOME_KEEP: &str = "Do not change";
+const HOME_CURRENT_POS: &str = "Current Position";
+
+impl EditableFuncs for SetHome {
+    fn name() -> &'static str {
+        "Set Home"
+    }
+
+    fn content(_cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(HOME_KEEP))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [HOME_KEEP, HOME_CURRENT_POS, "", "", ""],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let gps_ok = cm.sensor.gps_state != GpsState::NoGps;
+            if cm.control.editor.enter_pushed && val.as_str() == HOME_CURRENT_POS && gps_ok {
+                // coordinates are stored in 1e-7 degrees, the elevation is the current altitude
+                let lat = (cm.sensor.gps_lat.0.to_deg() * 1e7) as i32;
+                let lon = (cm.sensor.gps_lon.0.to_deg() * 1e7) as i32;
+                let elevation = cm.sensor.pressure_altitude.qnh_altitude().to_m();
+                let items = [
+                    (Variant::I32(lat), PersistenceId::HomeLatitude),
+                    (Variant::I32(lon), PersistenceId::HomeLongitude),
+                    (Variant::F32(elevation), PersistenceId::HomeElevation),
+                ];
+                for (variant, id) in items {
+                    persist::persist_set(cc, cm, variant, id, Echo::None);
+                }
+            }
+        }
+    }
+}
+
+pub struct SpeedUnit_;
+impl EditableFuncs for SpeedUnit_ {
+    fn name() -> &'static str {
+        "Speed Unit"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.config.units.speed.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [
+                SPEED_UNIT_KM_H,
+                SPEED_UNIT_KNOTS,
+                SPEED_UNIT_MPH,
+                "",
+                "",
+            ],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let mut units = cm.config.units;
+            units.speed = SpeedUnit::from(val.as_str());
+            persist_units(cm, cc, units);
+        }
+    }
+}
+
+pub struct VerticalSpeedUnit_;
+impl EditableFuncs for VerticalSpeedUnit_ {
+    fn name() -> &'static str {
+        "Vario Unit"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.config.units.vertical_speed.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [
+                VERTICAL_SPEED_UNIT_M_S,
+                VERTICAL_SPEED_UNIT_KNOTS,
+                VERTICAL_SPEED_UNIT_FT_MIN,
+                "",
+                "",
+            ],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let mut units = cm.config.units;
+            units.vertical_speed = VerticalSpeedUnit::from(val.as_str());
+            persist_units(cm, cc, units);
+        }
+    }
+}
+
+pub struct AltitudeUnit_;
+impl EditableFuncs for AltitudeUnit_ {
+    fn name() -> &'static str {
+        "Altitude Unit"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.config.units.altitude.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [
+                ALTITUDE_UNIT_METER,
+                ALTITUDE_UNIT_FEET,
+                "",
+                "",
+                "",
+            ],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let mut units = cm.config.units;
+            units.altitude = AltitudeUnit::from(val.as_str());
+            persist_units(cm, cc, units);
+        }
+    }
+}
+
+pub struct DistanceUnit_;
+impl EditableFuncs for DistanceUnit_ {
+    fn name() -> &'static str {
+        "Distance Unit"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.config.units.distance.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [
+                DISTANCE_UNIT_KM,
+                DISTANCE_UNIT_NAUTICAL_MILE,
+                DISTANCE_UNIT_MILE,
+                "",
+                "",
+            ],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let mut units = cm.config.units;
+            units.distance = DistanceUnit::from(val.as_str());
+            persist_units(cm, cc, units);
+        }
+    }
+}
+
+pub struct MassUnit_;
+impl EditableFuncs for MassUnit_ {
+    fn name() -> &'static str {
+        "Mass Unit"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.config.units.mass.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [
+                MASS_UNIT_KG,
+                MASS_UNIT_LB,
+                "",
+                "",
+                "",
+            ],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let mut units = cm.config.units;
+            units.mass = MassUnit::from(val.as_str());
+            persist_units(cm, cc, units);
+        }
+    }
+}
+
+pub struct PressureUnit_;
+impl EditableFuncs for PressureUnit_ {
+    fn name() -> &'static str {
+        "Pressure Unit"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.config.units.pressure.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [
+                PRESSURE_UNIT_HPA,
+                PRESSURE_UNIT_INHG,
+                "",
+                "",
+                "",
+            ],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let mut units = cm.config.units;
+            units.pressure = PressureUnit::from(val.as_str());
+            persist_units(cm, cc, units);
+        }
+    }
+}
+
+pub struct TemperatureUnit_;
+impl EditableFuncs for TemperatureUnit_ {
+    fn name() -> &'static str {
+        "Temperature Unit"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.config.units.temperature.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [
+                TEMPERATURE_UNIT_CELSIUS,
+                TEMPERATURE_UNIT_FAHRENHEIT,
+                "",
+                "",
+                "",
+            ],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let mut units = cm.config.units;
+            units.temperature = TemperatureUnit::from(val.as_str());
+            persist_units(cm, cc, units);
+        }
+    }
+}
+
+pub struct VarioSource_;
+impl EditableFuncs for VarioSource_ {
+    fn name() -> &'static str {
+        "Vario Source"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.control.vario_source.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [
+                VARIO_SOURCE_TOTAL_ENERGY,
+                VARIO_SOURCE_NETTO,
+                VARIO_SOURCE_RELATIVE,
+                "",
+                "",
+            ],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let source = VarioSource::from(val.as_str());
+            persist::persist_set(
+                cc,
+                cm,
+                Variant::U8(source as u8),
+                PersistenceId::VarioSource,
+                Echo::None,
+            );
+        }
+    }
+}
+
 pub struct Volume;
 impl EditableFuncs for Volume {
     fn name() -> &'static str {
@@ -735,7 +1512,7 @@ impl EditableFuncs for Volume {
         Content::F32(Some(cm.config.volume as f32))
     }
 
-    fn params() -> Params {
+    fn params(_cm: &CoreModel) -> Params {
         VOLUME_PARAMS
     }
 
@@ -751,3 +1528,33 @@ impl EditableFuncs for Volume {
         }
     }
 }
+
+pub struct WindSource;
+impl EditableFuncs for WindSource {
+    fn name() -> &'static str {
+        "Wind Source"
+    }
+
+    fn content(cm: &mut CoreModel, _cc: &mut CoreController) -> Content {
+        Content::Enum(TString::<16>::from_str(cm.control.wind_src.as_str()))
+    }
+
+    fn params(_cm: &CoreModel) -> Params {
+        Params::Enum(EnumParams {
+            variants: [DATA_SOURCE_FRONTEND, DATA_SOURCE_SENSORBOX, "", "", ""],
+        })
+    }
+
+    fn set_content(cm: &mut CoreModel, cc: &mut CoreController, content: Content) {
+        if let Content::Enum(val) = content {
+            let source = DataSource::from(val.as_str());
+            persist::persist_set(
+                cc,
+                cm,
+                Variant::U32(source as u32),
+                PersistenceId::WindSource,
+                Echo::None,
+            );
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::system_of_units::Temperature;
+
+/// Time in seconds the engine data stays valid without a new frame
+const ENGINE_TIMEOUT: u8 = 3;
+/// Minimum RPM of a running motor
+const RUNNING_RPM: f32 = 100.0;
+
+// warning limits, the second value is the hysteresis
+const MIN_SOC: (f32, f32) = (20.0, 5.0);
+const MAX_MOTOR_TEMP: (f32, f32) = (90.0, 5.0);
+const MAX_BATTERY_TEMP: (f32, f32) = (55.0, 3.0);
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum EngineWarning {
+    LowSoc,
+    MotorTemp,
+    BatteryTemp,
+}
+
+/// State of the motor and the motor battery of a self-launcher or FES glider
+///
+/// The data is received from the engine controller via CAN and discarded when the frames
+/// stop. The avionics supply is not part of it, see `Calculated::av_supply_voltage`.
+#[derive(Clone, Copy, Default)]
+pub struct Engine {
+    pub rpm: Option<f32>,
+    pub motor_temp: Option<Temperature>,
+    /// Battery voltage in V
+    pub voltage: Option<f32>,
+    /// Battery current in A
+    pub current: Option<f32>,
+    /// State of charge in %
+    pub soc: Option<f32>,
+    pub battery_temp: Option<Temperature>,
+    pub warning: Option<EngineWarning>,
+    alive_secs: u8,
+}
+
+impl Engine {
+    /// Must be called with every received engine frame
+    pub fn received(&mut self) {
+        self.alive_secs = ENGINE_TIMEOUT;
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.alive_secs > 0
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.rpm.is_some_and(|rpm| rpm > RUNNING_RPM)
+    }
+
+    /// Check the timeout and the limits, should be called every second
+    ///
+    /// Returns a warning when it is raised, a persisting warning is only returned once.
+    pub fn tick_1s(&mut self) -> Option<EngineWarning> {
+        self.alive_secs = self.alive_secs.saturating_sub(1);
+        if self.alive_secs == 0 {
+            *self = Engine::default();
+        }
+
+        let warning = self.check_limits();
+        let raised = match warning {
+            Some(warning) if self.warning != Some(warning) => Some(warning),
+            _ => None,
+        };
+        self.warning = warning;
+        raised
+    }
+
+    fn check_limits(&self) -> Option<EngineWarning> {
+        // the limits are widened by the hysteresis as long as the warning is active
+        let margin = |warning: EngineWarning, limit: (f32, f32)| match self.warning {
+            Some(active) if active == warning => limit.1,
+            _ => 0.0,
+        };
+        let too_hot = |temp: Option<Temperature>, warning, limit: (f32, f32)| {
+            temp.is_some_and(|temp| temp.to_deg_c() > limit.0 - margin(warning, limit))
+        };
+
+        if too_hot(self.motor_temp, EngineWarning::MotorTemp, MAX_MOTOR_TEMP) {
+            Some(EngineWarning::MotorTemp)
+        } else if too_hot(self.battery_temp, EngineWarning::BatteryTemp, MAX_BATTERY_TEMP) {
+            Some(EngineWarning::BatteryTemp)
+        } else if self
+            .soc
+            .is_some_and(|soc| soc < MIN_SOC.0 + margin(EngineWarning::LowSoc, MIN_SOC))
+        {
+            Some(EngineWarning::LowSoc)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system_of_units::FloatToTemperature;
+
+    #[test]
+    fn test_engine_timeout() {
+        let mut engine = Engine::default();
+        assert!(!engine.is_available());
+
+        engine.rpm = Some(4500.0);
+        engine.received();
+        assert!(engine.is_running());
+
+        for _ in 0..ENGINE_TIMEOUT - 1 {
+            engine.tick_1s();
+        }
+        assert!(engine.is_running());
+        engine.tick_1s();
+        assert!(!engine.is_available());
+        assert!(!engine.is_running());
+    }
+
+    #[test]
+    fn test_engine_warnings() {
+        let mut engine = Engine::default();
+        engine.soc = Some(25.0);
+        engine.received();
+        assert!(engine.tick_1s().is_none());
+
+        // low state of charge is raised once and kept within the hysteresis
+        engine.soc = Some(19.0);
+        engine.received();
+        assert!(engine.tick_1s() == Some(EngineWarning::LowSoc));
+        engine.soc = Some(22.0);
+        engine.received();
+        assert!(engine.tick_1s().is_none());
+        assert!(engine.warning == Some(EngineWarning::LowSoc));
+        engine.soc = Some(26.0);
+        engine.received();
+        engine.tick_1s();
+        assert!(engine.warning.is_none());
+
+        // an overheated motor takes precedence
+        engine.soc = Some(10.0);
+        engine.motor_temp = Some(95.0.deg_c());
+        engine.received();
+        assert!(engine.tick_1s() == Some(EngineWarning::MotorTemp));
+    }
+}
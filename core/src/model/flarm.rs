@@ -0,0 +1,256 @@
+use crate::system_of_units::{FloatToLength, Length, Speed};
+use embedded_graphics::geometry::{Angle, AngleUnit};
+
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
+/// Number of aircraft kept in the traffic table
+pub const MAX_TRAFFIC: usize = 8;
+
+/// Data not refreshed within this time [s] is considered as outdated
+pub const FLARM_TIMEOUT: u8 = 5;
+
+/// Alarm level as reported by FLARM
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub enum FlarmAlarmLevel {
+    None,
+    Low,       // 13-18 seconds to impact
+    Important, // 9-12 seconds to impact
+    Urgent,    // 0-8 seconds to impact
+}
+
+impl From<u8> for FlarmAlarmLevel {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => FlarmAlarmLevel::Low,
+            2 => FlarmAlarmLevel::Important,
+            3 => FlarmAlarmLevel::Urgent,
+            _ => FlarmAlarmLevel::None,
+        }
+    }
+}
+
+/// One aircraft of the traffic table, filled by $PFLAA datagrams
+///
+/// Non-directional targets (e.g. Mode-S transponders) have no rel_east, rel_north then contains
+/// the estimated distance.
+#[derive(Clone, Copy)]
+pub struct TrafficItem {
+    pub id: u32,
+    pub alarm_level: FlarmAlarmLevel,
+    pub rel_north: Length,
+    pub rel_east: Option<Length>,
+    pub rel_vertical: Length,
+    pub track: Option<Angle>,
+    pub climb_rate: Option<Speed>,
+    age: u8,
+}
+
+impl TrafficItem {
+    pub fn new(
+        id: u32,
+        alarm_level: FlarmAlarmLevel,
+        rel_north: Length,
+        rel_east: Option<Length>,
+        rel_vertical: Length,
+    ) -> Self {
+        TrafficItem {
+            id,
+            alarm_level,
+            rel_north,
+            rel_east,
+            rel_vertical,
+            track: None,
+            climb_rate: None,
+            age: 0,
+        }
+    }
+
+    /// Horizontal distance to the own aircraft
+    pub fn distance(&self) -> Length {
+        let n = self.rel_north.to_m();
+        match self.rel_east {
+            Some(rel_east) => {
+                let e = rel_east.to_m();
+                (n * n + e * e).sqrt().m()
+            }
+            None => n.abs().m(),
+        }
+    }
+
+    /// True bearing from the own aircraft to the traffic, unknown for non-directional targets
+    pub fn bearing(&self) -> Option<Angle> {
+        self.rel_east
+            .map(|rel_east| rel_east.to_m().atan2(self.rel_north.to_m()).rad())
+    }
+}
+
+/// FLARM status and traffic table
+///
+/// The status is updated by $PFLAU datagrams and contains the most relevant traffic or obstacle.
+/// Surrounding aircraft are kept in a small table, which is filled by $PFLAA datagrams. Both are
+/// aged every second and cleared if FLARM stops sending.
+#[derive(Clone, Copy)]
+pub struct Flarm {
+    pub rx: u8,
+    pub gps_ok: bool,
+    pub alarm_level: FlarmAlarmLevel,
+    pub rel_bearing: Option<Angle>,
+    pub rel_vertical: Length,
+    pub rel_distance: Length,
+    pub alarm_id: u32,
+    age: u8,
+    traffic: [Option<TrafficItem>; MAX_TRAFFIC],
+}
+
+impl Default for Flarm {
+    fn default() -> Self {
+        Flarm {
+            rx: 0,
+            gps_ok: false,
+            alarm_level: FlarmAlarmLevel::None,
+            rel_bearing: None,
+            rel_vertical: 0.0.m(),
+            rel_distance: 0.0.m(),
+            alarm_id: 0,
+            age: FLARM_TIMEOUT,
+            traffic: [None; MAX_TRAFFIC],
+        }
+    }
+}
+
+impl Flarm {
+    /// FLARM has sent a status datagram within the last seconds
+    pub fn is_available(&self) -> bool {
+        self.age < FLARM_TIMEOUT
+    }
+
+    pub fn alarm_active(&self) -> bool {
+        self.is_available() && self.alarm_level != FlarmAlarmLevel::None
+    }
+
+    /// Mark the status as fresh, called after a $PFLAU datagram was parsed
+    pub fn refresh(&mut self) {
+        self.age = 0;
+    }
+
+    /// Insert or update an aircraft in the traffic table
+    ///
+    /// If the table is full, the oldest entry will be replaced.
+    pub fn update_traffic(&mut self, item: TrafficItem) {
+        let mut oldest_idx = 0;
+        let mut oldest_age = 0;
+        for (idx, entry) in self.traffic.iter_mut().enumerate() {
+            match entry {
+                Some(traffic) if traffic.id == item.id => {
+                    *entry = Some(item);
+                    return;
+                }
+                Some(traffic) => {
+                    if traffic.age > oldest_age {
+                        oldest_age = traffic.age;
+                        oldest_idx = idx;
+                    }
+                }
+                None => {
+                    // a free slot is always preferred
+                    oldest_age = u8::MAX;
+                    oldest_idx = idx;
+                }
+            }
+        }
+        self.traffic[oldest_idx] = Some(item);
+    }
+
+    /// Iterate over all aircraft in the traffic table
+    pub fn traffic(&self) -> impl Iterator<Item = &TrafficItem> {
+        self.traffic.iter().flatten()
+    }
+
+    /// Age all data, should be called every second
+    pub fn tick_1s(&mut self) {
+        if self.age < FLARM_TIMEOUT {
+            self.age += 1;
+        } else {
+            self.alarm_level = FlarmAlarmLevel::None;
+        }
+
+        for entry in self.traffic.iter_mut() {
+            if let Some(traffic) = entry {
+                traffic.age += 1;
+                if traffic.age >= FLARM_TIMEOUT {
+                    *entry = None;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    fn item(id: u32) -> TrafficItem {
+        TrafficItem::new(
+            id,
+            FlarmAlarmLevel::None,
+            300.0.m(),
+            Some(400.0.m()),
+            50.0.m(),
+        )
+    }
+
+    #[test]
+    fn test_traffic_item() {
+        let traffic = item(0xDD1234);
+        assert_float_eq!(traffic.distance().to_m(), 500.0);
+        assert_float_eq!(traffic.bearing().unwrap().to_degrees(), 53.13);
+
+        // non-directional target
+        let traffic = TrafficItem::new(1, FlarmAlarmLevel::None, 700.0.m(), None, 50.0.m());
+        assert_float_eq!(traffic.distance().to_m(), 700.0);
+        assert!(traffic.bearing().is_none());
+    }
+
+    #[test]
+    fn test_traffic_table() {
+        let mut flarm = Flarm::default();
+        assert!(!flarm.is_available());
+
+        flarm.update_traffic(item(1));
+        flarm.update_traffic(item(1));
+        assert_eq!(flarm.traffic().count(), 1);
+
+        flarm.tick_1s();
+        for id in 2..=MAX_TRAFFIC as u32 {
+            flarm.update_traffic(item(id));
+        }
+        assert_eq!(flarm.traffic().count(), MAX_TRAFFIC);
+
+        // table is full, the oldest entry is replaced
+        flarm.update_traffic(item(100));
+        assert_eq!(flarm.traffic().count(), MAX_TRAFFIC);
+        assert!(flarm.traffic().all(|t| t.id != 1));
+
+        // outdated entries are removed
+        for _ in 0..FLARM_TIMEOUT {
+            flarm.tick_1s();
+        }
+        assert_eq!(flarm.traffic().count(), 0);
+    }
+
+    #[test]
+    fn test_alarm_timeout() {
+        let mut flarm = Flarm::default();
+        flarm.alarm_level = FlarmAlarmLevel::Urgent;
+        flarm.refresh();
+        assert!(flarm.alarm_active());
+
+        for _ in 0..=FLARM_TIMEOUT {
+            flarm.tick_1s();
+        }
+        assert!(!flarm.alarm_active());
+        assert!(flarm.alarm_level == FlarmAlarmLevel::None);
+    }
+}
@@ -0,0 +1,380 @@
+use crate::{
+    flight_physics::distance_and_course,
+    model::{FlyMode, TcrMode},
+    system_of_units::{Angle, FloatToLength, FloatToSpeed, Length, Speed},
+    utils::Time,
+    CoreModel, Latitude, Longitude,
+};
+
+/// Number of thermals kept in the list, older thermals only count in the totals
+pub const MAX_THERMALS: usize = 16;
+
+/// Snapshot of the position at the beginning or at the end of a thermal
+#[derive(Clone, Copy)]
+struct StatsFix {
+    time: Time,
+    secs: u32,
+    altitude: Length,
+    lat: Latitude,
+    lon: Longitude,
+}
+
+impl StatsFix {
+    fn new(cm: &CoreModel, secs: u32) -> Self {
+        StatsFix {
+            time: *cm.sensor.gps_date_time.time(),
+            secs,
+            altitude: cm.sensor.gps_altitude,
+            lat: cm.sensor.gps_lat,
+            lon: cm.sensor.gps_lon,
+        }
+    }
+}
+
+/// One recorded thermal
+#[derive(Clone, Copy)]
+pub struct Thermal {
+    pub entry: Time,
+    pub exit: Time,
+    /// Duration in s
+    pub duration: u32,
+    pub altitude_gain: Length,
+    /// Distance between entry and exit
+    pub drift: Length,
+    /// Direction to which the thermal has drifted
+    pub drift_course: Angle,
+}
+
+impl Thermal {
+    pub fn avg_climb_rate(&self) -> Speed {
+        if self.duration > 0 {
+            (self.altitude_gain.to_m() / self.duration as f32).m_s()
+        } else {
+            0.0.m_s()
+        }
+    }
+}
+
+/// Statistics of the flights since switching on
+///
+/// Thermals start and end with the changes of TcrMode, which is managed by the thermal climb
+/// rate calculation. The glides between two thermals are summed up to the average cruise speed
+/// and the achieved glide ratio. Data is only collected while the aircraft is flying.
+///
+/// The run time of the motor is counted independently of the flight state, the total run
+/// time is persisted and restored at start-up.
+#[derive(Clone, Copy)]
+pub struct FlightStats {
+    thermals: [Option<Thermal>; MAX_THERMALS],
+    thermal_count: u32,
+    thermal_secs: u32,
+    thermal_gain: Length,
+    circling_secs: u32,
+    flying_secs: u32,
+    cruise_secs: u32,
+    cruise_distance: Length,
+    cruise_loss: Length,
+    tcr_mode: TcrMode,
+    entry: Option<StatsFix>,
+    exit: Option<StatsFix>,
+    cruise_start: Option<StatsFix>,
+    engine_secs: u32,
+    engine_total_secs: u32,
+}
+
+impl Default for FlightStats {
+    fn default() -> Self {
+        FlightStats {
+            thermals: [None; MAX_THERMALS],
+            thermal_count: 0,
+            thermal_secs: 0,
+            thermal_gain: 0.0.m(),
+            circling_secs: 0,
+            flying_secs: 0,
+            cruise_secs: 0,
+            cruise_distance: 0.0.m(),
+            cruise_loss: 0.0.m(),
+            tcr_mode: TcrMode::StraightFlight,
+            entry: None,
+            exit: None,
+            cruise_start: None,
+            engine_secs: 0,
+            engine_total_secs: 0,
+        }
+    }
+}
+
+impl FlightStats {
+    /// Update the statistics, should be called every second
+    pub fn tick_1s(&mut self, cm: &CoreModel, flying: bool) {
+        let fix = StatsFix::new(cm, self.flying_secs + 1);
+        self.update(flying, cm.control.fly_mode, cm.control.tcr_mode, fix);
+    }
+
+    /// Count the run time of the motor, should be called every second
+    pub fn engine_tick_1s(&mut self, running: bool) {
+        if running {
+            self.engine_secs += 1;
+            self.engine_total_secs += 1;
+        }
+    }
+
+    fn update(&mut self, flying: bool, fly_mode: FlyMode, tcr_mode: TcrMode, fix: StatsFix) {
+        if !flying {
+            self.tcr_mode = tcr_mode;
+            self.entry = None;
+            self.exit = None;
+            self.cruise_start = None;
+            return;
+        }
+
+        self.flying_secs += 1;
+        if fly_mode == FlyMode::Circling {
+            self.circling_secs += 1;
+        }
+
+        match (self.tcr_mode, tcr_mode) {
+            (TcrMode::StraightFlight, TcrMode::Climbing) => self.thermal_entry(fix),
+            (TcrMode::Climbing, TcrMode::Transition) => self.exit = Some(fix),
+            (TcrMode::Transition, TcrMode::Climbing) => self.exit = None,
+            (TcrMode::Transition, TcrMode::StraightFlight) => self.thermal_finished(),
+            _ => (),
+        }
+        self.tcr_mode = tcr_mode;
+    }
+
+    fn thermal_entry(&mut self, fix: StatsFix) {
+        // the glide from the last thermal ends here
+        if let Some(start) = self.cruise_start.take() {
+            let (distance, _) = distance_and_course(start.lat, start.lon, fix.lat, fix.lon);
+            self.cruise_secs += fix.secs - start.secs;
+            self.cruise_distance += distance;
+            self.cruise_loss += start.altitude - fix.altitude;
+        }
+        self.entry = Some(fix);
+        self.exit = None;
+    }
+
+    fn thermal_finished(&mut self) {
+        if let (Some(entry), Some(exit)) = (self.entry.take(), self.exit.take()) {
+            let (drift, drift_course) =
+                distance_and_course(entry.lat, entry.lon, exit.lat, exit.lon);
+            let thermal = Thermal {
+                entry: entry.time,
+                exit: exit.time,
+                duration: exit.secs - entry.secs,
+                altitude_gain: exit.altitude - entry.altitude,
+                drift,
+                drift_course,
+            };
+            self.thermals[self.thermal_count as usize % MAX_THERMALS] = Some(thermal);
+            self.thermal_count += 1;
+            self.thermal_secs += thermal.duration;
+            self.thermal_gain += thermal.altitude_gain;
+            self.cruise_start = Some(exit);
+        }
+    }
+
+    /// Number of thermals in the list
+    pub fn len(&self) -> usize {
+        (self.thermal_count as usize).min(MAX_THERMALS)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.thermal_count == 0
+    }
+
+    /// Thermal by index, 0 is the oldest thermal still in the list
+    pub fn thermal(&self, idx: usize) -> Option<Thermal> {
+        if idx >= self.len() {
+            return None;
+        }
+        let first = self.thermal_count as usize - self.len();
+        self.thermals[(first + idx) % MAX_THERMALS]
+    }
+
+    pub fn last_thermal(&self) -> Option<Thermal> {
+        match self.len() {
+            0 => None,
+            len => self.thermal(len - 1),
+        }
+    }
+
+    /// Number of thermals since switching on
+    pub fn thermal_count(&self) -> u32 {
+        self.thermal_count
+    }
+
+    /// Weighted average climb rate of the last thermals, the latest thermal weighs most
+    pub fn recent_climb_rate(&self, count: usize) -> Option<Speed> {
+        let len = self.len();
+        let count = count.min(len);
+        let mut sum = 0.0;
+        let mut weights = 0.0;
+        for age in 0..count {
+            if let Some(thermal) = self.thermal(len - 1 - age) {
+                let weight = (count - age) as f32;
+                sum += thermal.avg_climb_rate().to_m_s() * weight;
+                weights += weight;
+            }
+        }
+        if weights > 0.0 {
+            Some((sum / weights).m_s())
+        } else {
+            None
+        }
+    }
+
+    /// Average climb rate of all thermals
+    pub fn avg_climb_rate(&self) -> Option<Speed> {
+        if self.thermal_secs > 0 {
+            Some((self.thermal_gain.to_m() / self.thermal_secs as f32).m_s())
+        } else {
+            None
+        }
+    }
+
+    /// Percentage of the flight time spent circling
+    pub fn circling_percent(&self) -> Option<f32> {
+        if self.flying_secs > 0 {
+            Some(self.circling_secs as f32 * 100.0 / self.flying_secs as f32)
+        } else {
+            None
+        }
+    }
+
+    /// Average ground speed between the thermals
+    pub fn cruise_speed(&self) -> Option<Speed> {
+        if self.cruise_secs > 0 {
+            Some((self.cruise_distance.to_m() / self.cruise_secs as f32).m_s())
+        } else {
+            None
+        }
+    }
+
+    /// Motor run time in s since switching on
+    pub fn engine_secs(&self) -> u32 {
+        self.engine_secs
+    }
+
+    /// Total motor run time in s, restored from the EEPROM
+    pub fn engine_total_secs(&self) -> u32 {
+        self.engine_total_secs
+    }
+
+    pub fn set_engine_total_secs(&mut self, secs: u32) {
+        self.engine_total_secs = secs;
+    }
+
+    /// Achieved glide ratio between the thermals
+    pub fn glide_ratio(&self) -> Option<f32> {
+        if self.cruise_loss.to_m() > 1.0 {
+            Some(self.cruise_distance.to_m() / self.cruise_loss.to_m())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{utils::DateTime, F64ToCoord};
+
+    struct Flight {
+        stats: FlightStats,
+        secs: u32,
+        altitude: f32,
+        lat: f64,
+    }
+
+    impl Flight {
+        fn new() -> Self {
+            Flight {
+                stats: FlightStats::default(),
+                secs: 0,
+                altitude: 1000.0,
+                lat: 50.0,
+            }
+        }
+
+        // fly for some seconds with constant climb rate and speed to the north
+        fn fly(&mut self, secs: u32, tcr_mode: TcrMode, climb_rate: f32, lat_per_sec: f64) {
+            let fly_mode = match tcr_mode {
+                TcrMode::Climbing => FlyMode::Circling,
+                _ => FlyMode::StraightFlight,
+            };
+            for _ in 0..secs {
+                let fix = StatsFix {
+                    time: *DateTime::new().time(),
+                    secs: self.secs,
+                    altitude: self.altitude.m(),
+                    lat: Latitude(self.lat.deg()),
+                    lon: Longitude(8.0_f64.deg()),
+                };
+                self.stats.update(true, fly_mode, tcr_mode, fix);
+                self.secs += 1;
+                self.altitude += climb_rate;
+                self.lat += lat_per_sec;
+            }
+        }
+    }
+
+    #[test]
+    fn test_thermals_and_glides() {
+        let mut flight = Flight::new();
+        assert!(flight.stats.is_empty());
+        assert!(flight.stats.glide_ratio().is_none());
+
+        // two thermals of 100 s with 2 m/s, 30 s transition, 30 s straight flight
+        for _ in 0..2 {
+            flight.fly(100, TcrMode::Climbing, 2.0, 0.0);
+            flight.fly(30, TcrMode::Transition, -1.0, 0.0003);
+            flight.fly(30, TcrMode::StraightFlight, -1.0, 0.0003);
+        }
+
+        assert_eq!(flight.stats.len(), 2);
+        let thermal = flight.stats.last_thermal().unwrap();
+        assert_eq!(thermal.duration, 100);
+        assert!((thermal.altitude_gain.to_m() - 200.0).abs() < 0.1);
+        assert!((thermal.avg_climb_rate().to_m_s() - 2.0).abs() < 0.01);
+        assert!((flight.stats.avg_climb_rate().unwrap().to_m_s() - 2.0).abs() < 0.01);
+
+        // one glide of 60 s between the thermals, 0.0003° latitude are about 33.4 m
+        assert!((flight.stats.cruise_speed().unwrap().to_m_s() - 33.4).abs() < 0.5);
+        assert!((flight.stats.glide_ratio().unwrap() - 33.4).abs() < 0.5);
+
+        // 200 s of 320 s circling
+        assert!((flight.stats.circling_percent().unwrap() - 62.5).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_thermal_list_overflow() {
+        let mut flight = Flight::new();
+        for _ in 0..MAX_THERMALS + 2 {
+            flight.fly(10, TcrMode::Climbing, 1.0, 0.0);
+            flight.fly(1, TcrMode::Transition, -1.0, 0.0);
+            flight.fly(1, TcrMode::StraightFlight, -1.0, 0.0);
+        }
+        assert_eq!(flight.stats.len(), MAX_THERMALS);
+        assert!(flight.stats.thermal(MAX_THERMALS).is_none());
+        assert_eq!(flight.stats.thermal(0).unwrap().duration, 10);
+    }
+
+    #[test]
+    fn test_recent_climb_rate() {
+        let mut flight = Flight::new();
+        assert!(flight.stats.recent_climb_rate(3).is_none());
+
+        // thermals with 1, 2 and 3 m/s, the latest one weighs most
+        for climb_rate in [1.0, 2.0, 3.0] {
+            flight.fly(10, TcrMode::Climbing, climb_rate, 0.0);
+            flight.fly(1, TcrMode::Transition, -1.0, 0.0);
+            flight.fly(1, TcrMode::StraightFlight, -1.0, 0.0);
+        }
+        assert_eq!(flight.stats.thermal_count(), 3);
+        let expected = (1.0 + 2.0 * 2.0 + 3.0 * 3.0) / 6.0;
+        assert!((flight.stats.recent_climb_rate(3).unwrap().to_m_s() - expected).abs() < 0.01);
+        assert!((flight.stats.recent_climb_rate(1).unwrap().to_m_s() - 3.0).abs() < 0.01);
+    }
+}
@@ -53,6 +53,14 @@ pub const SPEED_TO_FLY_IDX: usize = 9;
 pub const LANDING_GEAR_ALARM_IDX: usize = 10;
 pub const AVERAGE_CLIMB_RATE_IDX: usize = 11;
 pub const RESET_CONFIG_IDX: usize = 12;
+pub const UNITS_IDX: usize = 13;
+pub const FINAL_GLIDE_IDX: usize = 14;
+pub const FLIGHT_STATS_IDX: usize = 15;
+pub const CAN_BUS_IDX: usize = 16;
+pub const REMOTE_BUTTONS_IDX: usize = 17;
+pub const VARIO_TONE_IDX: usize = 18;
+pub const SPEED_ALARMS_IDX: usize = 19;
+pub const AUTO_QNH_IDX: usize = 20;
 
 pub const MENU_LIST: &[Menu] = &[
     ROOT,
@@ -68,6 +76,14 @@ pub const MENU_LIST: &[Menu] = &[
     LANDING_GEAR_ALARM,
     AVERAGE_CLIMB_RATE,
     RESET_CONFIG,
+    UNITS,
+    FINAL_GLIDE,
+    FLIGHT_STATS,
+    CAN_BUS,
+    REMOTE_BUTTONS,
+    VARIO_TONE,
+    SPEED_ALARMS,
+    AUTO_QNH,
 ];
 
 pub const ROOT: Menu = Menu {
@@ -92,10 +108,18 @@ pub const FLIGHT_MENU: Menu = Menu {
             content: MenuItemContent::EditItem(Editable::PilotWeight),
             next_menu_idx: ROOT_IDX,
         },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::FlapSetting),
+            next_menu_idx: ROOT_IDX,
+        },
         MenuItem {
             content: MenuItemContent::EditItem(Editable::Display),
             next_menu_idx: ROOT_IDX,
         },
+        MenuItem {
+            content: MenuItemContent::MenuItem(),
+            next_menu_idx: FLIGHT_STATS_IDX,
+        },
         MenuItem {
             content: MenuItemContent::EditItem(Editable::Return),
             next_menu_idx: ROOT_IDX,
@@ -181,10 +205,22 @@ pub const ADVANCED_SETTINGS: Menu = Menu {
             content: MenuItemContent::EditItem(Editable::UserProfile),
             next_menu_idx: ADVANCED_SETTINGS_IDX,
         },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::VarioSource),
+            next_menu_idx: ADVANCED_SETTINGS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::NmeaProfile),
+            next_menu_idx: ADVANCED_SETTINGS_IDX,
+        },
         MenuItem {
             content: MenuItemContent::EditItem(Editable::CenterFrequency),
             next_menu_idx: ADVANCED_SETTINGS_IDX,
         },
+        MenuItem {
+            content: MenuItemContent::MenuItem(),
+            next_menu_idx: VARIO_TONE_IDX,
+        },
         MenuItem {
             content: MenuItemContent::EditItem(Editable::BatteryGood),
             next_menu_idx: ADVANCED_SETTINGS_IDX,
@@ -197,6 +233,14 @@ pub const ADVANCED_SETTINGS: Menu = Menu {
             content: MenuItemContent::EditItem(Editable::FlashControl),
             next_menu_idx: ADVANCED_SETTINGS_IDX,
         },
+        MenuItem {
+            content: MenuItemContent::MenuItem(),
+            next_menu_idx: UNITS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::MenuItem(),
+            next_menu_idx: FINAL_GLIDE_IDX,
+        },
         MenuItem {
             content: MenuItemContent::MenuItem(),
             next_menu_idx: RESET_CONFIG_IDX,
@@ -213,10 +257,26 @@ pub const ADVANCED_SETTINGS: Menu = Menu {
             content: MenuItemContent::MenuItem(),
             next_menu_idx: LANDING_GEAR_ALARM_IDX,
         },
+        MenuItem {
+            content: MenuItemContent::MenuItem(),
+            next_menu_idx: SPEED_ALARMS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::MenuItem(),
+            next_menu_idx: AUTO_QNH_IDX,
+        },
         MenuItem {
             content: MenuItemContent::MenuItem(),
             next_menu_idx: DRAIN_SETTINGS_IDX,
         },
+        MenuItem {
+            content: MenuItemContent::MenuItem(),
+            next_menu_idx: CAN_BUS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::MenuItem(),
+            next_menu_idx: REMOTE_BUTTONS_IDX,
+        },
         MenuItem {
             content: MenuItemContent::EditItem(Editable::Return),
             next_menu_idx: VARIO_SETINGS_IDX,
@@ -267,6 +327,10 @@ pub const POLAR_SETTINGS: Menu = Menu {
             content: MenuItemContent::EditItem(Editable::ReferenceWeight),
             next_menu_idx: POLAR_SETTINGS_IDX,
         },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::FlapSetting),
+            next_menu_idx: POLAR_SETTINGS_IDX,
+        },
         MenuItem {
             content: MenuItemContent::EditItem(Editable::PolarValueV1),
             next_menu_idx: POLAR_SETTINGS_IDX,
@@ -416,6 +480,10 @@ pub const SPEED_TO_FLY: Menu = Menu {
     name: "Speed to Fly",
     level: 3,
     items: &[
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::AutoMc),
+            next_menu_idx: SPEED_TO_FLY_IDX,
+        },
         MenuItem {
             content: MenuItemContent::EditItem(Editable::TcCircleHysteresis),
             next_menu_idx: SPEED_TO_FLY_IDX,
@@ -511,3 +579,227 @@ pub const RESET_CONFIG: Menu = Menu {
         },
     ],
 };
+
+pub const UNITS: Menu = Menu {
+    name: "Units",
+    level: 3,
+    items: &[
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::SpeedUnit),
+            next_menu_idx: UNITS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::VerticalSpeedUnit),
+            next_menu_idx: UNITS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::AltitudeUnit),
+            next_menu_idx: UNITS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::DistanceUnit),
+            next_menu_idx: UNITS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::MassUnit),
+            next_menu_idx: UNITS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::PressureUnit),
+            next_menu_idx: UNITS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::TemperatureUnit),
+            next_menu_idx: UNITS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::Return),
+            next_menu_idx: ADVANCED_SETTINGS_IDX,
+        },
+    ],
+};
+
+pub const FINAL_GLIDE: Menu = Menu {
+    name: "Final Glide",
+    level: 3,
+    items: &[
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::GlideTarget),
+            next_menu_idx: FINAL_GLIDE_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::SafetyAltitude),
+            next_menu_idx: FINAL_GLIDE_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::SetHome),
+            next_menu_idx: FINAL_GLIDE_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::HomeElevation),
+            next_menu_idx: FINAL_GLIDE_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::WindSource),
+            next_menu_idx: FINAL_GLIDE_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::Return),
+            next_menu_idx: ADVANCED_SETTINGS_IDX,
+        },
+    ],
+};
+
+pub const FLIGHT_STATS: Menu = Menu {
+    name: "Flight Stats",
+    level: 2,
+    items: &[
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::Thermals),
+            next_menu_idx: FLIGHT_STATS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::AvgThermal),
+            next_menu_idx: FLIGHT_STATS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::CirclingPercent),
+            next_menu_idx: FLIGHT_STATS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::CruiseSpeed),
+            next_menu_idx: FLIGHT_STATS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::GlideRatio),
+            next_menu_idx: FLIGHT_STATS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::EngineTime),
+            next_menu_idx: FLIGHT_STATS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::Return),
+            next_menu_idx: FLIGHT_MENU_IDX,
+        },
+    ],
+};
+
+pub const CAN_BUS: Menu = Menu {
+    name: "CAN Bus",
+    level: 3,
+    items: &[
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::BusDevices),
+            next_menu_idx: CAN_BUS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::BusTraffic),
+            next_menu_idx: CAN_BUS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::Return),
+            next_menu_idx: ADVANCED_SETTINGS_IDX,
+        },
+    ],
+};
+
+pub const REMOTE_BUTTONS: Menu = Menu {
+    name: "Remote Buttons",
+    level: 3,
+    items: &[
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::RemoteButton1),
+            next_menu_idx: REMOTE_BUTTONS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::RemoteButton2),
+            next_menu_idx: REMOTE_BUTTONS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::RemoteButton3),
+            next_menu_idx: REMOTE_BUTTONS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::RemoteButton4),
+            next_menu_idx: REMOTE_BUTTONS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::Return),
+            next_menu_idx: ADVANCED_SETTINGS_IDX,
+        },
+    ],
+};
+
+pub const VARIO_TONE: Menu = Menu {
+    name: "Vario Tone",
+    level: 3,
+    items: &[
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::ToneProfile),
+            next_menu_idx: VARIO_TONE_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::ToneSinkLimit),
+            next_menu_idx: VARIO_TONE_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::ToneLiftLimit),
+            next_menu_idx: VARIO_TONE_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::ToneSinkPitch),
+            next_menu_idx: VARIO_TONE_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::ToneLiftPitch),
+            next_menu_idx: VARIO_TONE_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::ToneSlowBeeps),
+            next_menu_idx: VARIO_TONE_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::ToneFastBeeps),
+            next_menu_idx: VARIO_TONE_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::Return),
+            next_menu_idx: ADVANCED_SETTINGS_IDX,
+        },
+    ],
+};
+
+pub const SPEED_ALARMS: Menu = Menu {
+    name: "Speed Alarms",
+    level: 3,
+    items: &[
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::StallMargin),
+            next_menu_idx: SPEED_ALARMS_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::Return),
+            next_menu_idx: ADVANCED_SETTINGS_IDX,
+        },
+    ],
+};
+
+pub const AUTO_QNH: Menu = Menu {
+    name: "Auto QNH",
+    level: 3,
+    items: &[
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::AutoQnh),
+            next_menu_idx: AUTO_QNH_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::FieldElevation),
+            next_menu_idx: AUTO_QNH_IDX,
+        },
+        MenuItem {
+            content: MenuItemContent::EditItem(Editable::Return),
+            next_menu_idx: ADVANCED_SETTINGS_IDX,
+        },
+    ],
+};
@@ -1,24 +1,39 @@
 mod calculated;
+mod can_bus;
 mod config;
 mod control;
 mod device;
 mod device_const;
 pub mod editable;
+mod engine;
+mod flarm;
+mod flight_stats;
 pub mod menu;
+pub(crate) mod navigation;
 mod sensor;
+mod tone_profile;
+mod units;
 
 use crate::flight_physics::{polar_store, GliderData};
 use calculated::Calculated;
+pub use can_bus::{BusDevice, CanBus};
 pub use config::{Config, DisplayActive, DisplayTheme, OverlayActive, TypeOfInfo};
 pub use control::{
-    Control, DataSource, EditMode, FlyMode, SystemState, TcrMode, VarioMode, VarioModeControl,
+    AutoMcMode, AutoQnhMode, Control, DataSource, EditMode, FlyMode, NmeaProfile,
+    SensorboxUpdate, SystemState, TcrMode, VarioMode, VarioModeControl, VarioSource,
 };
 use device::Device;
 pub use device_const::{
     DeviceConst, DisplaySizes, HorizonSizes, Images, Misc, Palette, Sizes, VarioSizes,
 };
 pub use editable::Editable;
+pub use engine::{Engine, EngineWarning};
+pub use flarm::{Flarm, FlarmAlarmLevel, TrafficItem};
+pub use flight_stats::FlightStats;
+pub use navigation::{GlideTarget, Navigation, Waypoint};
 pub use sensor::{GpsState, Sensor};
+pub use tone_profile::{CustomTone, Tone, TonePreset};
+pub use units::{SpeedUnit, Units, VerticalSpeedUnit};
 
 /// Data model for the entire device
 ///
@@ -30,33 +45,46 @@ pub use sensor::{GpsState, Sensor};
 #[derive(Clone, Copy)]
 pub struct CoreModel {
     pub calculated: Calculated,
+    pub can_bus: CanBus,
     pub config: Config,
     pub control: Control,
     pub device: Device,
     pub device_const: &'static DeviceConst,
+    pub engine: Engine,
+    pub flarm: Flarm,
+    pub flight_stats: FlightStats,
     pub glider_data: GliderData,
+    pub navigation: Navigation,
     pub sensor: Sensor,
 }
 
 impl CoreModel {
     pub fn new(device_const: &'static DeviceConst, uuid: u32) -> Self {
         let calculated = Calculated::default();
+        let can_bus = CanBus::default();
         let config = Config::default(&device_const.dark_theme, uuid);
         let control = Control::default();
         let device = Device::default();
-        let glider_data = GliderData {
-            basic_glider_data: *polar_store::from_raw_idx(config.glider_idx as usize),
-            ..Default::default()
-        };
+        let engine = Engine::default();
+        let flarm = Flarm::default();
+        let flight_stats = FlightStats::default();
+        let mut glider_data = GliderData::default();
+        glider_data.select_glider(*polar_store::from_raw_idx(config.glider_idx as usize));
+        let navigation = Navigation::default();
 
         let sensor = Sensor::default();
         CoreModel {
             calculated,
+            can_bus,
             config,
             control,
             device,
             device_const,
+            engine,
+            flarm,
+            flight_stats,
             glider_data,
+            navigation,
             sensor,
         }
     }
@@ -0,0 +1,102 @@
+use crate::{
+    flight_physics::FinalGlide,
+    system_of_units::{FloatToLength, Length},
+    Coord, Latitude, Longitude,
+};
+
+/// Destination of the final glide calculation
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum GlideTarget {
+    Waypoint,
+    Home,
+}
+
+pub const GLIDE_TARGET_WAYPOINT: &str = "Waypoint";
+pub const GLIDE_TARGET_HOME: &str = "Home";
+
+impl From<u8> for GlideTarget {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => GlideTarget::Home,
+            _ => GlideTarget::Waypoint,
+        }
+    }
+}
+
+impl From<&str> for GlideTarget {
+    fn from(value: &str) -> Self {
+        match value {
+            GLIDE_TARGET_HOME => GlideTarget::Home,
+            _ => GlideTarget::Waypoint,
+        }
+    }
+}
+
+impl GlideTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GlideTarget::Waypoint => GLIDE_TARGET_WAYPOINT,
+            GlideTarget::Home => GLIDE_TARGET_HOME,
+        }
+    }
+}
+
+/// A position with its elevation above MSL, if known
+#[derive(Clone, Copy)]
+pub struct Waypoint {
+    pub lat: Latitude,
+    pub lon: Longitude,
+    pub elevation: Option<Length>,
+}
+
+impl Default for Waypoint {
+    fn default() -> Self {
+        Waypoint {
+            lat: Latitude(Coord::default()),
+            lon: Longitude(Coord::default()),
+            elevation: None,
+        }
+    }
+}
+
+/// Navigation data for the final glide
+///
+/// The waypoint is received via NMEA from a navigation software, the home position is selected
+/// locally and stored in the EEPROM. The final glide is calculated once per second to the
+/// destination chosen by target.
+#[derive(Clone, Copy)]
+pub struct Navigation {
+    pub target: GlideTarget,
+    pub safety_altitude: Length,
+    pub waypoint: Option<Waypoint>,
+    pub home: Option<Waypoint>,
+    pub final_glide: Option<FinalGlide>,
+}
+
+impl Default for Navigation {
+    fn default() -> Self {
+        Navigation {
+            target: GlideTarget::Waypoint,
+            safety_altitude: 300.0.m(),
+            waypoint: None,
+            home: None,
+            final_glide: None,
+        }
+    }
+}
+
+impl Navigation {
+    /// Returns the destination of the final glide, if one is available
+    pub fn destination(&self) -> Option<Waypoint> {
+        match self.target {
+            GlideTarget::Waypoint => self.waypoint,
+            GlideTarget::Home => self.home,
+        }
+    }
+
+    /// Returns the home position, which is created if it does not exist yet
+    pub fn home_mut(&mut self) -> &mut Waypoint {
+        self.home.get_or_insert_with(Waypoint::default)
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     flight_physics::{PressureAltitude, WindVector},
     system_of_units::{
         Acceleration, AngularVelocity, FloatToAcceleration, FloatToAngularVelocity, FloatToLength,
-        FloatToSpeed, Length, Pressure, Speed,
+        FloatToSpeed, Length, Pressure, Speed, Temperature,
     },
     AirSpeed, DateTime, Density, F64ToCoord, Latitude, Longitude,
 };
@@ -29,6 +29,8 @@ pub struct Sensor {
     pub euler_roll: Angle,
     pub euler_pitch: Angle,
     pub euler_yaw: Angle,
+    /// Position of the flap sensor in percent, if a sensor is available
+    pub flap_position: Option<f32>,
     pub g_force: Acceleration,
     pub gps_altitude: Length,
     pub gps_date_time: DateTime,
@@ -39,7 +41,11 @@ pub struct Sensor {
     pub gps_ground_speed: Speed,
     pub gps_sats: u8,
     pub gps_state: GpsState,
+    /// Relative humidity in percent, if a sensor is available
+    pub humidity: Option<f32>,
     pub nick_angle: Angle,
+    /// Outside air temperature, if a sensor is available
+    pub oat: Option<Temperature>,
     pub pressure: Pressure,
     pub pressure_altitude: PressureAltitude,
     pub slip_angle: Angle,
@@ -61,6 +67,7 @@ impl Default for Sensor {
             euler_roll: 0.0_f32.deg(),
             euler_pitch: 0.0_f32.deg(),
             euler_yaw: 0.0_f32.deg(),
+            flap_position: None,
             g_force: 9.81.m_s2(),
             gps_altitude: 0.0.m(),
             gps_date_time: DateTime::new(),
@@ -71,7 +78,9 @@ impl Default for Sensor {
             gps_ground_speed: 0.0.m_s(),
             gps_sats: 0,
             gps_state: GpsState::NoGps,
+            humidity: None,
             nick_angle: 0.0_f32.deg(),
+            oat: None,
             pressure: Pressure::AT_NN(),
             pressure_altitude: PressureAltitude::default(),
             slip_angle: 0.0_f32.deg(),
@@ -0,0 +1,327 @@
+use num_enum::FromPrimitive;
+
+/// Built-in vario tone profiles and the profile designed by the pilot
+#[derive(Clone, Copy, PartialEq, FromPrimitive)]
+#[repr(u8)]
+pub enum TonePreset {
+    #[default]
+    LarusClassic,
+    LxLike,
+    BorgeltLike,
+    LiftOnly,
+    Custom,
+}
+
+impl TonePreset {
+    pub const COUNT: usize = 5;
+
+    pub fn profile<'a>(&self, custom: &'a CustomTone) -> ToneProfile<'a> {
+        match self {
+            TonePreset::LarusClassic => LARUS_CLASSIC,
+            TonePreset::LxLike => LX_LIKE,
+            TonePreset::BorgeltLike => BORGELT_LIKE,
+            TonePreset::LiftOnly => LIFT_ONLY,
+            TonePreset::Custom => ToneProfile {
+                name: "Custom",
+                dead_band: (custom.sink_limit, custom.lift_limit()),
+                points: &custom.points,
+            },
+        }
+    }
+}
+
+/// Point of a tone profile, the tone between two points is interpolated linearly
+#[derive(Clone, Copy)]
+pub struct TonePoint {
+    /// Climb rate in m/s
+    pub climb: f32,
+    /// Factor of the center frequency
+    pub pitch: f32,
+    /// Beeps per second, 0.0 is a continuous tone
+    pub pulse_rate: f32,
+}
+
+const fn tp(climb: f32, pitch: f32, pulse_rate: f32) -> TonePoint {
+    TonePoint {
+        climb,
+        pitch,
+        pulse_rate,
+    }
+}
+
+/// Piecewise-linear vario tone profile
+///
+/// The points must be sorted by the climb rate. Two points with the same climb rate switch the
+/// tone at this climb rate, e.g. from a continuous sink tone to beeps. Climb rates outside the
+/// points use the first or last point.
+#[derive(Clone, Copy)]
+pub struct ToneProfile<'a> {
+    pub name: &'static str,
+    /// Climb rates between these limits in m/s are silent
+    pub dead_band: (f32, f32),
+    pub points: &'a [TonePoint],
+}
+
+/// Tone profile designed by the pilot
+///
+/// A continuous tone rises from the sink pitch at -5 m/s to the center frequency at the lift
+/// limit. From there on it beeps, rising to the lift pitch and from the slow to the fast pulse
+/// rate at 5 m/s. Climb rates between the sink limit and the lift limit are silent.
+#[derive(Clone, Copy)]
+pub struct CustomTone {
+    sink_limit: f32,
+    points: [TonePoint; 4],
+}
+
+impl Default for CustomTone {
+    // the Larus classic tone with linear instead of exponential pitch
+    fn default() -> Self {
+        Self {
+            sink_limit: 0.0,
+            points: [
+                tp(-5.0, 0.50, 0.0),
+                tp(0.0, 1.00, 0.0),
+                tp(0.0, 1.00, 1.65),
+                tp(5.0, 2.00, 3.30),
+            ],
+        }
+    }
+}
+
+impl CustomTone {
+    /// Upper end of the silent sink range in m/s
+    pub fn sink_limit(&self) -> f32 {
+        self.sink_limit
+    }
+
+    pub fn set_sink_limit(&mut self, climb: f32) {
+        self.sink_limit = climb;
+    }
+
+    /// Climb rate in m/s from which on the tone beeps
+    pub fn lift_limit(&self) -> f32 {
+        self.points[1].climb
+    }
+
+    pub fn set_lift_limit(&mut self, climb: f32) {
+        self.points[1].climb = climb;
+        self.points[2].climb = climb;
+    }
+
+    /// Factor of the center frequency at -5 m/s
+    pub fn sink_pitch(&self) -> f32 {
+        self.points[0].pitch
+    }
+
+    pub fn set_sink_pitch(&mut self, pitch: f32) {
+        self.points[0].pitch = pitch;
+    }
+
+    /// Factor of the center frequency at 5 m/s
+    pub fn lift_pitch(&self) -> f32 {
+        self.points[3].pitch
+    }
+
+    pub fn set_lift_pitch(&mut self, pitch: f32) {
+        self.points[3].pitch = pitch;
+    }
+
+    /// Beeps per second at the lift limit
+    pub fn slow_pulse_rate(&self) -> f32 {
+        self.points[2].pulse_rate
+    }
+
+    pub fn set_slow_pulse_rate(&mut self, pulse_rate: f32) {
+        self.points[2].pulse_rate = pulse_rate;
+    }
+
+    /// Beeps per second at 5 m/s
+    pub fn fast_pulse_rate(&self) -> f32 {
+        self.points[3].pulse_rate
+    }
+
+    pub fn set_fast_pulse_rate(&mut self, pulse_rate: f32) {
+        self.points[3].pulse_rate = pulse_rate;
+    }
+}
+
+/// Sound parameters of one tone
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Tone {
+    pub frequency: u16,
+    pub continuous: bool,
+    /// Number of oscillations of one beep and of one pause
+    pub duty_cycle: u16,
+}
+
+// The classic Larus tone, two octaves from -5 to 5 m/s and beeps of 200 oscillations in lift
+const LARUS_CLASSIC: ToneProfile<'static> = ToneProfile {
+    name: "Larus classic",
+    dead_band: (0.0, 0.0),
+    points: &[
+        tp(-5.0, 0.50, 0.0),
+        tp(-2.5, 0.71, 0.0),
+        tp(0.0, 1.00, 0.0),
+        tp(0.0, 1.00, 1.65),
+        tp(2.5, 1.41, 2.33),
+        tp(5.0, 2.00, 3.30),
+    ],
+};
+
+// Quiet in weak sink, the beeps accelerate faster than the pitch rises
+const LX_LIKE: ToneProfile<'static> = ToneProfile {
+    name: "LX like",
+    dead_band: (-1.0, 0.0),
+    points: &[
+        tp(-5.0, 0.45, 0.0),
+        tp(-1.0, 0.80, 0.0),
+        tp(0.0, 1.00, 0.0),
+        tp(0.0, 1.00, 2.0),
+        tp(1.0, 1.15, 3.0),
+        tp(3.0, 1.50, 5.0),
+        tp(5.0, 1.80, 7.0),
+    ],
+};
+
+// Beeps only in usable lift, a sink tone only in strong sink
+const BORGELT_LIKE: ToneProfile<'static> = ToneProfile {
+    name: "Borgelt like",
+    dead_band: (-2.5, 0.3),
+    points: &[
+        tp(-5.0, 0.50, 0.0),
+        tp(-2.5, 0.60, 0.0),
+        tp(0.3, 1.00, 0.0),
+        tp(0.3, 1.00, 1.5),
+        tp(2.0, 1.30, 3.0),
+        tp(5.0, 1.90, 5.0),
+    ],
+};
+
+// Silent below 0.2 m/s climb
+const LIFT_ONLY: ToneProfile<'static> = ToneProfile {
+    name: "Lift only",
+    dead_band: (f32::MIN, 0.2),
+    points: &[
+        tp(0.2, 1.05, 1.8),
+        tp(2.5, 1.41, 2.33),
+        tp(5.0, 2.00, 3.30),
+    ],
+};
+
+impl ToneProfile<'_> {
+    /// Tone of a climb rate in m/s, None within the dead band
+    pub fn tone(&self, climb: f32, center_frequency: f32) -> Option<Tone> {
+        if climb > self.dead_band.0 && climb < self.dead_band.1 {
+            return None;
+        }
+        Some(self.curve(climb, center_frequency))
+    }
+
+    /// Tone of a climb rate in m/s without dead band
+    pub fn curve(&self, climb: f32, center_frequency: f32) -> Tone {
+        let (pitch, pulse_rate) = self.interpolate(climb);
+        let frequency = center_frequency * pitch;
+        // a very low pulse rate is a continuous tone
+        let (continuous, duty_cycle) = if pulse_rate < 0.1 {
+            (true, 200)
+        } else {
+            (false, (frequency / (2.0 * pulse_rate)) as u16)
+        };
+        Tone {
+            frequency: frequency as u16,
+            continuous,
+            duty_cycle,
+        }
+    }
+
+    fn interpolate(&self, climb: f32) -> (f32, f32) {
+        let first = &self.points[0];
+        if climb < first.climb {
+            return (first.pitch, first.pulse_rate);
+        }
+        for segment in self.points.windows(2) {
+            let (p0, p1) = (&segment[0], &segment[1]);
+            if climb < p1.climb {
+                let ratio = (climb - p0.climb) / (p1.climb - p0.climb);
+                return (
+                    p0.pitch + (p1.pitch - p0.pitch) * ratio,
+                    p0.pulse_rate + (p1.pulse_rate - p0.pulse_rate) * ratio,
+                );
+            }
+        }
+        let last = &self.points[self.points.len() - 1];
+        (last.pitch, last.pulse_rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_larus_classic() {
+        let custom = CustomTone::default();
+        let profile = TonePreset::LarusClassic.profile(&custom);
+
+        // continuous sink tone, beeps of about 200 oscillations from 0 m/s on
+        let tone = profile.tone(-1.0, 659.0).unwrap();
+        assert!(tone.continuous);
+        let tone = profile.tone(0.0, 659.0).unwrap();
+        assert!(!tone.continuous);
+        assert_eq!(tone.frequency, 659);
+        assert!((tone.duty_cycle as i32 - 200).abs() <= 1);
+
+        // clamped outside the points
+        assert_eq!(profile.tone(8.0, 659.0).unwrap().frequency, 1318);
+        assert_eq!(profile.tone(-8.0, 659.0).unwrap().frequency, 329);
+    }
+
+    #[test]
+    fn test_dead_band_and_pulse_rate() {
+        let custom = CustomTone::default();
+        let profile = TonePreset::BorgeltLike.profile(&custom);
+        assert!(profile.tone(-1.0, 659.0).is_none());
+        assert!(profile.tone(0.2, 659.0).is_none());
+        assert!(profile.tone(-3.0, 659.0).unwrap().continuous);
+        assert!(profile.curve(-1.0, 659.0).continuous);
+
+        // stronger lift beeps faster and higher
+        let weak = profile.tone(1.0, 659.0).unwrap();
+        let strong = profile.tone(4.0, 659.0).unwrap();
+        assert!(strong.frequency > weak.frequency);
+        let beep_secs = |tone: Tone| tone.duty_cycle as f32 / tone.frequency as f32;
+        assert!(beep_secs(strong) < beep_secs(weak));
+
+        assert!(TonePreset::LiftOnly
+            .profile(&custom)
+            .tone(-4.0, 659.0)
+            .is_none());
+        assert!(TonePreset::from(TonePreset::COUNT as u8) == TonePreset::LarusClassic);
+    }
+
+    #[test]
+    fn test_custom_tone() {
+        let mut custom = CustomTone::default();
+        let classic = TonePreset::LarusClassic.profile(&custom);
+        let profile = TonePreset::Custom.profile(&custom);
+        for climb in [-5.0, 0.0, 5.0] {
+            assert_eq!(profile.tone(climb, 659.0), classic.tone(climb, 659.0));
+        }
+
+        custom.set_sink_limit(-2.0);
+        custom.set_lift_limit(0.5);
+        custom.set_lift_pitch(3.0);
+        custom.set_fast_pulse_rate(6.0);
+        let profile = TonePreset::Custom.profile(&custom);
+        assert!(profile.tone(-1.0, 659.0).is_none());
+        assert!(profile.tone(0.4, 659.0).is_none());
+        assert!(profile.tone(-3.0, 659.0).unwrap().continuous);
+
+        let tone = profile.tone(0.5, 659.0).unwrap();
+        assert!(!tone.continuous);
+        assert_eq!(tone.frequency, 659);
+        let tone = profile.tone(5.0, 659.0).unwrap();
+        assert_eq!(tone.frequency, 1977);
+        assert_eq!(tone.duty_cycle, 164);
+    }
+}
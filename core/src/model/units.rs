@@ -0,0 +1,540 @@
+use crate::system_of_units::{
+    FloatToLength, FloatToMass, FloatToPressure, FloatToSpeed, FloatToTemperature, Length, Mass,
+    Pressure, Speed, Temperature,
+};
+
+/// Unit used to display horizontal speeds like airspeed, speed to fly and wind
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum SpeedUnit {
+    KmH,
+    Knots,
+    Mph,
+}
+
+pub const SPEED_UNIT_KM_H: &str = "km/h";
+pub const SPEED_UNIT_KNOTS: &str = "kt";
+pub const SPEED_UNIT_MPH: &str = "mph";
+
+impl From<u8> for SpeedUnit {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => SpeedUnit::Knots,
+            2 => SpeedUnit::Mph,
+            _ => SpeedUnit::KmH,
+        }
+    }
+}
+
+impl From<&str> for SpeedUnit {
+    fn from(value: &str) -> Self {
+        match value {
+            SPEED_UNIT_KNOTS => SpeedUnit::Knots,
+            SPEED_UNIT_MPH => SpeedUnit::Mph,
+            _ => SpeedUnit::KmH,
+        }
+    }
+}
+
+impl SpeedUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpeedUnit::KmH => SPEED_UNIT_KM_H,
+            SpeedUnit::Knots => SPEED_UNIT_KNOTS,
+            SpeedUnit::Mph => SPEED_UNIT_MPH,
+        }
+    }
+
+    /// Convert a speed into a number in this unit
+    pub fn value(&self, speed: Speed) -> f32 {
+        match self {
+            SpeedUnit::KmH => speed.to_km_h(),
+            SpeedUnit::Knots => speed.to_kt(),
+            SpeedUnit::Mph => speed.to_mph(),
+        }
+    }
+
+    /// Create a speed from a number in this unit
+    pub fn speed(&self, value: f32) -> Speed {
+        match self {
+            SpeedUnit::KmH => value.km_h(),
+            SpeedUnit::Knots => value.kt(),
+            SpeedUnit::Mph => value.mph(),
+        }
+    }
+}
+
+/// Unit used to display climb rates, sink rates and the Mac Cready value
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum VerticalSpeedUnit {
+    MS,
+    Knots,
+    FtMin,
+}
+
+pub const VERTICAL_SPEED_UNIT_M_S: &str = "m/s";
+pub const VERTICAL_SPEED_UNIT_KNOTS: &str = "kt";
+pub const VERTICAL_SPEED_UNIT_FT_MIN: &str = "fpm";
+
+impl From<u8> for VerticalSpeedUnit {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => VerticalSpeedUnit::Knots,
+            2 => VerticalSpeedUnit::FtMin,
+            _ => VerticalSpeedUnit::MS,
+        }
+    }
+}
+
+impl From<&str> for VerticalSpeedUnit {
+    fn from(value: &str) -> Self {
+        match value {
+            VERTICAL_SPEED_UNIT_KNOTS => VerticalSpeedUnit::Knots,
+            VERTICAL_SPEED_UNIT_FT_MIN => VerticalSpeedUnit::FtMin,
+            _ => VerticalSpeedUnit::MS,
+        }
+    }
+}
+
+impl VerticalSpeedUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VerticalSpeedUnit::MS => VERTICAL_SPEED_UNIT_M_S,
+            VerticalSpeedUnit::Knots => VERTICAL_SPEED_UNIT_KNOTS,
+            VerticalSpeedUnit::FtMin => VERTICAL_SPEED_UNIT_FT_MIN,
+        }
+    }
+
+    /// Convert a vertical speed into a number in this unit
+    pub fn value(&self, speed: Speed) -> f32 {
+        match self {
+            VerticalSpeedUnit::MS => speed.to_m_s(),
+            VerticalSpeedUnit::Knots => speed.to_kt(),
+            VerticalSpeedUnit::FtMin => speed.to_ft_min(),
+        }
+    }
+
+    /// Create a vertical speed from a number in this unit
+    pub fn speed(&self, value: f32) -> Speed {
+        match self {
+            VerticalSpeedUnit::MS => value.m_s(),
+            VerticalSpeedUnit::Knots => value.kt(),
+            VerticalSpeedUnit::FtMin => value.ft_min(),
+        }
+    }
+
+    /// Typical increment of this unit, used by the editors
+    pub fn increment(&self) -> f32 {
+        match self {
+            VerticalSpeedUnit::MS => 0.1,
+            VerticalSpeedUnit::Knots => 0.2,
+            VerticalSpeedUnit::FtMin => 20.0,
+        }
+    }
+
+    /// Decimal places needed to display a value with the resolution of increment()
+    pub fn dec_places(&self) -> u8 {
+        match self {
+            VerticalSpeedUnit::FtMin => 0,
+            _ => 1,
+        }
+    }
+
+    /// Vertical speed between two marks of the vario scale
+    ///
+    /// The scale of the wallpaper is divided into steps of 1 m/s. For the other units a round
+    /// value close to 1 m/s is chosen, so that the labels of the scale can be replaced.
+    pub fn scale_step(&self) -> Speed {
+        match self {
+            VerticalSpeedUnit::MS => 1.0.m_s(),
+            VerticalSpeedUnit::Knots => 2.0.kt(),
+            VerticalSpeedUnit::FtMin => 200.0.ft_min(),
+        }
+    }
+
+    /// Value of a scale mark in the displayed unit, ft/min is labeled in hundreds
+    pub fn scale_label(&self, mark: i32) -> i32 {
+        match self {
+            VerticalSpeedUnit::MS => mark,
+            VerticalSpeedUnit::Knots | VerticalSpeedUnit::FtMin => mark * 2,
+        }
+    }
+}
+
+/// Unit used to display altitudes and vertical separations
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum AltitudeUnit {
+    Meter,
+    Feet,
+}
+
+pub const ALTITUDE_UNIT_METER: &str = "m";
+pub const ALTITUDE_UNIT_FEET: &str = "ft";
+
+impl From<u8> for AltitudeUnit {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => AltitudeUnit::Feet,
+            _ => AltitudeUnit::Meter,
+        }
+    }
+}
+
+impl From<&str> for AltitudeUnit {
+    fn from(value: &str) -> Self {
+        match value {
+            ALTITUDE_UNIT_FEET => AltitudeUnit::Feet,
+            _ => AltitudeUnit::Meter,
+        }
+    }
+}
+
+impl AltitudeUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AltitudeUnit::Meter => ALTITUDE_UNIT_METER,
+            AltitudeUnit::Feet => ALTITUDE_UNIT_FEET,
+        }
+    }
+
+    /// Convert an altitude into a number in this unit
+    pub fn value(&self, altitude: Length) -> f32 {
+        match self {
+            AltitudeUnit::Meter => altitude.to_m(),
+            AltitudeUnit::Feet => altitude.to_ft(),
+        }
+    }
+
+    /// Create an altitude from a number in this unit
+    pub fn length(&self, value: f32) -> Length {
+        match self {
+            AltitudeUnit::Meter => value.m(),
+            AltitudeUnit::Feet => value.ft(),
+        }
+    }
+}
+
+/// Unit used to display horizontal distances
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum DistanceUnit {
+    Km,
+    NauticalMile,
+    Mile,
+}
+
+pub const DISTANCE_UNIT_KM: &str = "km";
+pub const DISTANCE_UNIT_NAUTICAL_MILE: &str = "NM";
+pub const DISTANCE_UNIT_MILE: &str = "mi";
+
+impl From<u8> for DistanceUnit {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => DistanceUnit::NauticalMile,
+            2 => DistanceUnit::Mile,
+            _ => DistanceUnit::Km,
+        }
+    }
+}
+
+impl From<&str> for DistanceUnit {
+    fn from(value: &str) -> Self {
+        match value {
+            DISTANCE_UNIT_NAUTICAL_MILE => DistanceUnit::NauticalMile,
+            DISTANCE_UNIT_MILE => DistanceUnit::Mile,
+            _ => DistanceUnit::Km,
+        }
+    }
+}
+
+impl DistanceUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DistanceUnit::Km => DISTANCE_UNIT_KM,
+            DistanceUnit::NauticalMile => DISTANCE_UNIT_NAUTICAL_MILE,
+            DistanceUnit::Mile => DISTANCE_UNIT_MILE,
+        }
+    }
+
+    /// Convert a distance into a number in this unit
+    pub fn value(&self, distance: Length) -> f32 {
+        match self {
+            DistanceUnit::Km => distance.to_km(),
+            DistanceUnit::NauticalMile => distance.to_NM(),
+            DistanceUnit::Mile => distance.to_mi(),
+        }
+    }
+
+    /// Create a distance from a number in this unit
+    pub fn length(&self, value: f32) -> Length {
+        match self {
+            DistanceUnit::Km => value.km(),
+            DistanceUnit::NauticalMile => value.NM(),
+            DistanceUnit::Mile => value.mi(),
+        }
+    }
+}
+
+/// Unit used to display the pilot weight, the water ballast and the glider masses
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum MassUnit {
+    Kg,
+    Lb,
+}
+
+pub const MASS_UNIT_KG: &str = "kg";
+pub const MASS_UNIT_LB: &str = "lb";
+
+impl From<u8> for MassUnit {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => MassUnit::Lb,
+            _ => MassUnit::Kg,
+        }
+    }
+}
+
+impl From<&str> for MassUnit {
+    fn from(value: &str) -> Self {
+        match value {
+            MASS_UNIT_LB => MassUnit::Lb,
+            _ => MassUnit::Kg,
+        }
+    }
+}
+
+impl MassUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MassUnit::Kg => MASS_UNIT_KG,
+            MassUnit::Lb => MASS_UNIT_LB,
+        }
+    }
+
+    /// Convert a mass into a number in this unit
+    pub fn value(&self, mass: Mass) -> f32 {
+        match self {
+            MassUnit::Kg => mass.to_kg(),
+            MassUnit::Lb => mass.to_lb(),
+        }
+    }
+
+    /// Create a mass from a number in this unit
+    pub fn mass(&self, value: f32) -> Mass {
+        match self {
+            MassUnit::Kg => value.kg(),
+            MassUnit::Lb => value.lb(),
+        }
+    }
+}
+
+/// Unit used to display the QNH
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum PressureUnit {
+    Hpa,
+    InHg,
+}
+
+pub const PRESSURE_UNIT_HPA: &str = "hPa";
+pub const PRESSURE_UNIT_INHG: &str = "inHg";
+
+impl From<u8> for PressureUnit {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => PressureUnit::InHg,
+            _ => PressureUnit::Hpa,
+        }
+    }
+}
+
+impl From<&str> for PressureUnit {
+    fn from(value: &str) -> Self {
+        match value {
+            PRESSURE_UNIT_INHG => PressureUnit::InHg,
+            _ => PressureUnit::Hpa,
+        }
+    }
+}
+
+impl PressureUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PressureUnit::Hpa => PRESSURE_UNIT_HPA,
+            PressureUnit::InHg => PRESSURE_UNIT_INHG,
+        }
+    }
+
+    /// Convert a pressure into a number in this unit
+    pub fn value(&self, pressure: Pressure) -> f32 {
+        match self {
+            PressureUnit::Hpa => pressure.to_hpa(),
+            PressureUnit::InHg => pressure.to_inhg(),
+        }
+    }
+
+    /// Create a pressure from a number in this unit
+    pub fn pressure(&self, value: f32) -> Pressure {
+        match self {
+            PressureUnit::Hpa => value.hpa(),
+            PressureUnit::InHg => value.inhg(),
+        }
+    }
+
+    /// Decimal places to display a QNH in this unit
+    pub fn dec_places(&self) -> u8 {
+        match self {
+            PressureUnit::Hpa => 0,
+            PressureUnit::InHg => 2,
+        }
+    }
+}
+
+/// Unit used to display the outside air temperature
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+}
+
+pub const TEMPERATURE_UNIT_CELSIUS: &str = "°C";
+pub const TEMPERATURE_UNIT_FAHRENHEIT: &str = "°F";
+
+impl From<u8> for TemperatureUnit {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => TemperatureUnit::Fahrenheit,
+            _ => TemperatureUnit::Celsius,
+        }
+    }
+}
+
+impl From<&str> for TemperatureUnit {
+    fn from(value: &str) -> Self {
+        match value {
+            TEMPERATURE_UNIT_FAHRENHEIT => TemperatureUnit::Fahrenheit,
+            _ => TemperatureUnit::Celsius,
+        }
+    }
+}
+
+impl TemperatureUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => TEMPERATURE_UNIT_CELSIUS,
+            TemperatureUnit::Fahrenheit => TEMPERATURE_UNIT_FAHRENHEIT,
+        }
+    }
+
+    /// Convert a temperature into a number in this unit
+    pub fn value(&self, temperature: Temperature) -> f32 {
+        match self {
+            TemperatureUnit::Celsius => temperature.to_deg_c(),
+            TemperatureUnit::Fahrenheit => temperature.to_deg_f(),
+        }
+    }
+
+    /// Create a temperature from a number in this unit
+    pub fn temperature(&self, value: f32) -> Temperature {
+        match self {
+            TemperatureUnit::Celsius => value.deg_c(),
+            TemperatureUnit::Fahrenheit => value.deg_f(),
+        }
+    }
+}
+
+/// Unit preferences of the user profile
+///
+/// All quantities are kept in SI units in the model. The preferences are only used to convert
+/// them for the views and the editors. They are stored as one packed u32, four bits per unit.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Units {
+    pub speed: SpeedUnit,
+    pub vertical_speed: VerticalSpeedUnit,
+    pub altitude: AltitudeUnit,
+    pub distance: DistanceUnit,
+    pub mass: MassUnit,
+    pub pressure: PressureUnit,
+    pub temperature: TemperatureUnit,
+}
+
+impl Default for Units {
+    fn default() -> Self {
+        Units {
+            speed: SpeedUnit::KmH,
+            vertical_speed: VerticalSpeedUnit::MS,
+            altitude: AltitudeUnit::Meter,
+            distance: DistanceUnit::Km,
+            mass: MassUnit::Kg,
+            pressure: PressureUnit::Hpa,
+            temperature: TemperatureUnit::Celsius,
+        }
+    }
+}
+
+impl From<u32> for Units {
+    fn from(value: u32) -> Self {
+        let nibble = |idx: u32| ((value >> (idx * 4)) & 0x0f) as u8;
+        Units {
+            speed: SpeedUnit::from(nibble(0)),
+            vertical_speed: VerticalSpeedUnit::from(nibble(1)),
+            altitude: AltitudeUnit::from(nibble(2)),
+            distance: DistanceUnit::from(nibble(3)),
+            mass: MassUnit::from(nibble(4)),
+            pressure: PressureUnit::from(nibble(5)),
+            temperature: TemperatureUnit::from(nibble(6)),
+        }
+    }
+}
+
+impl Units {
+    pub fn as_u32(&self) -> u32 {
+        self.speed as u32
+            | (self.vertical_speed as u32) << 4
+            | (self.altitude as u32) << 8
+            | (self.distance as u32) << 12
+            | (self.mass as u32) << 16
+            | (self.pressure as u32) << 20
+            | (self.temperature as u32) << 24
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_float_eq;
+
+    #[test]
+    fn test_pack_units() {
+        assert!(Units::from(0) == Units::default());
+
+        let units = Units {
+            speed: SpeedUnit::Knots,
+            vertical_speed: VerticalSpeedUnit::FtMin,
+            altitude: AltitudeUnit::Feet,
+            distance: DistanceUnit::Mile,
+            mass: MassUnit::Lb,
+            pressure: PressureUnit::InHg,
+            temperature: TemperatureUnit::Fahrenheit,
+        };
+        assert_eq!(units.as_u32(), 0x111_2121);
+        assert!(Units::from(units.as_u32()) == units);
+    }
+
+    #[test]
+    fn test_convert_units() {
+        let units = VerticalSpeedUnit::Knots;
+        assert_float_eq!(units.value(1.0.m_s()), 1.944);
+        assert_float_eq!(units.speed(units.value(1.5.m_s())).to_m_s(), 1.5);
+        assert_float_eq!(VerticalSpeedUnit::FtMin.scale_step().to_m_s(), 1.016);
+        assert_float_eq!(SpeedUnit::Mph.value(100.0.km_h()), 62.14);
+        assert_float_eq!(MassUnit::Lb.value(90.0.kg()), 198.42);
+        assert_float_eq!(PressureUnit::InHg.value(1013.25.hpa()), 29.92);
+        assert_float_eq!(AltitudeUnit::Feet.length(1000.0).to_m(), 304.8);
+        assert_float_eq!(TemperatureUnit::Fahrenheit.value(20.0.deg_c()), 68.0);
+    }
+}
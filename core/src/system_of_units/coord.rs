@@ -10,7 +10,7 @@ const RAD_PER_DEGREE: f64 = PI / 180.0;
 #[allow(dead_code)]
 const DEGREE_PER_RAD: f64 = 180.0 / PI;
 
-#[derive(Copy, Clone, Default)]
+#[derive(Copy, Clone, Debug, Default)]
 pub struct Coord(pub f64);
 
 #[allow(dead_code)]
@@ -61,7 +61,7 @@ impl F64ToCoord for f64 {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct Latitude(pub Coord);
 
 impl uDisplayFormatted for Latitude {
@@ -96,7 +96,7 @@ impl uDisplayFormatted for Latitude {
         }
     }
 }
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct Longitude(pub Coord);
 
 impl uDisplayFormatted for Longitude {
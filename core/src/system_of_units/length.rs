@@ -8,7 +8,7 @@ use crate::{basic_ops, Float, CENTI, FOOT, INCH, KILO, MILE, MILLI, NAUTICAL_MIL
 /// Length is a measure of distance. In the International System of Quantities, length is a
 /// quantity with dimension distance (see [Wikipedia](https://en.wikipedia.org/wiki/Length)).
 /// SI unit name is metre, unit symbol is m.
-#[derive(Copy, Clone, Default)]
+#[derive(Copy, Clone, Debug, Default)]
 pub struct Length(pub Float);
 basic_ops!(Length);
 
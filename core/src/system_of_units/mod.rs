@@ -7,6 +7,7 @@ mod length;
 mod mass;
 mod pressure;
 mod speed;
+mod temperature;
 
 use core::f32::consts::PI;
 
@@ -20,6 +21,7 @@ pub use length::{FloatToLength, Length};
 pub use mass::{FloatToMass, Mass};
 pub use pressure::{FloatToPressure, Pressure};
 pub use speed::{FloatToSpeed, Speed};
+pub use temperature::{FloatToTemperature, Temperature};
 
 pub type Float = f32;
 
@@ -34,6 +36,12 @@ pub(crate) const INCH: Float = 0.0254; // inch -> m
 // mass
 pub(crate) const POUND: Float = 0.45359237; // lb -> kg
 
+// pressure
+pub(crate) const INCH_OF_MERCURY: Float = 3386.389; // inHg -> Pa
+
+// temperature
+pub(crate) const ZERO_CELSIUS: Float = 273.15; // °C -> K
+
 // acceleration
 pub(crate) const STANDARD_GRAVITY: Float = 9.80665; // m/s²
 
@@ -3,7 +3,7 @@ use core::{
     ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
 };
 
-use crate::{basic_ops, Float, ISA_PRESSURE_AT_NN};
+use crate::{basic_ops, Float, INCH_OF_MERCURY, ISA_PRESSURE_AT_NN};
 
 /// Pressure (symbol: p or P) is the force applied perpendicular to the surface
 /// of an object per unit area over which that force is distributed. Gauge
@@ -42,6 +42,12 @@ impl Pressure {
         Pressure(value * 100.0)
     }
 
+    /// Create an instance of type Pressure from a float number in inch of mercury
+    #[inline]
+    pub fn from_inhg(value: Float) -> Self {
+        Pressure(value * INCH_OF_MERCURY)
+    }
+
     /// Extract a float number in the unit kilogram per meter and second²
     #[inline]
     pub fn to_kg_ms2(self) -> Float {
@@ -59,6 +65,12 @@ impl Pressure {
     pub fn to_hpa(self) -> Float {
         self.0 * 0.01
     }
+
+    /// Extract a float number in the unit inch of mercury
+    #[inline]
+    pub fn to_inhg(self) -> Float {
+        self.0 / INCH_OF_MERCURY
+    }
 }
 
 /// Trait to convert data to the struct [Pressure]
@@ -72,6 +84,9 @@ pub trait FloatToPressure {
 
     /// Create an instance of type Pressure from a float number in hecto pascal
     fn hpa(self) -> Pressure;
+
+    /// Create an instance of type Pressure from a float number in inch of mercury
+    fn inhg(self) -> Pressure;
 }
 
 impl FloatToPressure for Float {
@@ -87,4 +102,8 @@ impl FloatToPressure for Float {
     fn hpa(self) -> Pressure {
         Pressure::from_hpa(self)
     }
+    #[inline]
+    fn inhg(self) -> Pressure {
+        Pressure::from_inhg(self)
+    }
 }
@@ -11,7 +11,7 @@ use crate::system_of_units::{Float, FOOT, HOUR, KILO, MILE, MINUTE, NAUTICAL_MIL
 /// its position per unit of time; it is thus a scalar quantity
 /// ([Wikipedia](https://en.wikipedia.org/wiki/Speed)).
 /// SI unit name is metre per second, unit symbol m/s.
-#[derive(Copy, Clone, Default)]
+#[derive(Copy, Clone, Debug, Default)]
 pub struct Speed(pub Float);
 basic_ops!(Speed);
 
@@ -0,0 +1,82 @@
+use core::{
+    cmp::{Ordering, PartialEq},
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::{basic_ops, Float, ZERO_CELSIUS};
+
+/// Thermodynamic temperature is a quantity defined in thermodynamics as distinct from kinetic
+/// theory or statistical mechanics.
+///
+/// ([Wikipedia](https://en.wikipedia.org/wiki/Thermodynamic_temperature)).
+///
+/// SI unit name is kelvin, unit symbol is K.
+#[derive(Copy, Clone, Default)]
+pub struct Temperature(pub Float);
+basic_ops!(Temperature);
+
+#[allow(dead_code)]
+impl Temperature {
+    /// Create an instance of type Temperature from a float number in kelvin
+    #[inline]
+    pub fn from_k(value: Float) -> Self {
+        Temperature(value)
+    }
+
+    /// Create an instance of type Temperature from a float number in degree celsius
+    #[inline]
+    pub fn from_deg_c(value: Float) -> Self {
+        Temperature(value + ZERO_CELSIUS)
+    }
+
+    /// Create an instance of type Temperature from a float number in degree fahrenheit
+    #[inline]
+    pub fn from_deg_f(value: Float) -> Self {
+        Temperature((value - 32.0) / 1.8 + ZERO_CELSIUS)
+    }
+
+    /// Extract a float number in the unit kelvin
+    #[inline]
+    pub fn to_k(self) -> Float {
+        self.0
+    }
+
+    /// Extract a float number in the unit degree celsius
+    #[inline]
+    pub fn to_deg_c(self) -> Float {
+        self.0 - ZERO_CELSIUS
+    }
+
+    /// Extract a float number in the unit degree fahrenheit
+    #[inline]
+    pub fn to_deg_f(self) -> Float {
+        (self.0 - ZERO_CELSIUS) * 1.8 + 32.0
+    }
+}
+
+/// Trait to convert data to the struct [Temperature]
+pub trait FloatToTemperature {
+    /// Create an instance of type [Temperature] from a number in kelvin
+    fn k(self) -> Temperature;
+
+    /// Create an instance of type [Temperature] from a number in degree celsius
+    fn deg_c(self) -> Temperature;
+
+    /// Create an instance of type [Temperature] from a number in degree fahrenheit
+    fn deg_f(self) -> Temperature;
+}
+
+impl FloatToTemperature for Float {
+    #[inline]
+    fn k(self) -> Temperature {
+        Temperature::from_k(self)
+    }
+    #[inline]
+    fn deg_c(self) -> Temperature {
+        Temperature::from_deg_c(self)
+    }
+    #[inline]
+    fn deg_f(self) -> Temperature {
+        Temperature::from_deg_f(self)
+    }
+}
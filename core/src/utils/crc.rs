@@ -4,7 +4,11 @@
 /// for providing this function a second time is that no thread protection measures are required
 /// when using this solution, as is the case when using singular hardware.
 pub fn stm32_crc(data: &[u32]) -> u32 {
-    let mut crc: u32 = 0xffffffff;
+    stm32_crc_update(0xffffffff, data)
+}
+
+/// Continue a CRC calculation with further data, e.g. if the data arrives in blocks
+pub fn stm32_crc_update(mut crc: u32, data: &[u32]) -> u32 {
     for w in data {
         for val in w.to_be_bytes() {
             crc ^= (val as u32) << 24;
@@ -17,6 +17,34 @@ pub struct Time {
     sec: u8,
 }
 
+impl Date {
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl Time {
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn min(&self) -> u8 {
+        self.min
+    }
+
+    pub fn sec(&self) -> u8 {
+        self.sec
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct DateTime {
     date: Date,
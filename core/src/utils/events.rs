@@ -5,6 +5,8 @@ pub enum Event {
     KeyItem(KeyEvent),
     DeviceItem(DeviceEvent),
     InputItem(InputPinState),
+    /// Button of a CAN stick remote or button panel was pressed
+    RemoteItem(u8),
 }
 
 #[repr(u8)]
@@ -43,6 +45,45 @@ pub enum DeviceEvent {
     PrepareFwUpload,
     UploadInProgress,
     UploadFinished,
+    /// A newer sensorbox image was found on the SD card
+    SensorboxFwAvailable(FwImage),
+    /// Part of the sensorbox image as requested by SdCardCmd::SensorboxRead
+    SensorboxFwBlock(FwBlock),
+}
+
+/// Number of bytes read from the image file at once
+pub const FW_BLOCK_SIZE: usize = 96;
+
+/// Firmware image of another device on the CAN bus
+#[derive(Clone, Copy, PartialEq)]
+pub struct FwImage {
+    pub sw_version: SwVersion,
+    pub len: u32,
+}
+
+/// Part of a firmware image, the last block of an image may be shorter
+#[derive(Clone, Copy, PartialEq)]
+pub struct FwBlock {
+    pub offset: u32,
+    pub len: u8,
+    pub data: [u8; FW_BLOCK_SIZE],
+}
+
+impl FwBlock {
+    pub fn new(offset: u32, data: &[u8]) -> Self {
+        let len = data.len().min(FW_BLOCK_SIZE);
+        let mut block = FwBlock {
+            offset,
+            len: len as u8,
+            data: [0xff; FW_BLOCK_SIZE],
+        };
+        block.data[..len].copy_from_slice(&data[..len]);
+        block
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len as usize]
+    }
 }
 
 #[derive(Clone, Copy, PartialEq)]
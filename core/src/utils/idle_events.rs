@@ -1,4 +1,4 @@
-use crate::{DateTime, PersistenceId, PersistenceItem};
+use crate::{DateTime, HwVersion, IgcFix, IgcHeader, PersistenceId, PersistenceItem, SwVersion};
 use heapless::spsc::{Consumer, Producer, Queue};
 
 #[derive(Debug, Copy, Clone)]
@@ -56,10 +56,20 @@ pub enum ResetReason {
     ConfigChanged,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone)]
 pub enum SdCardCmd {
     SwUpdateAccepted,
     SwUpdateCanceled,
+    /// Takeoff detected, create a new IGC file and write the header
+    IgcStart(IgcHeader),
+    /// Append a B record to the open IGC file
+    IgcFix(IgcFix),
+    /// Landing detected, close the IGC file
+    IgcStop,
+    /// Look for a sensorbox image newer than the given versions
+    SensorboxCheck(HwVersion, SwVersion),
+    /// Read FW_BLOCK_SIZE bytes of the sensorbox image from the given offset
+    SensorboxRead(u32),
 }
 
 // This queue transports the configuration PersItems from controller to the idle loop.
@@ -0,0 +1,291 @@
+use heapless::String;
+use tfmt::uwrite;
+
+use crate::{
+    model::GpsState, system_of_units::Angle, CoreModel, DateTime, Latitude, Length, Longitude,
+    Speed, VarioMode,
+};
+
+/// IGC manufacturer code of the Larus frontend (X = not IGC approved)
+pub const IGC_MANUFACTURER: &str = "XLR";
+
+/// Length of a B record including the extensions and CR LF
+pub const IGC_FIX_LEN: usize = 51;
+
+/// Maximum length of the complete file header
+pub const IGC_HEADER_LEN: usize = 320;
+
+/// Length of an 8.3 short file name
+pub const IGC_FILE_NAME_LEN: usize = 12;
+
+/// Extensions of the B record, the positions are 1 based as required by the IGC spec
+///
+/// TAS true airspeed km/h, VAT vario m/s * 10, WDI wind direction °, WVE wind speed km/h,
+/// XVM vario mode (V = vario, S = speed to fly)
+const IGC_EXTENSIONS: &str = "I053638TAS3942VAT4345WDI4648WVE4949XVM";
+
+/// The data of the file header, which is sent with the takeoff
+#[derive(Debug, Copy, Clone)]
+pub struct IgcHeader {
+    pub date_time: DateTime,
+    pub serial: u32,
+    pub glider_type: &'static str,
+}
+
+impl IgcHeader {
+    pub fn new(cm: &CoreModel) -> Self {
+        IgcHeader {
+            date_time: cm.sensor.gps_date_time,
+            serial: cm.config.uuid,
+            glider_type: cm.glider_data.basic_glider_data.name,
+        }
+    }
+
+    /// Three characters logger id derived from the serial number
+    pub fn logger_id(&self) -> [u8; 3] {
+        let mut id = [0_u8; 3];
+        let mut val = self.serial;
+        for c in id.iter_mut().rev() {
+            *c = base36(val % 36);
+            val /= 36;
+        }
+        id
+    }
+
+    /// Short IGC file name YMDCXXXF.IGC
+    ///
+    /// flight is the number of the flight of the day, starting with 1. Only 8.3 file names are
+    /// supported by the file system of the devices, so the long IGC file name is not used.
+    pub fn file_name(&self, flight: u8) -> String<IGC_FILE_NAME_LEN> {
+        let date = self.date_time.date();
+        let id = self.logger_id();
+        let mut name = String::new();
+        for c in [
+            base36((date.year() % 10) as u32),
+            base36(date.month() as u32),
+            base36(date.day() as u32),
+            IGC_MANUFACTURER.as_bytes()[0],
+            id[0],
+            id[1],
+            id[2],
+            base36(flight as u32),
+        ] {
+            let _ = name.push(c as char);
+        }
+        let _ = name.push_str(".IGC");
+        name
+    }
+
+    /// A, H and I records of the flight, ready to be written to the file
+    pub fn to_string(&self) -> String<IGC_HEADER_LEN> {
+        let date = self.date_time.date();
+        let id = self.logger_id();
+        let id = core::str::from_utf8(&id).unwrap_or("000");
+        let mut s = String::new();
+        let _ = uwrite!(
+            s,
+            "A{}{}LARUS\r\n\
+            HFDTEDATE:{:02}{:02}{:02},01\r\n\
+            HFPLTPILOTINCHARGE:\r\n\
+            HFGTYGLIDERTYPE:{}\r\n\
+            HFFTYFRTYPE:LARUS,FRONTEND\r\n\
+            HFDTMGPSDATUM:WGS84\r\n\
+            HFPRSPRESSALTSENSOR:LARUS,SENSORBOX\r\n\
+            {}\r\n",
+            IGC_MANUFACTURER,
+            id,
+            date.day(),
+            date.month(),
+            date.year() % 100,
+            self.glider_type,
+            IGC_EXTENSIONS,
+        );
+        s
+    }
+}
+
+/// One fix of the flight, will be written as B record
+#[derive(Debug, Copy, Clone)]
+pub struct IgcFix {
+    pub date_time: DateTime,
+    pub lat: Latitude,
+    pub lon: Longitude,
+    pub valid: bool,
+    pub pressure_altitude: Length,
+    pub gnss_altitude: Length,
+    pub tas: Speed,
+    pub vario: Speed,
+    pub wind_angle: Angle,
+    pub wind_speed: Speed,
+    pub vario_mode: VarioMode,
+}
+
+impl IgcFix {
+    pub fn new(cm: &CoreModel) -> Self {
+        IgcFix {
+            date_time: cm.sensor.gps_date_time,
+            lat: cm.sensor.gps_lat,
+            lon: cm.sensor.gps_lon,
+            valid: cm.sensor.gps_state != GpsState::NoGps,
+            pressure_altitude: cm.sensor.pressure_altitude.qne_altitude(),
+            gnss_altitude: cm.sensor.gps_altitude,
+            tas: cm.sensor.airspeed.tas(),
+            vario: cm.sensor.climb_rate,
+            wind_angle: cm.sensor.wind_vector.angle(),
+            wind_speed: cm.sensor.wind_vector.speed(),
+            vario_mode: cm.control.vario_mode,
+        }
+    }
+
+    /// B record with the extensions declared in the I record
+    pub fn to_string(&self) -> String<IGC_FIX_LEN> {
+        let time = self.date_time.time();
+        let (lat_deg, lat_min, lat_hemi) = split_coord(self.lat.0.to_deg(), b'N', b'S');
+        let (lon_deg, lon_min, lon_hemi) = split_coord(self.lon.0.to_deg(), b'E', b'W');
+        let vario = clamp_round(self.vario.to_m_s() * 10.0, -999, 999);
+        let wind_dir = clamp_round(self.wind_angle.to_degrees(), -360, 720).rem_euclid(360);
+
+        let mut s = String::new();
+        let _ = uwrite!(
+            s,
+            "B{:02}{:02}{:02}{:02}{:05}{}{:03}{:05}{}{}",
+            time.hour(),
+            time.min(),
+            time.sec(),
+            lat_deg,
+            lat_min,
+            lat_hemi as char,
+            lon_deg,
+            lon_min,
+            lon_hemi as char,
+            if self.valid { 'A' } else { 'V' },
+        );
+        push_signed(
+            &mut s,
+            clamp_round(self.pressure_altitude.to_m(), -9999, 99999),
+            5,
+        );
+        push_signed(
+            &mut s,
+            clamp_round(self.gnss_altitude.to_m(), -9999, 99999),
+            5,
+        );
+        push_signed(&mut s, clamp_round(self.tas.to_km_h(), 0, 999), 3);
+        push_signed(&mut s, vario, 4);
+        push_signed(&mut s, wind_dir, 3);
+        push_signed(&mut s, clamp_round(self.wind_speed.to_km_h(), 0, 999), 3);
+        let _ = s.push(match self.vario_mode {
+            VarioMode::Vario => 'V',
+            VarioMode::SpeedToFly => 'S',
+        });
+        let _ = s.push_str("\r\n");
+        s
+    }
+}
+
+fn base36(val: u32) -> u8 {
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"[(val % 36) as usize]
+}
+
+/// Split degrees into whole degrees and thousandth of minutes plus the hemisphere
+fn split_coord(degs: f64, pos: u8, neg: u8) -> (u32, u32, u8) {
+    let (degs, hemi) = if degs < 0.0 {
+        (-degs, neg)
+    } else {
+        (degs, pos)
+    };
+    let milli_minutes = (degs * 60_000.0 + 0.5) as u32;
+    (milli_minutes / 60_000, milli_minutes % 60_000, hemi)
+}
+
+fn clamp_round(val: f32, min: i32, max: i32) -> i32 {
+    let val = if val < 0.0 { val - 0.5 } else { val + 0.5 };
+    (val as i32).clamp(min, max)
+}
+
+/// Append a number with leading zeros, negative numbers replace the first digit by a minus
+fn push_signed<const N: usize>(s: &mut String<N>, val: i32, width: usize) {
+    if val < 0 {
+        let _ = s.push('-');
+        push_digits(s, val.unsigned_abs(), width - 1);
+    } else {
+        push_digits(s, val as u32, width);
+    }
+}
+
+fn push_digits<const N: usize>(s: &mut String<N>, mut val: u32, width: usize) {
+    let mut digits = [b'0'; 10];
+    for d in digits[..width].iter_mut().rev() {
+        *d = b'0' + (val % 10) as u8;
+        val /= 10;
+    }
+    for d in &digits[..width] {
+        let _ = s.push(*d as char);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AngleUnit, F64ToCoord, FloatToLength, FloatToSpeed};
+
+    fn fix() -> IgcFix {
+        let mut date_time = DateTime::new();
+        date_time.set_date_time(2024, 7, 21, 13, 5, 9);
+        IgcFix {
+            date_time,
+            lat: Latitude(49.456_789_f64.deg()),
+            lon: Longitude((-7.012_345_f64).deg()),
+            valid: true,
+            pressure_altitude: 1234.4.m(),
+            gnss_altitude: 1301.6.m(),
+            tas: 112.6.km_h(),
+            vario: (-1.26).m_s(),
+            wind_angle: (-90.0_f32).deg(),
+            wind_speed: 18.0.km_h(),
+            vario_mode: VarioMode::SpeedToFly,
+        }
+    }
+
+    #[test]
+    fn test_b_record() {
+        let record = fix().to_string();
+        assert_eq!(
+            record.as_str(),
+            "B1305094927407N00700741WA0123401302113-013270018S\r\n"
+        );
+        assert_eq!(record.len(), IGC_FIX_LEN);
+    }
+
+    #[test]
+    fn test_negative_altitude_and_invalid_fix() {
+        let mut fix = fix();
+        fix.valid = false;
+        fix.pressure_altitude = (-12.0).m();
+        fix.vario = 2.0.m_s();
+        let record = fix.to_string();
+        assert_eq!(&record[24..35], "V-001201302");
+        assert_eq!(&record[38..42], "0020");
+    }
+
+    #[test]
+    fn test_header() {
+        let mut date_time = DateTime::new();
+        date_time.set_date_time(2024, 12, 31, 10, 0, 0);
+        let header = IgcHeader {
+            date_time,
+            serial: 36 * 36 + 35,
+            glider_type: "LS-4",
+        };
+        assert_eq!(header.logger_id(), *b"10Z");
+        assert_eq!(header.file_name(1).as_str(), "4CVX10Z1.IGC");
+        assert_eq!(header.file_name(10).as_str(), "4CVX10ZA.IGC");
+
+        let s = header.to_string();
+        let mut lines = s.split("\r\n");
+        assert_eq!(lines.next(), Some("AXLR10ZLARUS"));
+        assert_eq!(lines.next(), Some("HFDTEDATE:311224,01"));
+        assert!(s.contains("HFGTYGLIDERTYPE:LS-4\r\n"));
+        assert!(s.ends_with("I053638TAS3942VAT4345WDI4648WVE4949XVM\r\n"));
+    }
+}
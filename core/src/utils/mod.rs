@@ -7,10 +7,12 @@ mod error;
 mod events;
 mod filter;
 mod idle_events;
+mod igc;
 mod metadata;
 mod parse;
 mod persistence;
 mod rgb565_colors;
+mod test_device_const;
 mod tstring;
 mod variant;
 mod version;
@@ -28,10 +30,12 @@ pub use error::CoreError;
 pub use events::*;
 pub use filter::*;
 pub use idle_events::*;
+pub use igc::*;
 pub use metadata::*;
 pub use parse::*;
 pub use persistence::*;
 pub use rgb565_colors::RGB565_COLORS;
+pub use test_device_const::TEST_DEVICE_CONST;
 pub use tstring::*;
 pub use variant::*;
 pub use version::*;
@@ -52,44 +56,27 @@ where
     }
 }
 
-/*#[cfg(test)]
+#[cfg(test)]
 pub(crate) mod tests {
     use crate::{
-        basic_config::MAX_TX_FRAMES, CoreController, CoreModel, DeviceConst, HwVersion, Images, Palette, QIdleEvents, QTxFrames, SwVersion
+        basic_config::MAX_TX_FRAMES, CoreController, CoreModel, QIdleEvents, QTxFrames,
+        TEST_DEVICE_CONST,
     };
     use heapless::spsc::Queue;
-    use u8g2_fonts::{fonts, FontRenderer};
+    use std::boxed::Box;
 
-    const HW_VERSION: HwVersion = HwVersion::from_bytes([1, 3, 1, 0]);
-    const SW_VERSION: SwVersion = SwVersion {
-        version: [0, 0, 0, 0],
-    };
 
     #[allow(unused)]
     pub(crate) fn cores() -> (CoreModel, CoreController) {
-        let (p_tx_frames, _c_tx_frames) = {
-            static mut Q_TX_FRAMES: QTxFrames<MAX_TX_FRAMES> = Queue::new();
-            // Note: unsafe is ok here, because [heapless::spsc] queue protects against UB
-            unsafe { Q_TX_FRAMES.split() }
-        };
+        let q_tx_frames: &'static mut QTxFrames<MAX_TX_FRAMES> = Box::leak(Box::new(Queue::new()));
+        let (p_tx_frames, _c_tx_frames) = q_tx_frames.split();
 
         // This queue routes the StorageItems from the controller to the idle loop.
-        let (p_idle_events, _c_idle_events) = {
-            static mut Q_IDLE_EVENTS: QIdleEvents = Queue::new();
-            // Note: unsafe is ok here, because [heapless::spsc] queue protects against UB
-            unsafe { Q_IDLE_EVENTS.split() }
-        };
-
-        const DEVICE_CONST: DeviceConst = DeviceConst {
-            dark_theme: Palette::default(),
-            bright_theme: Palette::default(),
-            big_font: FontRenderer::new::<fonts::u8g2_font_fub20_tf>(),
-            small_font: FontRenderer::new::<fonts::u8g2_font_fub20_tf>(),
-            images: Images::new(),
-        };
+        let q_idle_events: &'static mut QIdleEvents = Box::leak(Box::new(Queue::new()));
+        let (p_idle_events, _c_idle_events) = q_idle_events.split();
 
-        let mut model = CoreModel::new(1234_u32, HW_VERSION, SW_VERSION, &DEVICE_CONST);
+        let mut model = CoreModel::new(&TEST_DEVICE_CONST, 1234_u32);
         let controller = CoreController::new(&mut model, p_idle_events, p_tx_frames);
         (model, controller)
     }
-}*/
+}
@@ -12,6 +12,8 @@ pub mod eeprom {
     pub const ADR_USER_PROFILE: u32 = 8;
     // address, where reset reason is stored
     pub const ADR_RESET_REASON: u32 = 9;
+    // address, where the version of the data layout is stored
+    pub const ADR_LAYOUT_VERSION: u32 = 10;
     //...
 
     // start adress of data allocation table
@@ -24,6 +26,8 @@ pub mod eeprom {
     pub const MAX_ITEM_COUNT: u32 = (SIZE - ADR_DATA_STORAGE) / 4;
     // magic number to identify, if eeprom is initialized
     pub const MAGIC: [u8; 8] = [0x1e, 0xf9, 0xb4, 0xaf, 0x22, 0xe1, 0xe5, 0xeb];
+    // version of the data layout, version 0 had room for 64 items per profile only
+    pub const LAYOUT_VERSION: u8 = 1;
 }
 
 pub enum EepromTopic {
@@ -31,6 +35,10 @@ pub enum EepromTopic {
 }
 
 pub const MAX_USER_VALUES: u32 = 256;
+pub const USER_PROFILES: u32 = 4;
+
+/// Items per profile in the data layout of version 0
+const LAYOUT_0_VALUES: u32 = 64;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct PersistenceItem {
@@ -186,9 +194,40 @@ pub trait EepromTrait {
             // Write magic number
             self.write_page(eeprom::ADR_IDENTIFICATION_BLOCK, &eeprom::MAGIC)?;
             self.clear_all_data()?;
+            self.write_byte(eeprom::ADR_LAYOUT_VERSION, eeprom::LAYOUT_VERSION)?;
         }
         Ok(())
     }
+
+    /// check the version of the data layout, an old layout is migrated
+    ///
+    /// In version 0, the profiles were only 64 items apart, while the DAT has room for
+    /// MAX_USER_VALUES items per profile. So items from id 64 onwards overwrote the next profile.
+    /// The profiles 1..3 are moved to their new location and the items from id 64 onwards are
+    /// deleted, as their content is not reliable.
+    fn check_layout(&mut self) -> Result<(), CoreError> {
+        if self.read_byte(eeprom::ADR_LAYOUT_VERSION)? == eeprom::LAYOUT_VERSION {
+            return Ok(());
+        }
+        let mut data = [0_u8; 4];
+        for profile in 1..USER_PROFILES {
+            for id in 0..LAYOUT_0_VALUES {
+                let old = eeprom::ADR_DATA_STORAGE + (profile * LAYOUT_0_VALUES + id) * 4;
+                let new = eeprom::ADR_DATA_STORAGE + (profile * MAX_USER_VALUES + id) * 4;
+                self.read_data(old, &mut data)?;
+                self.write_page(new, &data)?;
+            }
+        }
+        let clear = [0_u8; 8];
+        for profile in 0..USER_PROFILES {
+            let start = eeprom::ADR_DAT + (profile * MAX_USER_VALUES + LAYOUT_0_VALUES) / 8;
+            let end = eeprom::ADR_DAT + (profile + 1) * MAX_USER_VALUES / 8;
+            for address in (start..end).step_by(clear.len()) {
+                self.write_page(address, &clear)?;
+            }
+        }
+        self.write_byte(eeprom::ADR_LAYOUT_VERSION, eeprom::LAYOUT_VERSION)
+    }
 }
 
 pub struct Eeprom<S>
@@ -241,6 +280,7 @@ where
     /// Create a Persistence Instance
     pub fn new(mut eeprom: S, is_unique: fn(PersistenceId)->bool) -> Result<Self, CoreError> {
         eeprom.check_magic()?;
+        eeprom.check_layout()?;
 
         let user_profile = eeprom.read_byte(ADR_USER_PROFILE)?;
         let user_profile = match user_profile {
@@ -319,7 +359,7 @@ where
 
     /// returns the address of an item
     fn item_address(&mut self, id: PersistenceId) -> u32 {
-        eeprom::ADR_DATA_STORAGE + self.profile(id) * MAX_USER_VALUES + id as u32 * 4
+        eeprom::ADR_DATA_STORAGE + (self.profile(id) * MAX_USER_VALUES + id as u32) * 4
     }
 
     /// returns address of the byte of id in DAT
@@ -430,3 +470,60 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Memory([u8; eeprom::SIZE as usize]);
+
+    impl EepromTrait for Memory {
+        fn write_byte(&mut self, address: u32, data: u8) -> Result<(), CoreError> {
+            self.0[address as usize] = data;
+            Ok(())
+        }
+
+        fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), CoreError> {
+            self.0[address as usize..address as usize + data.len()].copy_from_slice(data);
+            Ok(())
+        }
+
+        fn read_byte(&mut self, address: u32) -> Result<u8, CoreError> {
+            Ok(self.0[address as usize])
+        }
+
+        fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), CoreError> {
+            data.copy_from_slice(&self.0[address as usize..address as usize + data.len()]);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_layout_migration() {
+        // profile 1 with the volume stored in the layout of version 0
+        let mut memory = Memory([0xff; eeprom::SIZE as usize]);
+        memory.0[..8].copy_from_slice(&eeprom::MAGIC);
+        memory.0[ADR_USER_PROFILE as usize] = 1;
+        memory.0[eeprom::ADR_DAT as usize..(eeprom::ADR_DAT + eeprom::DAT_LEN) as usize].fill(0);
+        let volume = PersistenceItem::from_i8(PersistenceId::Volume, 7);
+        let old = eeprom::ADR_DATA_STORAGE + LAYOUT_0_VALUES * 4;
+        memory.write_page(old, &volume.data).unwrap();
+        memory.0[(eeprom::ADR_DAT + MAX_USER_VALUES / 8) as usize] = 0x01;
+        // an item above id 63 of profile 0 shared its location with profile 1
+        memory.0[(eeprom::ADR_DAT + LAYOUT_0_VALUES / 8) as usize] = 0x01;
+
+        let mut persistence = Eeprom::new(memory, |_| false).unwrap();
+        let item = persistence.read_item(PersistenceId::Volume).unwrap();
+        assert_eq!(item.to_i8(), 7);
+        assert_eq!(persistence.eeprom.0[eeprom::ADR_LAYOUT_VERSION as usize], 1);
+        assert_eq!(persistence.eeprom.0[(eeprom::ADR_DAT + LAYOUT_0_VALUES / 8) as usize], 0);
+
+        // the items of a profile do not overlap the next profile anymore
+        let flap_polar = PersistenceItem::from_u32(PersistenceId::FlapPolar15, 0);
+        persistence.user_profile = 0;
+        persistence.write_item(flap_polar).unwrap();
+        persistence.user_profile = 1;
+        let item = persistence.read_item(PersistenceId::Volume).unwrap();
+        assert_eq!(item.to_i8(), 7);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    DeviceConst, DisplaySizes, EditMode, HorizonSizes, HwVersion, Images, Misc, Palette, Sizes,
+    SwVersion, VarioSizes,
+};
+use embedded_graphics::geometry::{Point, Size};
+use u8g2_fonts::{fonts, FontRenderer};
+
+/// Device constants for the tests, the views are not drawn, so all sizes and images are empty
+#[doc(hidden)]
+pub const TEST_DEVICE_CONST: DeviceConst = DeviceConst {
+    dark_theme: Palette::default(),
+    bright_theme: Palette::default(),
+    big_font: FontRenderer::new::<fonts::u8g2_font_fub20_tf>(),
+    small_font: FontRenderer::new::<fonts::u8g2_font_fub20_tf>(),
+    images: Images {
+        attention: &[],
+        bat_empty: &[],
+        bat_full: &[],
+        bat_half: &[],
+        gear: &[],
+        glider: &[],
+        north: &[],
+        spiral: &[],
+        straight: &[],
+        km_h: &[],
+        m_s: &[],
+        sat: &[],
+        small_glider: &[],
+        wp_editor: &[],
+        wp_horizon: &[],
+        wp_vario: &[],
+    },
+    sizes: Sizes {
+        vario: VarioSizes {
+            stf_diameter: 0,
+            stf_width: 0,
+            indicator_len: 0,
+            attention_pos: Point::zero(),
+            glider_pos: Point::zero(),
+            north_pos: Point::zero(),
+            bat_pos: Point::zero(),
+            sat_pos: Point::zero(),
+            unit_pos: Point::zero(),
+            info1_pos: Point::zero(),
+            info2_pos: Point::zero(),
+            info3_pos: Point::zero(),
+            pic_info3_pos: Point::zero(),
+            small_gld_size: Size::zero(),
+            ta_circle_radius: 0,
+            ta_point_diameter: 0,
+            wind_len: 0,
+            wind_len_min: 0,
+            angle_m_s: 0.0,
+            scale_label_radius: 0,
+            scale_label_diameter: 0,
+        },
+        horizon: HorizonSizes {
+            t_width: 0,
+            rm_len: 0,
+            rm_width: 0.0,
+            stroke_width: 0,
+            box_height: 0,
+            tc_pos_y: 0,
+            tc_needle_y: 0,
+            tc_needle_delta: 0,
+            pitch_scale_len: 0,
+        },
+        display: DisplaySizes {
+            height: 0,
+            width: 0,
+            margin: 0,
+            radius: 0,
+            center: Point::zero(),
+            screen_center: Point::zero(),
+            m_s: Size::zero(),
+            km_h: Size::zero(),
+            alarm: Size::zero(),
+        },
+    },
+    misc: Misc {
+        sw_version: SwVersion {
+            version: [0, 0, 0, 0],
+        },
+        hw_version: HwVersion::from_bytes([1, 3, 1, 0]),
+        edit_mode: EditMode::Off,
+    },
+};
@@ -3,7 +3,7 @@ use core::cmp::Ordering;
 use heapless::String;
 
 #[repr(C)]
-#[derive(PartialEq, Clone, Copy, Default)]
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
 pub struct HwVersion {
     pub version: [u8; 4],
 }
@@ -37,7 +37,7 @@ impl HwVersion {
 }
 
 #[repr(C)]
-#[derive(PartialEq, Clone, Copy, Default)]
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
 pub struct SwVersion {
     pub version: [u8; 4],
 }
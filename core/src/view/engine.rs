@@ -0,0 +1,84 @@
+use super::viewable::dialog_box::DialogBox;
+use crate::{
+    model::CoreModel, system_of_units::Temperature, tformat, utils::Colors, CoreError, DrawImage,
+};
+
+use embedded_graphics::draw_target::DrawTarget;
+use heapless::String;
+
+#[derive(PartialEq)]
+pub struct EngineView;
+
+impl EngineView {
+    pub fn new() -> EngineView {
+        EngineView
+    }
+
+    pub fn draw<D>(&self, display: &mut D, cm: &CoreModel) -> Result<(), CoreError>
+    where
+        D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+    {
+        let engine = &cm.engine;
+        // the values are drawn in the alarm color as long as a limit is exceeded
+        let text_color = match engine.warning {
+            Some(_) => cm.palette().alarm,
+            None => cm.palette().text1,
+        };
+        let run_secs = cm.flight_stats.engine_secs();
+        let header = tformat!(20, "Engine {}:{:02}", run_secs / 3600, run_secs / 60 % 60).unwrap();
+        let mut dialog_box = DialogBox::new(
+            header.as_str(),
+            cm.palette().background,
+            cm.palette().scale,
+            cm.palette().scale,
+            text_color,
+        );
+
+        let text = if engine.is_available() {
+            let temperature = |label: &str, temp: Option<Temperature>| -> String<20> {
+                let unit = cm.config.units.temperature;
+                match temp {
+                    Some(temp) => tformat!(20, "{} {:.0}{}", label, unit.value(temp), unit.as_str()),
+                    None => tformat!(20, "{} --", label),
+                }
+                .unwrap()
+            };
+            let rpm = match engine.rpm {
+                Some(rpm) => tformat!(20, "{:.0} rpm", rpm),
+                None => tformat!(20, "-- rpm"),
+            }
+            .unwrap();
+            let battery = match (engine.voltage, engine.current) {
+                (Some(voltage), Some(current)) => tformat!(20, "{:.1}V {:.0}A", voltage, current),
+                (Some(voltage), None) => tformat!(20, "{:.1}V", voltage),
+                _ => tformat!(20, "--V"),
+            }
+            .unwrap();
+            let soc = match engine.soc {
+                Some(soc) => tformat!(20, "SoC {:.0}%", soc),
+                None => tformat!(20, "SoC --"),
+            }
+            .unwrap();
+            tformat!(
+                100,
+                "{}\n{}\n{}\n{}\n{}",
+                rpm.as_str(),
+                temperature("Motor", engine.motor_temp).as_str(),
+                battery.as_str(),
+                soc.as_str(),
+                temperature("Bat", engine.battery_temp).as_str()
+            )
+            .unwrap()
+        } else {
+            tformat!(100, "No data").unwrap()
+        };
+
+        dialog_box.draw(
+            display,
+            cm.device_const.sizes.display.height,
+            cm.device_const.sizes.display.width,
+            text.as_str(),
+            &cm.device_const.big_font,
+        )
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    model::{CoreModel, FlarmAlarmLevel},
+    tformat,
+    utils::Colors,
+    view::sprites::{pos, Arrow, DrawStyled, Rotate},
+    CoreError, DrawImage,
+};
+
+use embedded_graphics::{draw_target::DrawTarget, prelude::*, primitives::PrimitiveStyle};
+use u8g2_fonts::types::{FontColor, HorizontalAlignment, VerticalPosition};
+
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
+/// Overlay to show the most relevant FLARM alarm
+#[derive(PartialEq)]
+pub struct FlarmView {}
+
+impl FlarmView {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn draw<D>(&self, display: &mut D, cm: &CoreModel) -> Result<(), CoreError>
+    where
+        D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+    {
+        let flarm = &cm.flarm;
+        display.draw_img(cm.device_const.images.wp_editor, Point::new(0, 0), None)?;
+
+        let d_sizes = &cm.device_const.sizes.display;
+        let delta_y = d_sizes.height as i32 / 15;
+
+        let (header, color) = match flarm.alarm_level {
+            FlarmAlarmLevel::Urgent => ("Collision Alarm", cm.palette().alarm),
+            FlarmAlarmLevel::Important => ("Traffic Warning", cm.palette().alarm),
+            _ => ("Traffic", cm.palette().text2),
+        };
+        cm.device_const.big_font.render_aligned(
+            header,
+            d_sizes.screen_center + Point::new(0, -delta_y),
+            VerticalPosition::Center,
+            HorizontalAlignment::Center,
+            FontColor::Transparent(color),
+            display,
+        )?;
+
+        // relative bearing as clock position, 12 o'clock is straight ahead, non-directional
+        // targets have no bearing
+        let unit = cm.config.units.altitude;
+        let vertical = unit.value(flarm.rel_vertical) as i32;
+        let sign = if vertical < 0 { "" } else { "+" };
+        let value = match flarm.rel_bearing {
+            Some(bearing) => {
+                let mut clock = (bearing.to_degrees() / 30.0).round() as i32 % 12;
+                if clock <= 0 {
+                    clock += 12;
+                }
+                tformat!(20, "{}h  {}{}{}", clock, sign, vertical, unit.as_str()).unwrap()
+            }
+            None => tformat!(20, "{}{}{}", sign, vertical, unit.as_str()).unwrap(),
+        };
+        cm.device_const.big_font.render_aligned(
+            value.as_str(),
+            d_sizes.screen_center + Point::new(0, delta_y),
+            VerticalPosition::Center,
+            HorizontalAlignment::Center,
+            FontColor::Transparent(cm.palette().text2_bold),
+            display,
+        )?;
+
+        // arrow in the direction of the traffic
+        match flarm.rel_bearing {
+            Some(bearing) => {
+                let style = PrimitiveStyle::with_fill(color);
+                Arrow::new(
+                    delta_y * 2,
+                    d_sizes.screen_center + Point::new(0, -delta_y * 3),
+                )
+                .zero_pos(pos::TWELVE_O_CLOCK)
+                .rotate(bearing.to_radians())
+                .draw_styled(style, display)
+            }
+            None => Ok(()),
+        }
+    }
+}
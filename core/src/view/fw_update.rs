@@ -1,5 +1,10 @@
 use super::viewable::dialog_box::DialogBox;
-use crate::{model::CoreModel, tformat, utils::Colors, CoreError, DeviceEvent, DrawImage};
+use crate::{
+    model::{CoreModel, SensorboxUpdate},
+    tformat,
+    utils::Colors,
+    CoreError, DeviceEvent, DrawImage,
+};
 
 use embedded_graphics::draw_target::DrawTarget;
 use heapless::String;
@@ -35,11 +40,27 @@ impl SwUpdate {
             cm.palette().scale,
             cm.palette().text1,
         );
+        // the update of the sensorbox is confirmed by the pilot and shown with its progress
+        let text = match cm.control.sensorbox_update {
+            Some(SensorboxUpdate::Offered(sw_version)) => tformat!(
+                100,
+                "Sensorbox\n{}\nOK: install\nESC: skip",
+                sw_version.as_string().as_str()
+            )
+            .unwrap(),
+            Some(SensorboxUpdate::Running(progress)) => {
+                tformat!(100, "Sensorbox\n{} %\nDo NOT power\noff device", progress).unwrap()
+            }
+            Some(SensorboxUpdate::Failed) => {
+                tformat!(100, "Sensorbox\nupdate failed\nOK: continue").unwrap()
+            }
+            None => self.text.clone(),
+        };
         dialog_box.draw(
             display,
             cm.device_const.sizes.display.height,
             cm.device_const.sizes.display.width,
-            self.text.as_str(),
+            text.as_str(),
             &cm.device_const.big_font,
         )
     }
@@ -1,8 +1,9 @@
 use crate::{
-    model::{CoreModel, TypeOfInfo},
+    model::{CoreModel, EngineWarning, TypeOfInfo},
+    system_of_units::Temperature,
     tformat,
     utils::Colors,
-    view::viewable::circle_area::draw_info,
+    view::viewable::circle_area::{draw_flashing_info, draw_info},
     CoreError, DrawImage,
 };
 
@@ -26,12 +27,58 @@ impl InfoView {
     {
         match self.type_of_info {
             TypeOfInfo::WaterBallast => {
-                let value = tformat!(20, "{:.0} kg", cm.glider_data.water_ballast.to_kg()).unwrap();
+                let unit = cm.config.units.mass;
+                let ballast = unit.value(cm.glider_data.water_ballast);
+                let value = tformat!(20, "{:.0} {}", ballast, unit.as_str()).unwrap();
                 draw_info(display, cm, "Water Ballast", value.as_str())?;
             }
             TypeOfInfo::GearAlarm => {
                 draw_alarm_info(display, cm, "Landing Gear", cm.device_const.images.gear)?
             }
+            TypeOfInfo::Icing => {
+                let unit = cm.config.units.temperature;
+                let value = match cm.sensor.oat {
+                    Some(oat) => tformat!(20, "OAT {:.0}{}", unit.value(oat), unit.as_str()),
+                    None => tformat!(20, "OAT --"),
+                }
+                .unwrap();
+                draw_info(display, cm, "Icing Risk", value.as_str())?;
+            }
+            TypeOfInfo::Engine => {
+                let unit = cm.config.units.temperature;
+                let temperature = |temp: Option<Temperature>| match temp {
+                    Some(temp) => tformat!(20, "{:.0}{}", unit.value(temp), unit.as_str()),
+                    None => tformat!(20, "--"),
+                };
+                let (name, value) = match cm.engine.warning {
+                    Some(EngineWarning::LowSoc) => (
+                        "Motor Battery",
+                        tformat!(20, "SoC {:.0} %", cm.engine.soc.unwrap_or_default()),
+                    ),
+                    Some(EngineWarning::MotorTemp) => {
+                        ("Motor Temp", temperature(cm.engine.motor_temp))
+                    }
+                    Some(EngineWarning::BatteryTemp) => {
+                        ("Battery Temp", temperature(cm.engine.battery_temp))
+                    }
+                    None => return Ok(()),
+                };
+                draw_info(display, cm, name, value.unwrap().as_str())?;
+            }
+            TypeOfInfo::LowBattery => {
+                let value = tformat!(20, "{:.1} V", cm.calculated.av_supply_voltage).unwrap();
+                draw_info(display, cm, "Battery Low", value.as_str())?;
+            }
+            TypeOfInfo::Stall | TypeOfInfo::Overspeed => {
+                let name = match self.type_of_info {
+                    TypeOfInfo::Stall => "Stall",
+                    _ => "Overspeed",
+                };
+                let unit = cm.config.units.speed;
+                let speed = unit.value(cm.sensor.airspeed.ias());
+                let value = tformat!(20, "{:.0} {}", speed, unit.as_str()).unwrap();
+                draw_flashing_info(display, cm, name, value.as_str())?;
+            }
             TypeOfInfo::None => (),
         };
         Ok(())
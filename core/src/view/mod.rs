@@ -1,7 +1,10 @@
 use embedded_graphics::draw_target::DrawTarget;
 
 pub mod editor;
+pub(crate) mod engine;
+pub(crate) mod flarm;
 pub mod fw_update;
+pub(crate) mod qnh_dialog;
 pub(crate) mod thermal_data;
 
 pub(crate) mod horizon;
@@ -15,8 +18,8 @@ use crate::{
     model::{CoreModel, DisplayActive, OverlayActive, TypeOfInfo},
     utils::Colors,
     view::{
-        editor::Edit, fw_update::SwUpdate, horizon::Horizon, info::InfoView, menu::MenuView,
-        vario::Vario,
+        editor::Edit, engine::EngineView, flarm::FlarmView, fw_update::SwUpdate, horizon::Horizon,
+        info::InfoView, menu::MenuView, qnh_dialog::QnhDialog, vario::Vario,
     },
     CoreError, DrawImage,
 };
@@ -35,6 +38,8 @@ enum PrimaryView {
     Horizon(Horizon),
     SwUpade(SwUpdate),
     MenuView(MenuView),
+    Engine(EngineView),
+    QnhDialog(QnhDialog),
 }
 
 #[derive(PartialEq)]
@@ -42,6 +47,7 @@ enum SecondaryView {
     Edit(Edit),
     MenuView(MenuView),
     InfoView(InfoView),
+    FlarmView(FlarmView),
 }
 
 pub struct CoreView<D>
@@ -88,6 +94,8 @@ where
                     PrimaryView::SwUpade(SwUpdate::new(update_state))
                 }
                 DisplayActive::Menu => PrimaryView::MenuView(MenuView::new()),
+                DisplayActive::Engine => PrimaryView::Engine(EngineView::new()),
+                DisplayActive::QnhDialog => PrimaryView::QnhDialog(QnhDialog::new()),
                 _ => PrimaryView::Vario(Vario::new()),
             };
         }
@@ -98,6 +106,10 @@ where
             OverlayActive::None => None,
         };
 
+        if self.secondary_view.is_none() && core_model.flarm.alarm_active() {
+            self.secondary_view = Some(SecondaryView::FlarmView(FlarmView::new()));
+        }
+
         if self.secondary_view.is_none() {
             let type_of_info = core_model.config.info_active;
             if type_of_info != TypeOfInfo::None {
@@ -116,6 +128,10 @@ where
             PrimaryView::SwUpade(sw_update) => {
                 sw_update.draw(&mut self.display, &self.core_model)?
             }
+            PrimaryView::Engine(engine) => engine.draw(&mut self.display, &self.core_model)?,
+            PrimaryView::QnhDialog(qnh_dialog) => {
+                qnh_dialog.draw(&mut self.display, &self.core_model)?
+            }
         }
 
         if let Some(secondary_view) = &mut self.secondary_view {
@@ -125,11 +141,17 @@ where
                     menu.draw(&mut self.display, &self.core_model, true)?
                 }
                 SecondaryView::InfoView(info_view) => match self.primary_view {
-                    PrimaryView::Horizon(_) | PrimaryView::Vario(_) => {
+                    PrimaryView::Horizon(_) | PrimaryView::Vario(_) | PrimaryView::Engine(_) => {
                         info_view.draw(&mut self.display, &self.core_model)?
                     }
                     _ => (),
                 },
+                SecondaryView::FlarmView(flarm_view) => match self.primary_view {
+                    PrimaryView::Horizon(_) | PrimaryView::Vario(_) | PrimaryView::Engine(_) => {
+                        flarm_view.draw(&mut self.display, &self.core_model)?
+                    }
+                    _ => (),
+                },
             }
         }
         Ok(())
@@ -0,0 +1,56 @@
+use super::viewable::dialog_box::DialogBox;
+use crate::{model::CoreModel, tformat, utils::Colors, CoreError, DrawImage};
+
+use embedded_graphics::draw_target::DrawTarget;
+
+#[derive(PartialEq)]
+pub struct QnhDialog;
+
+impl QnhDialog {
+    pub fn new() -> QnhDialog {
+        QnhDialog
+    }
+
+    pub fn draw<D>(&self, display: &mut D, cm: &CoreModel) -> Result<(), CoreError>
+    where
+        D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+    {
+        let mut dialog_box = DialogBox::new(
+            "Auto QNH",
+            cm.palette().background,
+            cm.palette().scale,
+            cm.palette().scale,
+            cm.palette().text1,
+        );
+
+        let text = match cm.calculated.qnh_proposal {
+            Some(qnh) => {
+                let unit = cm.config.units.pressure;
+                let qnh = match unit.dec_places() {
+                    0 => tformat!(20, "{:.0} {}", unit.value(qnh), unit.as_str()),
+                    _ => tformat!(20, "{:.2} {}", unit.value(qnh), unit.as_str()),
+                }
+                .unwrap();
+                let unit = cm.config.units.altitude;
+                let elevation = unit.value(cm.calculated.qnh_elevation);
+                tformat!(
+                    100,
+                    "{}\nElev. {:.0} {}\nOK: apply\nESC: reject",
+                    qnh.as_str(),
+                    elevation,
+                    unit.as_str()
+                )
+            }
+            None => tformat!(100, "No data"),
+        }
+        .unwrap();
+
+        dialog_box.draw(
+            display,
+            cm.device_const.sizes.display.height,
+            cm.device_const.sizes.display.width,
+            text.as_str(),
+            &cm.device_const.big_font,
+        )
+    }
+}
@@ -0,0 +1,58 @@
+use super::{DrawColored, Rotate};
+use core::f32::consts::PI;
+use embedded_graphics::{
+    geometry::AngleUnit,
+    prelude::*,
+    primitives::{Arc, PrimitiveStyle},
+};
+
+use crate::utils::{Colors, CoreError};
+
+/// Bar along the rim of the round display, e.g. to show the arrival height of the final glide
+///
+/// The bar starts at zero_pos and ends at zero_pos + rotation. Both angles are in radians, zero
+/// is at twelve o'clock and positive angles are clockwise like in the other sprites.
+pub struct GlideBar {
+    zero_pos: f32,
+    rotation: f32,
+    diameter: u32,
+    width: u32,
+    center: Point,
+}
+
+impl GlideBar {
+    pub const fn new(diameter: u32, width: u32, center: Point) -> Self {
+        Self {
+            zero_pos: 0.0,
+            rotation: 0.0,
+            diameter,
+            width,
+            center,
+        }
+    }
+}
+
+impl DrawColored for GlideBar {
+    fn draw_colored<D>(&self, color: Colors, display: &mut D) -> Result<(), CoreError>
+    where
+        D: DrawTarget<Color = Colors, Error = CoreError>,
+    {
+        // embedded graphics counts the angles from three o'clock
+        let start = (self.zero_pos - 0.5 * PI).rad();
+        Arc::with_center(self.center, self.diameter, start, self.rotation.rad())
+            .into_styled(PrimitiveStyle::with_stroke(color, self.width))
+            .draw(display)?;
+        Ok(())
+    }
+}
+
+impl Rotate for GlideBar {
+    fn rotate(&mut self, rotation: f32) -> &mut Self {
+        self.rotation = rotation;
+        self
+    }
+    fn zero_pos(&mut self, zero_pos: f32) -> &mut Self {
+        self.zero_pos = zero_pos;
+        self
+    }
+}
@@ -1,5 +1,6 @@
 pub(crate) mod arrow;
 pub(crate) mod classic_indicator;
+pub(crate) mod glide_bar;
 pub(crate) mod polar_defs;
 pub(crate) mod scale_marker;
 pub(crate) mod simple_indicator;
@@ -7,6 +8,7 @@ pub(crate) mod wind_arrow;
 
 pub(crate) use arrow::Arrow;
 pub(crate) use classic_indicator::ClassicIndicator;
+pub(crate) use glide_bar::GlideBar;
 pub(crate) use polar_defs::*;
 pub(crate) use scale_marker::ScaleMarker;
 pub(crate) use simple_indicator::SimpleIndicator;
@@ -1,37 +1,51 @@
-use super::{sprites::*, thermal_data::ThermalData};
+use super::{sprites::*, thermal_data::ThermalData, viewable::units::draw_unit};
 use crate::{
-    model::{CoreModel, DataSource, FlyMode, SystemState, VarioMode},
+    model::{CoreModel, DataSource, FlyMode, SystemState, VarioMode, VerticalSpeedUnit},
+    system_of_units::Speed,
     tformat,
     utils::Colors,
-    CoreError, DrawImage,
+    CoreError, DrawImage, FloatToSpeed,
 };
 
 use embedded_graphics::{
     geometry::AngleUnit,
     prelude::*,
-    primitives::{Arc, PrimitiveStyle},
+    primitives::{Arc, Circle, PrimitiveStyle},
 };
 use num::clamp;
 use u8g2_fonts::types::{FontColor, HorizontalAlignment, VerticalPosition};
 
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
+/// Maximum angle of the glide bar in radians
+const GLIDE_BAR_ANGLE: f32 = 0.25 * core::f32::consts::PI;
+
 pub fn draw_thermal_climb<D>(display: &mut D, cm: &CoreModel) -> Result<(), CoreError>
 where
     D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
 {
     let sizes = &cm.device_const.sizes.vario;
+    let unit = cm.config.units.vertical_speed;
 
     display.draw_img(
         cm.device_const.images.spiral,
         sizes.pic_info3_pos,
         Some(cm.palette().vario_pic_info1),
     )?;
-    display.draw_img(
-        cm.device_const.images.m_s,
-        sizes.info3_pos,
-        Some(cm.palette().scale),
+    draw_unit(
+        display,
+        cm,
+        unit.as_str(),
+        sizes.info3_pos + Point::new(0, cm.device_const.sizes.display.m_s.height as i32 / 2),
+        HorizontalAlignment::Left,
+        cm.palette().scale,
     )?;
-    let acr = num::clamp(cm.calculated.thermal_climb_rate.to_m_s(), -9.9, 99.9);
-    let txt = tformat!(10, "{:.1}", acr).unwrap();
+    let acr = num::clamp(cm.calculated.thermal_climb_rate.to_m_s(), -9.9, 99.9).m_s();
+    let txt = match unit.dec_places() {
+        0 => tformat!(10, "{:.0}", unit.value(acr)).unwrap(),
+        _ => tformat!(10, "{:.1}", unit.value(acr)).unwrap(),
+    };
     cm.device_const.big_font.render_aligned(
         txt.as_str(),
         sizes.info3_pos,
@@ -43,6 +57,73 @@ where
     Ok(())
 }
 
+/// Replace the m/s numbers of the wallpaper scale with the numbers of the selected unit
+fn draw_scale_labels<D>(display: &mut D, cm: &CoreModel) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    let unit = cm.config.units.vertical_speed;
+    if unit == VerticalSpeedUnit::MS {
+        return Ok(()); // the wallpaper is already labeled in m/s
+    }
+
+    let sizes = &cm.device_const.sizes.vario;
+    let center = cm.device_const.sizes.display.center;
+    let radius = sizes.scale_label_radius as f32;
+    let style = PrimitiveStyle::with_fill(cm.palette().scale);
+    for mark in (-5..=5).filter(|mark| *mark != 0) {
+        let angle = (mark as f32 * sizes.angle_m_s).to_radians();
+        let label_pos =
+            center - Point::new((angle.cos() * radius) as i32, (angle.sin() * radius) as i32);
+        Circle::with_center(label_pos, sizes.scale_label_diameter)
+            .into_styled(style)
+            .draw(display)?;
+        let txt = tformat!(4, "{}", unit.scale_label(mark).abs()).unwrap();
+        cm.device_const.small_font.render_aligned(
+            txt.as_str(),
+            label_pos,
+            VerticalPosition::Center,
+            HorizontalAlignment::Center,
+            FontColor::Transparent(cm.palette().background),
+            display,
+        )?;
+    }
+    Ok(())
+}
+
+/// Draw the rough air range yellow and the range above Vne red on the speed to fly scale
+///
+/// The speed to fly bar reaches into these ranges, when the speed to fly exceeds a limit. Without
+/// speed limits of the glider nothing is drawn.
+fn draw_speed_limits<D>(display: &mut D, cm: &CoreModel) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    let sizes = &cm.device_const.sizes.vario;
+    let center = cm.device_const.sizes.display.center;
+    let ias = cm.sensor.airspeed.ias();
+    // position of a speed on the scale, like the speed to fly bar 10 km/h per mark
+    let mark = |speed: Speed| clamp(-(speed - ias).to_km_h() / 10.0, -5.0, 5.0);
+    let (vra, vne) = match (cm.calculated.rough_air_speed, cm.calculated.vne) {
+        (Some(vra), Some(vne)) => (mark(vra), mark(vne)),
+        _ => return Ok(()),
+    };
+    let ranges = [
+        (vra, vne, cm.palette().signal_warning),
+        (vne, -5.0, cm.palette().signal_stop),
+    ];
+    for (from, to, color) in ranges {
+        if from > to {
+            let start = (180.0 + sizes.angle_m_s * from).deg();
+            let sweep = (sizes.angle_m_s * (to - from)).deg();
+            Arc::with_center(center, sizes.stf_diameter, start, sweep)
+                .into_styled(PrimitiveStyle::with_stroke(color, sizes.stf_width / 2))
+                .draw(display)?;
+        }
+    }
+    Ok(())
+}
+
 #[derive(PartialEq)]
 pub struct Vario {
     thermal_data: ThermalData,
@@ -69,10 +150,24 @@ impl Vario {
             Point::new(0, 0),
             Some(cm.palette().scale),
         )?;
-        display.draw_img(
-            cm.device_const.images.m_s,
-            sizes.unit_pos,
-            Some(cm.palette().background),
+        draw_scale_labels(display, cm)?;
+        draw_unit(
+            display,
+            cm,
+            cm.config.units.vertical_speed.as_str(),
+            sizes.unit_pos + Point::new(0, d_sizes.m_s.height as i32 / 2),
+            HorizontalAlignment::Center,
+            cm.palette().background,
+        )?;
+
+        // draw the label of the vario source above the unit
+        cm.device_const.small_font.render_aligned(
+            cm.control.vario_source.label(),
+            sizes.unit_pos + Point::new(d_sizes.m_s.width as i32 / 2, -2),
+            VerticalPosition::Bottom,
+            HorizontalAlignment::Center,
+            FontColor::Transparent(cm.palette().scale),
+            display,
         )?;
 
         // draw battery symbol
@@ -153,6 +248,7 @@ impl Vario {
                 draw_thermal_climb(display, cm)?;
             }
             VarioMode::SpeedToFly => {
+                draw_speed_limits(display, cm)?;
                 let stf = num::clamp(-cm.calculated.speed_to_fly_dif.to_km_h() / 10.0, -5.0, 5.0);
                 let angle_sweep = (sizes.angle_m_s * stf).deg();
                 let col = cm.palette().vario_speed_to_fly;
@@ -166,12 +262,16 @@ impl Vario {
                         sizes.pic_info3_pos,
                         Some(cm.palette().vario_pic_info1),
                     )?;
-                    display.draw_img(
-                        cm.device_const.images.km_h,
-                        sizes.info3_pos,
-                        Some(cm.palette().scale),
+                    let unit = cm.config.units.speed;
+                    draw_unit(
+                        display,
+                        cm,
+                        unit.as_str(),
+                        sizes.info3_pos + Point::new(0, d_sizes.km_h.height as i32 / 2),
+                        HorizontalAlignment::Left,
+                        cm.palette().scale,
                     )?;
-                    let stf = num::clamp(cm.calculated.speed_to_fly_1s.to_km_h(), 0.0, 999.0);
+                    let stf = num::clamp(unit.value(cm.calculated.speed_to_fly_1s), 0.0, 999.0);
                     let txt = tformat!(10, "{:.0}", stf).unwrap();
                     cm.device_const.big_font.render_aligned(
                         txt.as_str(),
@@ -187,18 +287,35 @@ impl Vario {
             }
         }
 
+        // draw glide bar, 500 m arrival height above the safety altitude fill 45°
+        if let Some(final_glide) = cm.navigation.final_glide {
+            let arrival = clamp(final_glide.arrival_height.to_m() / 500.0, -1.0, 1.0);
+            let color = if arrival > 0.0 {
+                cm.palette().signal_go
+            } else {
+                cm.palette().signal_stop
+            };
+            GlideBar::new(sizes.stf_diameter, sizes.stf_width, d_sizes.center)
+                .zero_pos(pos::THREE_O_CLOCK)
+                .rotate(-arrival * GLIDE_BAR_ANGLE)
+                .draw_colored(color, display)?;
+        }
+
+        // the needles are scaled in marks of the scale, which depend on the unit
+        let scale_step = cm.config.units.vertical_speed.scale_step();
+
         // draw mc_cready indicator
         ScaleMarker::new(d_sizes.radius as i32, d_sizes.center)
             .zero_pos(pos::NINE_O_CLOCK)
-            .rotate((cm.config.mc_cready.to_m_s() * sizes.angle_m_s).to_radians())
+            .rotate((cm.config.mc_cready / scale_step * sizes.angle_m_s).to_radians())
             .draw_colored(cm.palette().needle2, display)?;
 
         // draw average climb rate marker
         let avg_climb_rate = match cm.control.avg_climb_rate_src {
-            DataSource::Frontend => cm.calculated.av2_climb_rate.to_m_s(),
-            DataSource::Sensorbox => cm.sensor.average_climb_rate.to_m_s(),
+            DataSource::Frontend => cm.calculated.av2_climb_rate,
+            DataSource::Sensorbox => cm.sensor.average_climb_rate,
         };
-        let av_climb_rate = clamp(avg_climb_rate, -5.0, 5.0);
+        let av_climb_rate = clamp(avg_climb_rate / scale_step, -5.0, 5.0);
         SimpleIndicator::at_base(
             (d_sizes.radius - sizes.indicator_len) as i32,
             d_sizes.center,
@@ -208,7 +325,7 @@ impl Vario {
         .draw_colored(cm.palette().needle3, display)?;
 
         // draw climb rate indicator
-        let climb_rate = num::clamp(cm.sensor.climb_rate.to_m_s(), -5.1, 5.1);
+        let climb_rate = num::clamp(cm.calculated.vario_climb_rate / scale_step, -5.1, 5.1);
         ClassicIndicator::new(d_sizes.radius as i32, d_sizes.center)
             .zero_pos(pos::NINE_O_CLOCK)
             .rotate((climb_rate * sizes.angle_m_s).to_radians())
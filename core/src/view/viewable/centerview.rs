@@ -2,7 +2,10 @@ use crate::view::{
     sprites::{pos, Arrow, DrawStyled, PolarCoordinate, Rotate, WindArrow},
     thermal_data::{ThermalData, DELTA_ALPHA, THERMAL_DATA_CNT},
 };
-use crate::{Colors, CoreError, CoreModel, DrawImage, FloatToSpeed, FlyMode, VarioSizes};
+use crate::{
+    model::FlarmAlarmLevel, tformat, Colors, CoreError, CoreModel, DrawImage, FloatToSpeed,
+    FlyMode, VarioSizes,
+};
 
 use embedded_graphics::{
     draw_target::DrawTarget,
@@ -12,6 +15,7 @@ use embedded_graphics::{
     Drawable,
 };
 use num::clamp;
+use u8g2_fonts::types::{FontColor, HorizontalAlignment, VerticalPosition};
 
 #[allow(unused_imports)]
 use micromath::F32Ext;
@@ -28,25 +32,32 @@ pub enum CenterView {
     DoubleArrowStraight,
     ThermalAssistant1,
     ThermalAssistant2,
+    TrafficRadarCircling,
+    TrafficRadarStraight,
     LastElemntNotInUse,
 }
 
-const CIRCLING_CENTER_VIEW: [CenterView; 4] = [
+const CIRCLING_CENTER_VIEW: [CenterView; 5] = [
     CenterView::SingleArrowCircling,
     CenterView::DoubleArrowCircling,
     CenterView::ThermalAssistant1,
     CenterView::ThermalAssistant2,
+    CenterView::TrafficRadarCircling,
 ];
 
-const STRAIGHT_CENTER_VIEW: [CenterView; 2] = [
+const STRAIGHT_CENTER_VIEW: [CenterView; 3] = [
     CenterView::SingleArrowStraight,
     CenterView::DoubleArrowStraight,
+    CenterView::TrafficRadarStraight,
 ];
 
 // Limits of the wind arrow
 const WIND_MIN: f32 = 10.0; // 10 km/h
 const WIND_MAX: f32 = 30.0; // 30 km/h
 
+// Range of the traffic radar
+const RADAR_RANGE: f32 = 2000.0; // 2 km
+
 pub enum CenterType {
     Circling,
     Straight,
@@ -106,6 +117,8 @@ impl CenterView {
             CenterView::DoubleArrowStraight => "Double Arrow",
             CenterView::ThermalAssistant1 => "Dotted Assistant",
             CenterView::ThermalAssistant2 => "Spider Assistant",
+            CenterView::TrafficRadarCircling => "Traffic Radar",
+            CenterView::TrafficRadarStraight => "Traffic Radar",
             CenterView::None => "None",
             CenterView::LastElemntNotInUse => "",
         }
@@ -129,6 +142,8 @@ impl CenterView {
             CenterView::DoubleArrowStraight => draw_double_arrow(display, cm),
             CenterView::ThermalAssistant1 => draw_thermal_assitant1(display, cm, thermal_data),
             CenterView::ThermalAssistant2 => draw_thermal_assitant2(display, cm, thermal_data),
+            CenterView::TrafficRadarCircling => draw_traffic_radar(display, cm),
+            CenterView::TrafficRadarStraight => draw_traffic_radar(display, cm),
             CenterView::LastElemntNotInUse => Ok(()),
         }
     }
@@ -253,6 +268,71 @@ where
     Ok(())
 }
 
+fn draw_traffic_radar<D>(display: &mut D, cm: &CoreModel) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    let sizes = &cm.device_const.sizes;
+    let center = sizes.display.center;
+    let radius = sizes.vario.ta_circle_radius as f32;
+
+    // range rings at full and half range
+    let style = PrimitiveStyle::with_stroke(cm.palette().scale, 1);
+    Circle::with_center(center, 2 * sizes.vario.ta_circle_radius)
+        .into_styled(style)
+        .draw(display)?;
+    Circle::with_center(center, sizes.vario.ta_circle_radius)
+        .into_styled(style)
+        .draw(display)?;
+
+    // own glider in the center, heading up
+    let dx = (sizes.vario.small_gld_size.width / 2) as i32;
+    let dy = (sizes.vario.small_gld_size.height / 2) as i32;
+    display.draw_img(
+        cm.device_const.images.small_glider,
+        center + Point::new(-dx, -dy),
+        Some(cm.palette().scale),
+    )?;
+
+    if !cm.flarm.is_available() {
+        return Ok(());
+    }
+
+    for traffic in cm.flarm.traffic() {
+        // non-directional targets cannot be placed on the radar
+        let bearing = match traffic.bearing() {
+            Some(bearing) => bearing,
+            None => continue,
+        };
+        let pcoord = PolarCoordinate {
+            alpha: (bearing - cm.sensor.euler_yaw).to_radians(),
+            len: radius * (traffic.distance().to_m() / RADAR_RANGE).min(1.0),
+        };
+        let p = pcoord.to_xy(1.0, 0.0) + center;
+        let color = match traffic.alarm_level {
+            FlarmAlarmLevel::Urgent | FlarmAlarmLevel::Important => cm.palette().alarm,
+            FlarmAlarmLevel::Low => cm.palette().signal_warning,
+            FlarmAlarmLevel::None => cm.palette().sprite1_fill,
+        };
+        Circle::with_center(p, sizes.vario.ta_point_diameter)
+            .into_styled(PrimitiveStyle::with_fill(color))
+            .draw(display)?;
+
+        // vertical separation in hundreds of the altitude unit
+        let vertical = cm.config.units.altitude.value(traffic.rel_vertical) / 100.0;
+        let txt = tformat!(5, "{}", vertical.round() as i32).unwrap();
+        cm.device_const.small_font.render_aligned(
+            txt.as_str(),
+            p + Point::new(sizes.vario.ta_point_diameter as i32, 0),
+            VerticalPosition::Center,
+            HorizontalAlignment::Left,
+            FontColor::Transparent(cm.palette().scale),
+            display,
+        )?;
+    }
+    Ok(())
+}
+
 fn draw_and_calc_wind_basics<D>(
     display: &mut D,
     cm: &CoreModel,
@@ -35,6 +35,42 @@ where
     Ok(())
 }
 
+pub fn draw_flashing_info<D>(
+    display: &mut D,
+    cm: &CoreModel,
+    header: &str,
+    value: &str,
+) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    display.draw_img(cm.device_const.images.wp_editor, Point::new(0, 0), None)?;
+
+    let d_sizes = &cm.device_const.sizes.display;
+    let delta_y = d_sizes.height as i32 / 15;
+    cm.device_const.big_font.render_aligned(
+        header,
+        d_sizes.screen_center + Point::new(0, -delta_y),
+        VerticalPosition::Center,
+        HorizontalAlignment::Center,
+        FontColor::Transparent(cm.palette().alarm),
+        display,
+    )?;
+
+    // the value flashes twice per second
+    if (cm.control.alive_ticks / 3) % 2 == 1 {
+        cm.device_const.big_font.render_aligned(
+            value,
+            d_sizes.screen_center + Point::new(0, delta_y),
+            VerticalPosition::Center,
+            HorizontalAlignment::Center,
+            FontColor::Transparent(cm.palette().alarm),
+            display,
+        )?;
+    }
+    Ok(())
+}
+
 pub fn draw_alarm_info<D>(
     display: &mut D,
     cm: &CoreModel,
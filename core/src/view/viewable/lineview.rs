@@ -1,4 +1,8 @@
-use crate::{model::DataSource, tformat, Colors, CoreError, CoreModel, DrawImage, FloatToSpeed};
+use super::units::{draw_unit, format_climb_rate};
+use crate::{
+    density_altitude, model::DataSource, tformat, Colors, CoreError, CoreModel, DrawImage,
+    FloatToSpeed,
+};
 use embedded_graphics::{draw_target::DrawTarget, geometry::Point};
 use num_enum::FromPrimitive;
 use u8g2_fonts::types::{FontColor, HorizontalAlignment, VerticalPosition};
@@ -23,14 +27,30 @@ pub enum LineView {
     WindAndAvgWind,
     SpeedToFly,
     TrueAirSpeed,
+    Altitude,
+    Qnh,
+    FinalGlide,
+    LastThermal,
+    AvgThermal,
+    McSuggestion,
+    OatDensityAltitude,
+    Flaps,
     LastElemntNotInUse,
 }
 
 const TOP_LINE_VIEW: &[LineView] = &[
     LineView::None,
+    LineView::Altitude,
     LineView::AverageClimbRate,
+    LineView::AvgThermal,
     LineView::DriftAngle,
+    LineView::FinalGlide,
+    LineView::Flaps,
     LineView::FlightLevel,
+    LineView::LastThermal,
+    LineView::McSuggestion,
+    LineView::OatDensityAltitude,
+    LineView::Qnh,
     LineView::SpeedToFly,
     LineView::TrueAirSpeed,
     LineView::TrueCourse,
@@ -39,9 +59,17 @@ const TOP_LINE_VIEW: &[LineView] = &[
 
 const BOTTOM_LINE_VIEW: &[LineView] = &[
     LineView::None,
+    LineView::Altitude,
     LineView::AverageClimbRate,
+    LineView::AvgThermal,
     LineView::DriftAngle,
+    LineView::FinalGlide,
+    LineView::Flaps,
     LineView::FlightLevel,
+    LineView::LastThermal,
+    LineView::McSuggestion,
+    LineView::OatDensityAltitude,
+    LineView::Qnh,
     LineView::SpeedToFly,
     LineView::TrueAirSpeed,
     LineView::TrueCourse,
@@ -104,9 +132,17 @@ impl LineView {
     /// Get the name of a viewable
     pub fn name(&self) -> &'static str {
         match self {
+            LineView::Altitude => "Altitude",
             LineView::AverageClimbRate => "Avg Climb Rate",
+            LineView::AvgThermal => "Avg Thermal",
             LineView::DriftAngle => "Drift Angle",
+            LineView::FinalGlide => "Final Glide",
+            LineView::Flaps => "Flaps",
             LineView::FlightLevel => "Flight Level",
+            LineView::LastThermal => "Last Thermal",
+            LineView::McSuggestion => "MC Suggestion",
+            LineView::OatDensityAltitude => "OAT, Density Alt",
+            LineView::Qnh => "QNH",
             LineView::SpeedToFly => "Speed to Fly",
             LineView::TrueAirSpeed => "True Air Speed",
             LineView::TrueCourse => "True Course",
@@ -131,9 +167,17 @@ impl LineView {
     {
         match self {
             LineView::None => Ok(()),
+            LineView::Altitude => draw_altitude(display, cm, pos, color),
             LineView::AverageClimbRate => draw_average_climb_rate(display, cm, pos, color),
+            LineView::AvgThermal => draw_avg_thermal(display, cm, pos, color),
             LineView::DriftAngle => draw_drift_angle(display, cm, pos, color),
+            LineView::FinalGlide => draw_final_glide(display, cm, pos, color),
+            LineView::Flaps => draw_flaps(display, cm, pos, color),
             LineView::FlightLevel => draw_flight_level(display, cm, pos, color),
+            LineView::LastThermal => draw_last_thermal(display, cm, pos, color),
+            LineView::McSuggestion => draw_mc_suggestion(display, cm, pos, color),
+            LineView::OatDensityAltitude => draw_oat_density_altitude(display, cm, pos, color),
+            LineView::Qnh => draw_qnh(display, cm, pos, color),
             LineView::SpeedToFly => draw_speed_to_fly(display, cm, pos, color),
             LineView::TrueAirSpeed => draw_true_air_speed(display, cm, pos, color),
             LineView::TrueCourse => draw_true_course(display, cm, pos, color),
@@ -155,33 +199,44 @@ where
     D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
 {
     let avg_climb_rate = match cm.control.avg_climb_rate_src {
-        DataSource::Frontend => cm.calculated.av2_climb_rate.to_m_s(),
-        DataSource::Sensorbox => cm.sensor.average_climb_rate.to_m_s(),
+        DataSource::Frontend => cm.calculated.av2_climb_rate,
+        DataSource::Sensorbox => cm.sensor.average_climb_rate,
     };
-    let s = if avg_climb_rate < 0.0 {
-        tformat!(5, "{:.1}", avg_climb_rate).unwrap()
-    } else {
-        tformat!(5, "+{:.1}", avg_climb_rate).unwrap()
-    };
-    let txt_x = pos.x - cm.device_const.sizes.display.m_s.width as i32 / 2;
-    let result = cm.device_const.big_font.render_aligned(
+    let s = format_climb_rate(cm, avg_climb_rate);
+    draw_value_and_unit(
+        display,
+        cm,
         s.as_str(),
-        Point::new(txt_x, pos.y),
-        VerticalPosition::Center,
-        HorizontalAlignment::Center,
-        FontColor::Transparent(color),
+        cm.config.units.vertical_speed.as_str(),
+        pos,
+        color,
+    )
+}
+
+fn draw_avg_thermal<D>(
+    display: &mut D,
+    cm: &CoreModel,
+    pos: Point,
+    color: Colors,
+) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    // average climb rate of all thermals today
+    let s = match cm.flight_stats.avg_climb_rate() {
+        Some(climb_rate) => {
+            tformat!(12, "AT {}", format_climb_rate(cm, climb_rate).as_str()).unwrap()
+        }
+        None => tformat!(12, "AT --").unwrap(),
+    };
+    draw_value_and_unit(
         display,
-    )?;
-    if let Some(rectangle) = result {
-        let pic_x = txt_x + 2 + (rectangle.size.width / 2) as i32;
-        let pic_y = pos.y - (cm.device_const.sizes.display.m_s.height as i32) / 2;
-        display.draw_img(
-            cm.device_const.images.m_s,
-            Point::new(pic_x, pic_y),
-            Some(color),
-        )?;
-    }
-    Ok(())
+        cm,
+        s.as_str(),
+        cm.config.units.vertical_speed.as_str(),
+        pos,
+        color,
+    )
 }
 
 fn draw_drift_angle<D>(
@@ -221,6 +276,64 @@ where
     Ok(())
 }
 
+fn draw_final_glide<D>(
+    display: &mut D,
+    cm: &CoreModel,
+    pos: Point,
+    color: Colors,
+) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    // arrival height above the safety altitude at the destination
+    let unit = cm.config.units.altitude;
+    let s = match cm.navigation.final_glide {
+        Some(final_glide) => {
+            let arrival_height = unit.value(final_glide.arrival_height);
+            if arrival_height > 0.0 {
+                tformat!(10, "FG +{:.0}", arrival_height).unwrap()
+            } else {
+                tformat!(10, "FG {:.0}", arrival_height).unwrap()
+            }
+        }
+        None => tformat!(10, "FG --").unwrap(),
+    };
+    draw_value_and_unit(display, cm, s.as_str(), unit.as_str(), pos, color)
+}
+
+fn draw_flaps<D>(
+    display: &mut D,
+    cm: &CoreModel,
+    pos: Point,
+    color: Colors,
+) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    // current flap setting and the recommendation for the current airspeed
+    let s = match (
+        cm.glider_data.basic_glider_data.flaps,
+        cm.calculated.optimal_flap,
+    ) {
+        (Some(flaps), Some(optimal)) => match cm.calculated.flap_setting {
+            Some(current) if current == optimal => tformat!(12, "F {}", flaps.label(current)),
+            Some(current) => tformat!(12, "F {} > {}", flaps.label(current), flaps.label(optimal)),
+            None => tformat!(12, "F -- > {}", flaps.label(optimal)),
+        }
+        .unwrap(),
+        _ => tformat!(12, "F --").unwrap(),
+    };
+    cm.device_const.big_font.render_aligned(
+        s.as_str(),
+        pos,
+        VerticalPosition::Center,
+        HorizontalAlignment::Center,
+        FontColor::Transparent(color),
+        display,
+    )?;
+    Ok(())
+}
+
 fn draw_flight_level<D>(
     display: &mut D,
     cm: &CoreModel,
@@ -248,6 +361,75 @@ where
     Ok(())
 }
 
+fn draw_last_thermal<D>(
+    display: &mut D,
+    cm: &CoreModel,
+    pos: Point,
+    color: Colors,
+) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    // average climb rate of the last thermal
+    let s = match cm.flight_stats.last_thermal() {
+        Some(thermal) => {
+            let climb_rate = format_climb_rate(cm, thermal.avg_climb_rate());
+            tformat!(12, "LT {}", climb_rate.as_str()).unwrap()
+        }
+        None => tformat!(12, "LT --").unwrap(),
+    };
+    draw_value_and_unit(
+        display,
+        cm,
+        s.as_str(),
+        cm.config.units.vertical_speed.as_str(),
+        pos,
+        color,
+    )
+}
+
+fn draw_mc_suggestion<D>(
+    display: &mut D,
+    cm: &CoreModel,
+    pos: Point,
+    color: Colors,
+) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    let unit = cm.config.units.vertical_speed;
+    let s = match cm.calculated.mc_suggestion {
+        Some(mc) => match unit.dec_places() {
+            0 => tformat!(10, "MC {:.0}", unit.value(mc)).unwrap(),
+            _ => tformat!(10, "MC {:.1}", unit.value(mc)).unwrap(),
+        },
+        None => tformat!(10, "MC --").unwrap(),
+    };
+    draw_value_and_unit(display, cm, s.as_str(), unit.as_str(), pos, color)
+}
+
+fn draw_oat_density_altitude<D>(
+    display: &mut D,
+    cm: &CoreModel,
+    pos: Point,
+    color: Colors,
+) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    // outside air temperature and density altitude in the altitude unit
+    let altitude = cm.config.units.altitude;
+    let density_altitude = altitude.value(density_altitude(cm.sensor.density));
+    let s = match cm.sensor.oat {
+        Some(oat) => {
+            let oat = cm.config.units.temperature.value(oat);
+            tformat!(14, "{:.0}° {:.0}", oat, density_altitude).unwrap()
+        }
+        None => tformat!(14, "--° {:.0}", density_altitude).unwrap(),
+    };
+    draw_value_and_unit(display, cm, s.as_str(), altitude.as_str(), pos, color)
+}
+
 fn draw_speed_to_fly<D>(
     display: &mut D,
     cm: &CoreModel,
@@ -257,27 +439,10 @@ fn draw_speed_to_fly<D>(
 where
     D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
 {
-    let stf = cm.calculated.speed_to_fly_1s.to_km_h();
+    let unit = cm.config.units.speed;
+    let stf = unit.value(cm.calculated.speed_to_fly_1s);
     let s = tformat!(8, "stf {:.0}", stf).unwrap();
-    let txt_x = pos.x - cm.device_const.sizes.display.km_h.width as i32 / 2;
-    let result = cm.device_const.big_font.render_aligned(
-        s.as_str(),
-        Point::new(txt_x, pos.y),
-        VerticalPosition::Center,
-        HorizontalAlignment::Center,
-        FontColor::Transparent(color),
-        display,
-    )?;
-    if let Some(rectangle) = result {
-        let pic_x = txt_x + 2 + (rectangle.size.width / 2) as i32;
-        let pic_y = pos.y - (cm.device_const.sizes.display.km_h.height as i32) / 2;
-        display.draw_img(
-            cm.device_const.images.km_h,
-            Point::new(pic_x, pic_y),
-            Some(color),
-        )?;
-    }
-    Ok(())
+    draw_value_and_unit(display, cm, s.as_str(), unit.as_str(), pos, color)
 }
 
 fn draw_true_air_speed<D>(
@@ -289,27 +454,10 @@ fn draw_true_air_speed<D>(
 where
     D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
 {
-    let tas = cm.sensor.airspeed.tas().to_km_h();
+    let unit = cm.config.units.speed;
+    let tas = unit.value(cm.sensor.airspeed.tas());
     let s = tformat!(8, "tas {:.0}", tas).unwrap();
-    let txt_x = pos.x - cm.device_const.sizes.display.km_h.width as i32 / 2;
-    let result = cm.device_const.big_font.render_aligned(
-        s.as_str(),
-        Point::new(txt_x, pos.y),
-        VerticalPosition::Center,
-        HorizontalAlignment::Center,
-        FontColor::Transparent(color),
-        display,
-    )?;
-    if let Some(rectangle) = result {
-        let pic_x = txt_x + 2 + (rectangle.size.width / 2) as i32;
-        let pic_y = pos.y - (cm.device_const.sizes.display.km_h.height as i32) / 2;
-        display.draw_img(
-            cm.device_const.images.km_h,
-            Point::new(pic_x, pic_y),
-            Some(color),
-        )?;
-    }
-    Ok(())
+    draw_value_and_unit(display, cm, s.as_str(), unit.as_str(), pos, color)
 }
 
 fn draw_true_course<D>(
@@ -375,7 +523,7 @@ where
     };
 
     let wind_deg = angle.to_degrees();
-    let wind_speed = cm.sensor.wind_vector.speed().to_km_h();
+    let wind_speed = cm.config.units.speed.value(cm.sensor.wind_vector.speed());
     let wind_x = pos.x - cm.device_const.sizes.display.km_h.width as i32 / 2;
     let wind_y = pos.y - (total_height as i32) / 2;
     let s = tformat!(25, "{:.0}° {:.0}", wind_deg, wind_speed).unwrap();
@@ -389,15 +537,19 @@ where
     )?;
 
     if let Some(rectangle) = result {
-        let pic_x = wind_x + 2 + (rectangle.size.width / 2) as i32;
-        display.draw_img(
-            cm.device_const.images.km_h,
-            Point::new(pic_x, wind_y),
-            Some(color),
+        let unit_x = wind_x + 2 + (rectangle.size.width / 2) as i32;
+        let unit_y = wind_y + (cm.device_const.sizes.display.km_h.height / 2) as i32;
+        draw_unit(
+            display,
+            cm,
+            cm.config.units.speed.as_str(),
+            Point::new(unit_x, unit_y),
+            HorizontalAlignment::Left,
+            color,
         )?;
     }
 
-    let avg_wind_spped = cm.sensor.average_wind.speed().to_km_h();
+    let avg_wind_spped = cm.config.units.speed.value(cm.sensor.average_wind.speed());
     let avg_wind_angle = cm.sensor.average_wind.angle().to_degrees();
     let delta_speed = wind_speed - avg_wind_spped;
     let (avg_txt, avg_color) = if delta_speed < 0.0 {
@@ -444,7 +596,7 @@ where
     };
 
     let wind_deg = angle.to_degrees();
-    let wind_speed = cm.sensor.wind_vector.speed().to_km_h();
+    let wind_speed = cm.config.units.speed.value(cm.sensor.wind_vector.speed());
     let wind_x = pos.x - cm.device_const.sizes.display.km_h.width as i32 / 2;
     let wind_y = pos.y - (total_height as i32) / 2;
     let s = tformat!(25, "{:.0}° {:.0}", wind_deg, wind_speed).unwrap();
@@ -458,15 +610,19 @@ where
     )?;
 
     if let Some(rectangle) = result {
-        let pic_x = wind_x + 2 + (rectangle.size.width / 2) as i32;
-        display.draw_img(
-            cm.device_const.images.km_h,
-            Point::new(pic_x, wind_y),
-            Some(color),
+        let unit_x = wind_x + 2 + (rectangle.size.width / 2) as i32;
+        let unit_y = wind_y + (cm.device_const.sizes.display.km_h.height / 2) as i32;
+        draw_unit(
+            display,
+            cm,
+            cm.config.units.speed.as_str(),
+            Point::new(unit_x, unit_y),
+            HorizontalAlignment::Left,
+            color,
         )?;
     }
 
-    let avg_wind_spped = cm.sensor.average_wind.speed().to_km_h();
+    let avg_wind_spped = cm.config.units.speed.value(cm.sensor.average_wind.speed());
     let delta_speed = wind_speed - avg_wind_spped;
     let (delta_txt, delta_color) = if delta_speed < 0.0 {
         (
@@ -491,3 +647,66 @@ where
     )?;
     Ok(())
 }
+
+fn draw_altitude<D>(
+    display: &mut D,
+    cm: &CoreModel,
+    pos: Point,
+    color: Colors,
+) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    let unit = cm.config.units.altitude;
+    let altitude = unit.value(cm.sensor.pressure_altitude.qnh_altitude());
+    let s = tformat!(8, "{:.0}", altitude).unwrap();
+    draw_value_and_unit(display, cm, s.as_str(), unit.as_str(), pos, color)
+}
+
+fn draw_qnh<D>(display: &mut D, cm: &CoreModel, pos: Point, color: Colors) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    let unit = cm.config.units.pressure;
+    let qnh = unit.value(cm.sensor.pressure_altitude.qnh());
+    let s = match unit.dec_places() {
+        0 => tformat!(10, "QNH {:.0}", qnh).unwrap(),
+        _ => tformat!(10, "QNH {:.2}", qnh).unwrap(),
+    };
+    draw_value_and_unit(display, cm, s.as_str(), unit.as_str(), pos, color)
+}
+
+/// Draw a value centered at pos, followed by its unit
+fn draw_value_and_unit<D>(
+    display: &mut D,
+    cm: &CoreModel,
+    value: &str,
+    unit: &str,
+    pos: Point,
+    color: Colors,
+) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    let txt_x = pos.x - cm.device_const.sizes.display.m_s.width as i32 / 2;
+    let result = cm.device_const.big_font.render_aligned(
+        value,
+        Point::new(txt_x, pos.y),
+        VerticalPosition::Center,
+        HorizontalAlignment::Center,
+        FontColor::Transparent(color),
+        display,
+    )?;
+    if let Some(rectangle) = result {
+        let unit_x = txt_x + 2 + (rectangle.size.width / 2) as i32;
+        draw_unit(
+            display,
+            cm,
+            unit,
+            Point::new(unit_x, pos.y),
+            HorizontalAlignment::Left,
+            color,
+        )?;
+    }
+    Ok(())
+}
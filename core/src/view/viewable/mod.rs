@@ -2,3 +2,4 @@ pub(crate) mod centerview;
 pub(crate) mod circle_area;
 pub(crate) mod dialog_box;
 pub(crate) mod lineview;
+pub(crate) mod units;
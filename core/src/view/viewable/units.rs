@@ -0,0 +1,59 @@
+use crate::{
+    model::{SpeedUnit, VerticalSpeedUnit},
+    tformat, Colors, CoreError, CoreModel, DrawImage, Speed,
+};
+use embedded_graphics::{draw_target::DrawTarget, geometry::Point};
+use heapless::String;
+use u8g2_fonts::types::{FontColor, HorizontalAlignment, VerticalPosition};
+
+/// Draw the unit of a displayed value
+///
+/// The metric units m/s and km/h are available as images of the device, all other units are
+/// rendered with the small font. pos is the left center of the unit, centered text is aligned to
+/// the width of the m/s image.
+pub fn draw_unit<D>(
+    display: &mut D,
+    cm: &CoreModel,
+    unit: &str,
+    pos: Point,
+    alignment: HorizontalAlignment,
+    color: Colors,
+) -> Result<(), CoreError>
+where
+    D: DrawTarget<Color = Colors, Error = CoreError> + DrawImage,
+{
+    let d_sizes = &cm.device_const.sizes.display;
+    if unit == VerticalSpeedUnit::MS.as_str() {
+        let img_pos = Point::new(pos.x, pos.y - d_sizes.m_s.height as i32 / 2);
+        display.draw_img(cm.device_const.images.m_s, img_pos, Some(color))
+    } else if unit == SpeedUnit::KmH.as_str() {
+        let img_pos = Point::new(pos.x, pos.y - d_sizes.km_h.height as i32 / 2);
+        display.draw_img(cm.device_const.images.km_h, img_pos, Some(color))
+    } else {
+        let x = match alignment {
+            HorizontalAlignment::Center => pos.x + d_sizes.m_s.width as i32 / 2,
+            _ => pos.x,
+        };
+        cm.device_const.small_font.render_aligned(
+            unit,
+            Point::new(x, pos.y),
+            VerticalPosition::Center,
+            alignment,
+            FontColor::Transparent(color),
+            display,
+        )?;
+        Ok(())
+    }
+}
+
+/// Format a climb rate in the unit of the user profile with a leading sign
+pub fn format_climb_rate(cm: &CoreModel, climb_rate: Speed) -> String<8> {
+    let unit = cm.config.units.vertical_speed;
+    let value = unit.value(climb_rate);
+    match (unit.dec_places(), value < 0.0) {
+        (0, true) => tformat!(8, "{:.0}", value).unwrap(),
+        (0, false) => tformat!(8, "+{:.0}", value).unwrap(),
+        (_, true) => tformat!(8, "{:.1}", value).unwrap(),
+        (_, false) => tformat!(8, "+{:.1}", value).unwrap(),
+    }
+}
@@ -1,6 +1,9 @@
 #[path = "can/filter.rs"]
 mod filter;
 
+#[path = "can/remote_config.rs"]
+mod remote_config;
+
 #[path = "can/same_object_id.rs"]
 mod same_object_id;
 
@@ -0,0 +1,16 @@
+use corelib::*;
+use heapless::spsc::Queue;
+
+/// Model and controller with the output queue of the CAN frames
+#[allow(unused)]
+pub fn get_the_cores() -> (CoreModel, CoreController, CTxFrames<10>) {
+    // every test gets its own queues, the tests run in parallel
+    let q_tx_frames: &'static mut QTxFrames<10> = Box::leak(Box::new(Queue::new()));
+    let (p_tx_frames, c_tx_frames) = q_tx_frames.split();
+    let q_idle_events: &'static mut QIdleEvents = Box::leak(Box::new(Queue::new()));
+    let (p_idle_events, _c_idle_events) = q_idle_events.split();
+
+    let mut cm = CoreModel::new(&TEST_DEVICE_CONST, 1234_u32);
+    let cc = CoreController::new(&mut cm, p_idle_events, p_tx_frames);
+    (cm, cc, c_tx_frames)
+}
@@ -0,0 +1,183 @@
+mod cores;
+use cores::*;
+
+use corelib::*;
+
+fn request(config_id: u16, cmd: SysSettingCmd, value: f32) -> Frame {
+    Frame::Generic(GenericFrame {
+        generic_id: GenericId::SetSysSetting as u16,
+        can_frame: CanFrame::empty_from_id(0x412)
+            .push_u16(config_id)
+            .push_u8(0)
+            .push_u8(cmd as u8)
+            .push_f32(value),
+    })
+}
+
+// Returns config id, status and value of the answer, other frames are skipped
+fn answer(c_tx_frames: &mut CTxFrames<10>) -> Option<(u16, u8, f32)> {
+    while let Some(frame) = c_tx_frames.dequeue() {
+        if let Frame::Specific(frame) = frame {
+            if frame.specific_id == SpecialId::ConfigValue as u16 {
+                let can_frame = frame.can_frame;
+                return Some((
+                    can_frame.read_u16(0),
+                    can_frame.read_u8(2),
+                    can_frame.read_f32(4),
+                ));
+            }
+        }
+    }
+    None
+}
+
+#[test]
+fn get_all_frontend_ids() {
+    let (mut cm, mut cc, mut c_tx_frames) = get_the_cores();
+
+    for config_id in 0..(CanConfigId::Ignore as u16) {
+        cc.read_can_frame(&mut cm, &request(config_id, SysSettingCmd::Get, 0.0));
+        let (id, status, value) = answer(&mut c_tx_frames).unwrap();
+        assert_eq!(id, config_id);
+        assert_eq!(status, ConfigStatus::Ok as u8);
+        assert!(value.is_finite());
+    }
+
+    cc.read_can_frame(
+        &mut cm,
+        &request(CanConfigId::MacCready as u16, SysSettingCmd::Get, 0.0),
+    );
+    let (_, _, value) = answer(&mut c_tx_frames).unwrap();
+    assert_eq!(value, cm.config.mc_cready.to_m_s());
+}
+
+#[test]
+fn set_frontend_ids() {
+    let (mut cm, mut cc, mut c_tx_frames) = get_the_cores();
+
+    cc.read_can_frame(
+        &mut cm,
+        &request(CanConfigId::MacCready as u16, SysSettingCmd::Set, 1.5),
+    );
+    assert_eq!(
+        answer(&mut c_tx_frames),
+        Some((1, ConfigStatus::Ok as u8, 1.5))
+    );
+    assert_eq!(cm.config.mc_cready.to_m_s(), 1.5);
+
+    cc.read_can_frame(
+        &mut cm,
+        &request(CanConfigId::Bugs as u16, SysSettingCmd::Set, f32::NAN),
+    );
+    let (_, status, value) = answer(&mut c_tx_frames).unwrap();
+    assert_eq!(status, ConfigStatus::InvalidValue as u8);
+    assert!(value.is_nan());
+
+    cc.read_can_frame(
+        &mut cm,
+        &request(
+            CanConfigId::VarioModeControl as u16,
+            SysSettingCmd::Set,
+            1.0,
+        ),
+    );
+    let (_, status, _) = answer(&mut c_tx_frames).unwrap();
+    assert_eq!(status, ConfigStatus::ReadOnly as u8);
+
+    cc.read_can_frame(
+        &mut cm,
+        &request(CanConfigId::VarioMode as u16, SysSettingCmd::Set, 7.0),
+    );
+    let (_, status, _) = answer(&mut c_tx_frames).unwrap();
+    assert_eq!(status, ConfigStatus::InvalidValue as u8);
+}
+
+#[test]
+fn unknown_and_sensor_box_ids() {
+    let (mut cm, mut cc, mut c_tx_frames) = get_the_cores();
+
+    for config_id in [CanConfigId::Ignore as u16, 0x0042, 0x2010, 0x4000] {
+        cc.read_can_frame(&mut cm, &request(config_id, SysSettingCmd::Get, 0.0));
+        let (id, status, _) = answer(&mut c_tx_frames).unwrap();
+        assert_eq!(id, config_id);
+        assert_eq!(status, ConfigStatus::UnknownId as u8);
+    }
+
+    // without a sensor box on the bus its ids are not available
+    cc.read_can_frame(
+        &mut cm,
+        &request(CanConfigId::PitotOffset as u16, SysSettingCmd::Get, 0.0),
+    );
+    let (_, status, _) = answer(&mut c_tx_frames).unwrap();
+    assert_eq!(status, ConfigStatus::NotAvailable as u8);
+
+    // with a sensor box the request is forwarded
+    let sensor_box = CanParticipant {
+        vda: 2,
+        object_id: object_id::SENSOR,
+        age: 0,
+        frame_rate: 100,
+    };
+    cm.can_bus.update(40, [sensor_box].into_iter());
+    cc.read_can_frame(
+        &mut cm,
+        &request(CanConfigId::PitotOffset as u16, SysSettingCmd::Set, 0.5),
+    );
+    match c_tx_frames.dequeue() {
+        Some(Frame::Generic(frame)) => {
+            assert_eq!(frame.can_frame.read_u16(0), CanConfigId::PitotOffset as u16);
+            assert_eq!(frame.can_frame.read_u8(2), 1);
+            assert_eq!(frame.can_frame.read_f32(4), 0.5);
+        }
+        _ => panic!("request not forwarded"),
+    }
+}
+
+#[test]
+fn push_is_not_answered() {
+    let (mut cm, mut cc, mut c_tx_frames) = get_the_cores();
+
+    cc.read_can_frame(
+        &mut cm,
+        &request(CanConfigId::MacCready as u16, SysSettingCmd::Push, 2.0),
+    );
+    assert_eq!(cm.config.mc_cready.to_m_s(), 2.0);
+    assert!(answer(&mut c_tx_frames).is_none());
+}
+
+#[test]
+fn values_out_of_range() {
+    let (mut cm, mut cc, mut c_tx_frames) = get_the_cores();
+    cm.glider_data.basic_glider_data.max_ballast = 100.0;
+    let volume = cm.config.volume;
+    let mc_cready = cm.config.mc_cready.to_m_s();
+    let bugs = cm.glider_data.bugs;
+    let ballast = cm.glider_data.water_ballast.to_kg();
+
+    // values outside of the editor limits are rejected and not applied
+    for (config_id, value) in [
+        (CanConfigId::MacCready, -50.0),
+        (CanConfigId::Volume, 200.0),
+        (CanConfigId::Bugs, 100.0),
+        (CanConfigId::WaterBallast, 120.0),
+        (CanConfigId::Qnh, 500.0),
+        (CanConfigId::TcClimbRate, 5.0),
+    ] {
+        cc.read_can_frame(&mut cm, &request(config_id as u16, SysSettingCmd::Set, value));
+        let (_, status, value) = answer(&mut c_tx_frames).unwrap();
+        assert_eq!(status, ConfigStatus::InvalidValue as u8);
+        assert!(value.is_nan());
+    }
+    assert_eq!(cm.config.volume, volume);
+    assert_eq!(cm.config.mc_cready.to_m_s(), mc_cready);
+    assert_eq!(cm.glider_data.bugs, bugs);
+    assert_eq!(cm.glider_data.water_ballast.to_kg(), ballast);
+
+    cc.read_can_frame(
+        &mut cm,
+        &request(CanConfigId::WaterBallast as u16, SysSettingCmd::Set, 80.0),
+    );
+    let (_, status, _) = answer(&mut c_tx_frames).unwrap();
+    assert_eq!(status, ConfigStatus::Ok as u8);
+    assert_eq!(cm.glider_data.water_ballast.to_kg(), 80.0);
+}
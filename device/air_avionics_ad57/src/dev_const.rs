@@ -93,6 +93,8 @@ const fn vario_sizes() -> VarioSizes {
         wind_len: 105,
         wind_len_min: 50,
         angle_m_s: 25.0,
+        scale_label_radius: 116,
+        scale_label_diameter: 22,
     }
 }
 
@@ -4,15 +4,18 @@ use stm32f4xx_hal::{timer::monotonic::fugit::ExtU32, watchdog::IndependentWatchd
 
 use crate::{
     driver::{delay_ms, QEvents, Storage},
-    install_and_restart, update_available, ResetWatch,
+    install_and_restart, sensorbox_block, sensorbox_image, update_available, IgcLogger, ResetWatch,
 };
 use corelib::{CIdleEvents, DeviceEvent, Eeprom, Event, IdleEvent, SdCardCmd};
+use heapless::String;
 
 pub struct IdleLoop {
     eeprom: Eeprom<Storage>,
     c_idle_events: CIdleEvents,
     q_events: &'static QEvents,
     watchdog: IndependentWatchdog,
+    igc_logger: IgcLogger,
+    sensorbox_image: Option<String<12>>,
 }
 
 impl IdleLoop {
@@ -41,6 +44,8 @@ impl IdleLoop {
             c_idle_events,
             q_events,
             watchdog,
+            igc_logger: IgcLogger::new(),
+            sensorbox_image: None,
         }
     }
 
@@ -72,6 +77,26 @@ impl IdleLoop {
                                 self.watchdog.start(ExtU32::millis(1000));
                                 trace!("Start watchdog");
                             }
+                            SdCardCmd::IgcStart(header) => self.igc_logger.start(&header),
+                            SdCardCmd::IgcFix(fix) => self.igc_logger.fix(&fix),
+                            SdCardCmd::IgcStop => self.igc_logger.stop(),
+                            SdCardCmd::SensorboxCheck(hw_version, sw_version) => {
+                                let image = sensorbox_image(hw_version, sw_version);
+                                if let Some((name, image)) = image {
+                                    trace!("Sensorbox update available: {}", image.sw_version);
+                                    self.sensorbox_image = Some(name);
+                                    let event = DeviceEvent::SensorboxFwAvailable(image);
+                                    let _ = self.q_events.enqueue(Event::DeviceItem(event));
+                                }
+                            }
+                            SdCardCmd::SensorboxRead(offset) => {
+                                if let Some(name) = &self.sensorbox_image {
+                                    if let Some(block) = sensorbox_block(name, offset) {
+                                        let event = DeviceEvent::SensorboxFwBlock(block);
+                                        let _ = self.q_events.enqueue(Event::DeviceItem(event));
+                                    }
+                                }
+                            }
                         }
                     }
                     IdleEvent::DateTime(date_time) => {
@@ -157,15 +157,23 @@ mod app {
     }
 
     /// Task to support can dispatcher with timing functions
-    #[task(shared = [can_tx, statistics, can_dispatch], priority=6)]
+    #[task(shared = [can_tx, statistics, can_dispatch, core_model], priority=6)]
     fn task_can_timer(mut cx: task_can_timer::Context) {
         task_start!(cx, Task::CanTimer);
         let ticks = app::monotonics::now().ticks();
 
-        let next_wakeup = cx
-            .shared
-            .can_dispatch
-            .lock(|can_dispatch| can_dispatch.tick(ticks));
+        let next_wakeup = cx.shared.can_dispatch.lock(|can_dispatch| {
+            let next_wakeup = can_dispatch.tick(ticks);
+            // provide the bus participants for the diagnostics page
+            cx.shared
+                .core_model
+                .lock(|core_model| {
+                    core_model
+                        .can_bus
+                        .update(can_dispatch.vda(), can_dispatch.participants())
+                });
+            next_wakeup
+        });
         let instant = cx.shared.can_tx.lock(|can_tx| {
             can_tx.wakeup_at = next_wakeup.unwrap_or(can_tx.wakeup_at + 100_000);
             DevInstant::from_ticks(can_tx.wakeup_at)
@@ -1,9 +1,11 @@
 mod error;
+mod igc_logger;
 mod statistics;
 mod update;
 mod version;
 
 pub use error::*;
+pub use igc_logger::*;
 pub use statistics::{Statistics, Task};
 pub use update::*;
 pub use version::*;
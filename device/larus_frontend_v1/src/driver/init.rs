@@ -121,9 +121,14 @@ pub fn hw_init(
 
     let mut can_dispatch = CanDispatch::new(rnd, p_tx_irq_frames, p_rx_frames, c_tx_frames);
     can_dispatch.set_legacy_filter(0x100, 0x11f).unwrap();
-    can_dispatch.set_legacy_filter(0x282, 0x282).unwrap(); // Vario display master device avg_climb_rates
+    can_dispatch.set_legacy_filter(0x282, 0x284).unwrap(); // Vario display master device climb rates, state, settings
+    can_dispatch.set_legacy_filter(0x203, 0x204).unwrap(); // Audio legacy temperature, humidity
+    can_dispatch.set_legacy_filter(0x206, 0x206).unwrap(); // Audio legacy flaps
+    can_dispatch.set_legacy_filter(0x303, 0x304).unwrap(); // Frontend legacy temperature, humidity
     let _ = can_dispatch.set_object_id_filter(2); // Sensorbox
     let _ = can_dispatch.set_object_id_filter(3); // Gps
+    let _ = can_dispatch.set_object_id_filter(5); // Stick remote, button panel
+    let _ = can_dispatch.set_object_id_filter(6); // Engine controller
 
     // Setup ----------> CoreModel
     let mut core_model = CoreModel::new(&&DEVICE_CONST, uuid());
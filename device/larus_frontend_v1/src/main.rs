@@ -142,15 +142,23 @@ mod app {
     }
 
     /// Task to support can dispatcher with timing functions
-    #[task(shared = [statistics, can_dispatch, can_tx], priority=5)]
+    #[task(shared = [statistics, can_dispatch, can_tx, core_model], priority=5)]
     fn task_can_timer(mut cx: task_can_timer::Context) {
         task_start!(cx, Task::CanTimer);
 
         let ticks = app::monotonics::now().ticks();
-        let next_wakeup = cx
-            .shared
-            .can_dispatch
-            .lock(|can_dispatch| can_dispatch.tick(ticks));
+        let next_wakeup = cx.shared.can_dispatch.lock(|can_dispatch| {
+            let next_wakeup = can_dispatch.tick(ticks);
+            // provide the bus participants for the diagnostics page
+            cx.shared
+                .core_model
+                .lock(|core_model| {
+                    core_model
+                        .can_bus
+                        .update(can_dispatch.vda(), can_dispatch.participants())
+                });
+            next_wakeup
+        });
         let wakeup_at = cx.shared.can_tx.lock(|can_tx| {
             let wakeup_at = next_wakeup.unwrap_or(can_tx.wakeup_at + 100_000);
             can_tx.wakeup_at = wakeup_at;
@@ -220,6 +228,7 @@ mod app {
                         core_model.calculated.frequency,
                         core_model.calculated.continuous,
                         core_model.calculated.gain,
+                        core_model.calculated.duty_cycle,
                     ))
                 } else {
                     None
@@ -228,8 +237,9 @@ mod app {
         });
 
         // set sound params
-        if let Some((frequecy, continuous, gain)) = recalc {
+        if let Some((frequecy, continuous, gain, duty_cycle)) = recalc {
             cx.shared.sound.lock(|sound| {
+                sound.set_duty_cycle(duty_cycle);
                 sound.set_params(frequecy, continuous, gain);
             });
         }
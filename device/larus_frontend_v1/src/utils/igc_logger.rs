@@ -0,0 +1,103 @@
+use corelib::{CoreError, IgcFix, IgcHeader, IGC_FILE_NAME_LEN};
+use defmt::trace;
+use embedded_sdmmc::{Error as SdmmcError, Mode, VolumeIdx};
+use heapless::{String, Vec};
+
+use crate::driver::*;
+
+/// Fixes are collected until one block of the SD card is filled
+const BLOCK_SIZE: usize = 512;
+
+/// Writes the IGC file of the current flight to the SD card
+///
+/// The data is buffered and written block by block. Every write access opens and closes the file
+/// again, so that the file is always complete when the device is switched off. The logger runs
+/// in the idle loop, so the SD card is written with interrupts enabled. The lock of FILE_SYS
+/// keeps the panic handler away from the file system during a write.
+///
+/// The logger is shared with larus_frontend_v2, which has the same file system driver.
+pub struct IgcLogger {
+    file_name: Option<String<IGC_FILE_NAME_LEN>>,
+    buffer: Vec<u8, { 2 * BLOCK_SIZE }>,
+}
+
+impl IgcLogger {
+    pub fn new() -> Self {
+        IgcLogger {
+            file_name: None,
+            buffer: Vec::new(),
+        }
+    }
+
+    pub fn start(&mut self, header: &IgcHeader) {
+        self.stop();
+        self.file_name = create_file(header).ok();
+        if let Some(name) = &self.file_name {
+            trace!("IGC file {} created", name.as_str());
+            let _ = self.buffer.extend_from_slice(header.to_string().as_bytes());
+        }
+    }
+
+    pub fn fix(&mut self, fix: &IgcFix) {
+        if self.file_name.is_some() {
+            let _ = self.buffer.extend_from_slice(fix.to_string().as_bytes());
+            if self.buffer.len() >= BLOCK_SIZE {
+                self.flush();
+            }
+        }
+    }
+
+    pub fn stop(&mut self) {
+        if self.file_name.is_some() {
+            self.flush();
+            trace!("IGC file closed");
+        }
+        self.file_name = None;
+    }
+
+    fn flush(&mut self) {
+        if let Some(name) = &self.file_name {
+            if append(name.as_str(), &self.buffer).is_err() {
+                // SD card removed or full, stop logging
+                self.file_name = None;
+            }
+        }
+        self.buffer.clear();
+    }
+}
+
+/// Create a new file with the next free flight number of the day
+fn create_file(header: &IgcHeader) -> Result<String<IGC_FILE_NAME_LEN>, CoreError> {
+    FILE_SYS.lock_during_use(|opt_fs| {
+        let fs = opt_fs.ok_or(CoreError::SdCard)?;
+        let mut volume = fs
+            .vol_mgr()
+            .open_volume(VolumeIdx(0))
+            .map_err(|_| CoreError::SdCard)?;
+        let mut root_dir = volume.open_root_dir().map_err(|_| CoreError::SdCard)?;
+        for flight in 1..36 {
+            let name = header.file_name(flight);
+            match root_dir.open_file_in_dir(name.as_str(), Mode::ReadWriteCreate) {
+                Ok(_) => return Ok(name),
+                Err(SdmmcError::FileAlreadyExists) => (),
+                Err(_) => return Err(CoreError::SdCard),
+            }
+        }
+        Err(CoreError::SdCard)
+    })
+}
+
+fn append(name: &str, data: &[u8]) -> Result<(), CoreError> {
+    FILE_SYS.lock_during_use(|opt_fs| {
+        let fs = opt_fs.ok_or(CoreError::SdCard)?;
+        let mut volume = fs
+            .vol_mgr()
+            .open_volume(VolumeIdx(0))
+            .map_err(|_| CoreError::SdCard)?;
+        let mut root_dir = volume.open_root_dir().map_err(|_| CoreError::SdCard)?;
+        let mut file = root_dir
+            .open_file_in_dir(name, Mode::ReadWriteAppend)
+            .map_err(|_| CoreError::SdCard)?;
+        file.write(data).map_err(|_| CoreError::SdCard)
+    })
+}
@@ -1,8 +1,10 @@
+mod igc_logger;
 pub mod samples;
 mod statistics;
 mod update;
 mod version;
 
+pub use igc_logger::*;
 pub use statistics::*;
 pub use update::*;
 pub use version::*;
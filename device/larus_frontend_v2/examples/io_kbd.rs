@@ -52,6 +52,7 @@ fn main() -> ! {
             match event {
                 Event::KeyItem(key_event) => info!("KeyItem {}", *key_event as u32),
                 Event::DeviceItem(_) => info!("DeviceItem"),
+                Event::RemoteItem(button) => info!("RemoteItem {}", *button as u32),
                 Event::InputItem(io) => match io {
                     InputPinState::Io1(state) => info!("Input 1 state {}", *state as u32),
                     InputPinState::Io2(state) => info!("Input 2 state {}", *state as u32),
@@ -93,6 +93,8 @@ const fn vario_sizes() -> VarioSizes {
         wind_len: 150,
         wind_len_min: 80,
         angle_m_s: 25.0,
+        scale_label_radius: 192,
+        scale_label_diameter: 36,
     }
 }
 
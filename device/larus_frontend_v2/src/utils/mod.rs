@@ -1,8 +1,11 @@
+#[path = "../../../larus_frontend_v1/src/utils/igc_logger.rs"]
+mod igc_logger;
 pub mod samples;
 mod statistics;
 mod update;
 mod version;
 
+pub use igc_logger::*;
 pub use statistics::*;
 pub use update::*;
 pub use version::*;
@@ -1,5 +1,8 @@
 use core::str;
-use corelib::{stm32_crc, MetaDataV1, SwVersion, VersionCheck, SIZE_METADATA_V1};
+use corelib::{
+    stm32_crc, FwBlock, FwImage, HwVersion, MetaDataV1, SwVersion, VersionCheck, FW_BLOCK_SIZE,
+    SIZE_METADATA_V1,
+};
 use defmt::trace;
 use embedded_sdmmc::{Mode, ShortFileName, VolumeIdx};
 use embedded_storage::nor_flash::NorFlash;
@@ -18,42 +21,11 @@ pub fn update_available() -> Option<SwVersion> {
 }
 
 fn update_available_private(fs: &mut FileSys) -> Option<SwVersion> {
-    trace!("Check sd card");
-    let mut volume = fs.vol_mgr().open_volume(VolumeIdx(0)).ok()?;
-    let mut root_dir = volume.open_root_dir().ok()?;
-    trace!("Found file system");
-
-    // read root directory, look after *.bin files
-    let mut files = Vec::<ShortFileName, 20>::new();
-    root_dir
-        .iterate_dir(|entry| {
-            if entry.name.extension() == [66, 73, 78] && // BIN
-                entry.size > SIZE_METADATA_V1 as u32
-            {
-                let _ = files.push(entry.name.clone());
-            }
-        })
-        .ok()?;
-
-    // check the *.bin files if there is something interesting there
     let mut check = VersionCheck::new(HW_VERSION, SW_VERSION);
-    let mut buffer = [0_u8; SIZE_METADATA_V1];
-    for name in files {
-        let mut fname = String::<12>::new();
-        let base = unsafe { str::from_utf8_unchecked(name.base_name()) };
-        let _ = fname.push_str(base);
-        let _ = fname.push('.');
-        let ext = unsafe { str::from_utf8_unchecked(name.extension()) };
-        let _ = fname.push_str(ext);
+    scan_images(fs, &mut check)?;
 
-        let mut file = root_dir
-            .open_file_in_dir(fname.as_str(), Mode::ReadOnly)
-            .ok()?;
-        let num_read = file.read(&mut buffer).ok()?;
-        if num_read == SIZE_METADATA_V1 {
-            check.analyse(fname.as_str(), &buffer)
-        }
-    }
+    let mut volume = fs.vol_mgr().open_volume(VolumeIdx(0)).ok()?;
+    let mut root_dir = volume.open_root_dir().ok()?;
 
     let result = if let Some(image_name) = check.new_image_name() {
         // a new image file was found
@@ -107,6 +79,97 @@ fn update_available_private(fs: &mut FileSys) -> Option<SwVersion> {
     result
 }
 
+/// Look for a sensorbox image newer than the given versions, returns the file name and the image
+pub fn sensorbox_image(
+    hw_version: HwVersion,
+    sw_version: SwVersion,
+) -> Option<(String<12>, FwImage)> {
+    FILE_SYS.lock_during_use(|opt_fs| match opt_fs {
+        Some(fs) => sensorbox_image_private(fs, hw_version, sw_version),
+        None => None,
+    })
+}
+
+fn sensorbox_image_private(
+    fs: &mut FileSys,
+    hw_version: HwVersion,
+    sw_version: SwVersion,
+) -> Option<(String<12>, FwImage)> {
+    // the versions of the sensorbox decide which image fits
+    let mut check = VersionCheck::new(hw_version, sw_version);
+    scan_images(fs, &mut check)?;
+    let image_name = check.new_image_name()?.clone();
+
+    let mut volume = fs.vol_mgr().open_volume(VolumeIdx(0)).ok()?;
+    let mut root_dir = volume.open_root_dir().ok()?;
+    let image_file = root_dir
+        .open_file_in_dir(image_name.as_str(), Mode::ReadOnly)
+        .ok()?;
+    let image = FwImage {
+        sw_version: check.new_sw_version(),
+        len: image_file.length(),
+    };
+    Some((image_name, image))
+}
+
+// Analyse the metadata of all *.bin files in the root directory
+fn scan_images(fs: &mut FileSys, check: &mut VersionCheck) -> Option<()> {
+    trace!("Check sd card");
+    let mut volume = fs.vol_mgr().open_volume(VolumeIdx(0)).ok()?;
+    let mut root_dir = volume.open_root_dir().ok()?;
+    trace!("Found file system");
+
+    // read root directory, look after *.bin files
+    let mut files = Vec::<ShortFileName, 20>::new();
+    root_dir
+        .iterate_dir(|entry| {
+            if entry.name.extension() == [66, 73, 78] && // BIN
+                entry.size > SIZE_METADATA_V1 as u32
+            {
+                let _ = files.push(entry.name.clone());
+            }
+        })
+        .ok()?;
+
+    let mut buffer = [0_u8; SIZE_METADATA_V1];
+    for name in files {
+        let fname = file_name(&name)?;
+        let mut file = root_dir
+            .open_file_in_dir(fname.as_str(), Mode::ReadOnly)
+            .ok()?;
+        let num_read = file.read(&mut buffer).ok()?;
+        if num_read == SIZE_METADATA_V1 {
+            check.analyse(fname.as_str(), &buffer)
+        }
+    }
+    Some(())
+}
+
+// Name of a file in the form BASE.EXT
+fn file_name(name: &ShortFileName) -> Option<String<12>> {
+    let mut fname = String::<12>::new();
+    fname.push_str(str::from_utf8(name.base_name()).ok()?).ok()?;
+    fname.push('.').ok()?;
+    fname.push_str(str::from_utf8(name.extension()).ok()?).ok()?;
+    Some(fname)
+}
+
+/// Read one block of the sensorbox image
+pub fn sensorbox_block(image_name: &str, offset: u32) -> Option<FwBlock> {
+    FILE_SYS.lock_during_use(|opt_fs| match opt_fs {
+        Some(fs) => {
+            let mut volume = fs.vol_mgr().open_volume(VolumeIdx(0)).ok()?;
+            let mut root_dir = volume.open_root_dir().ok()?;
+            let mut file = root_dir.open_file_in_dir(image_name, Mode::ReadOnly).ok()?;
+            file.seek_from_start(offset).ok()?;
+            let mut buffer = [0_u8; FW_BLOCK_SIZE];
+            let num_read = file.read(&mut buffer).ok()?;
+            Some(FwBlock::new(offset, &buffer[..num_read]))
+        }
+        None => None,
+    })
+}
+
 pub fn install_and_restart() {
     let meta_data = meta_data();
     let func = unsafe { core::mem::transmute::<u32, fn()>(meta_data.copy_func) };
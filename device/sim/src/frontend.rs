@@ -10,7 +10,7 @@ use arboard::{Clipboard, ImageData};
 use crate::{
     Com, tcp::TcpServer, hardware, OutPins, LogSettings, Error,
     dev_const::{DEVICE_CONST, DISPLAY_WIDTH, DISPLAY_HEIGHT},
-    AppWindow, hardware::{CanReader, Display, Storage, Sound},
+    AppWindow, hardware::{CanReader, Display, IgcLogger, Storage, Sound},
     hardware::{DISPLAY_WIDTH_INC_PAD, DISPLAY_HEIGHT_INC_PAD},
 };
 
@@ -224,6 +224,7 @@ impl Frontend {
             let outputs_ = Outputs::new();
             let mut can_reader = CanReader::new("127.0.0.1:5005");
             let sound = Sound::new();
+            let mut igc_logger = IgcLogger::new();
             let mut img_no = 0;
             let mut clipboard_ctx = Clipboard::new().unwrap();
 
@@ -333,6 +334,7 @@ impl Frontend {
                         IdleEvent::ClearEepromItems(items_list) => {
                             eeprom.delete_items_list(items_list).unwrap();
                         }
+                        IdleEvent::SdCardItem(cmd) => igc_logger.sd_card_cmd(cmd),
                         IdleEvent::ResetDevice(reason) => {
                             println!("Reset triggered by app, reason ‘{:?}’, please restart", reason);
                             quit_event_loop().unwrap();
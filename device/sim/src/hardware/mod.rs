@@ -2,6 +2,7 @@ mod can;
 mod display;
 mod eeprom;
 mod pins;
+mod sd_card;
 mod sound;
 mod version;
 
@@ -9,5 +10,6 @@ pub use can::CanReader;
 pub use display::{Display, DISPLAY_WIDTH_INC_PAD, DISPLAY_HEIGHT_INC_PAD};
 pub use eeprom::Storage;
 pub use pins::{InPins, OutPins};
+pub use sd_card::IgcLogger;
 pub use sound::Sound;
 pub use version::{SW_VERSION, HW_VERSION};
\ No newline at end of file
@@ -0,0 +1,36 @@
+use corelib::*;
+use std::{fs::File, io::Write};
+
+/// Writes the IGC files of the simulated flights into the working directory
+pub struct IgcLogger {
+    file: Option<File>,
+}
+
+impl IgcLogger {
+    pub fn new() -> Self {
+        IgcLogger { file: None }
+    }
+
+    pub fn sd_card_cmd(&mut self, cmd: SdCardCmd) {
+        match cmd {
+            SdCardCmd::IgcStart(header) => {
+                self.file = (1..36).find_map(|flight| {
+                    File::create_new(header.file_name(flight).as_str()).ok()
+                });
+                self.write(header.to_string().as_bytes());
+            }
+            SdCardCmd::IgcFix(fix) => self.write(fix.to_string().as_bytes()),
+            SdCardCmd::IgcStop => self.file = None,
+            _ => (),
+        }
+    }
+
+    fn write(&mut self, data: &[u8]) {
+        if let Some(file) = &mut self.file {
+            if file.write_all(data).is_err() {
+                eprintln!("Could not write IGC file");
+                self.file = None;
+            }
+        }
+    }
+}